@@ -10,9 +10,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use criterion::{criterion_group, criterion_main, Criterion};
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::Parser;
+use sqlparser::tokenizer::Tokenizer;
 
 fn basic_queries(c: &mut Criterion) {
     let mut group = c.benchmark_group("sqlparser-rs parsing benchmark");
@@ -39,5 +40,47 @@ fn basic_queries(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, basic_queries);
+/// Builds a corpus of `n` statements resembling a migration file; keep in
+/// sync with `migration_corpus` in `tests/sqlparser_regression.rs`, which
+/// pins the number of statements and tokens it produces
+fn migration_corpus(n: usize) -> String {
+    let templates = [
+        "CREATE TABLE t{i} (id INT PRIMARY KEY, name VARCHAR(100) NOT NULL, note TEXT DEFAULT 'it''s {i}')",
+        "INSERT INTO t{i} (id, name) VALUES ({i}, 'a\\\\b'), ({i} + 1, 'x''y')",
+        "UPDATE t{i} SET name = 'n_{i}' WHERE id = {i} AND name LIKE 'a\\_%'",
+        "SELECT a.id, COUNT(*) FROM t{i} AS a JOIN u ON a.id = u.id WHERE a.name <> \"quoted \"\"{i}\"\"\" GROUP BY a.id ORDER BY 2 DESC LIMIT 10",
+        "DELETE FROM t{i} WHERE id IN (SELECT id FROM u WHERE x = X'0{i}')",
+    ];
+    let mut sql = String::new();
+    for i in 0..n {
+        sql.push_str(&templates[i % templates.len()].replace("{i}", &i.to_string()));
+        sql.push_str(";\n");
+    }
+    sql
+}
+
+/// Tokens/sec over 500 and 5000 statements, which should be about the same:
+/// a drop for the larger input points at superlinear behavior, e.g.
+/// re-scanning the token buffer on each lookahead. Run with
+/// `cargo bench -- throughput`.
+fn throughput(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sqlparser-rs throughput");
+    let dialect = GenericDialect {};
+    for &n in &[500, 5000] {
+        let sql = migration_corpus(n);
+        let tokens = Tokenizer::new(&dialect, &sql).tokenize().unwrap().len();
+        Parser::parse_sql(&dialect, &sql).unwrap();
+        group.throughput(Throughput::Elements(tokens as u64));
+
+        group.bench_function(format!("throughput::tokenize/{}", n), |b| {
+            b.iter(|| Tokenizer::new(&dialect, &sql).tokenize());
+        });
+        group.bench_function(format!("throughput::parse/{}", n), |b| {
+            b.iter(|| Parser::parse_sql(&dialect, &sql));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, basic_queries, throughput);
 criterion_main!(benches);
@@ -11,12 +11,13 @@
 // limitations under the License.
 
 #[cfg(not(feature = "std"))]
-use alloc::boxed::Box;
+use alloc::{boxed::Box, string::String, vec::Vec};
 use core::fmt;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::ast::value::escape_single_quote_string;
 use crate::ast::ObjectName;
 
 /// SQL data types
@@ -57,10 +58,10 @@ pub enum DataType {
     Boolean,
     /// Date
     Date,
-    /// Time
-    Time,
-    /// Timestamp
-    Timestamp,
+    /// Time, e.g. `TIME [ WITH TIME ZONE ]` or PostgreSQL's `TIMETZ`
+    Time { tz: bool },
+    /// Timestamp, e.g. `TIMESTAMP [ WITH TIME ZONE ]` or PostgreSQL's `TIMESTAMPTZ`
+    Timestamp { tz: bool },
     /// Interval
     Interval,
     /// Regclass used in postgresql serial
@@ -71,10 +72,47 @@ pub enum DataType {
     String,
     /// Bytea
     Bytea,
+    /// JSON (PostgreSQL)
+    Json,
+    /// JSONB (PostgreSQL)
+    Jsonb,
+    /// IPv4 or IPv6 host address (PostgreSQL)
+    Inet,
+    /// IPv4 or IPv6 network address (PostgreSQL)
+    Cidr,
+    /// MAC address (PostgreSQL)
+    Macaddr,
+    /// Currency amount (PostgreSQL)
+    Money,
+    /// Autoincrementing small integer (PostgreSQL)
+    SmallSerial,
+    /// Autoincrementing integer (PostgreSQL)
+    Serial,
+    /// Autoincrementing big integer (PostgreSQL)
+    BigSerial,
     /// Custom type such as enums
     Custom(ObjectName),
-    /// Arrays
+    /// Arrays, e.g. PostgreSQL's `INT[]` or ANSI `INT ARRAY`
     Array(Box<DataType>),
+    /// ANSI multisets, e.g. `INT MULTISET`
+    Multiset(Box<DataType>),
+    /// Enums e.g. ENUM('a', 'b') (MySQL)
+    Enum(Vec<String>),
+    /// Sets e.g. SET('x', 'y') (MySQL)
+    Set(Vec<String>),
+}
+
+impl DataType {
+    /// The integer type underlying a PostgreSQL serial pseudo-type, e.g.
+    /// `INT` for `SERIAL`, or `None` if this is not a serial type
+    pub fn serial_base_type(&self) -> Option<DataType> {
+        match self {
+            DataType::SmallSerial => Some(DataType::SmallInt),
+            DataType::Serial => Some(DataType::Int),
+            DataType::BigSerial => Some(DataType::BigInt),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for DataType {
@@ -105,15 +143,27 @@ impl fmt::Display for DataType {
             DataType::Double => write!(f, "DOUBLE"),
             DataType::Boolean => write!(f, "BOOLEAN"),
             DataType::Date => write!(f, "DATE"),
-            DataType::Time => write!(f, "TIME"),
-            DataType::Timestamp => write!(f, "TIMESTAMP"),
+            DataType::Time { tz } => write!(f, "TIME{}", format_time_zone(*tz)),
+            DataType::Timestamp { tz } => write!(f, "TIMESTAMP{}", format_time_zone(*tz)),
             DataType::Interval => write!(f, "INTERVAL"),
             DataType::Regclass => write!(f, "REGCLASS"),
             DataType::Text => write!(f, "TEXT"),
             DataType::String => write!(f, "STRING"),
             DataType::Bytea => write!(f, "BYTEA"),
+            DataType::Json => write!(f, "JSON"),
+            DataType::Jsonb => write!(f, "JSONB"),
+            DataType::Inet => write!(f, "INET"),
+            DataType::Cidr => write!(f, "CIDR"),
+            DataType::Macaddr => write!(f, "MACADDR"),
+            DataType::Money => write!(f, "MONEY"),
+            DataType::SmallSerial => write!(f, "SMALLSERIAL"),
+            DataType::Serial => write!(f, "SERIAL"),
+            DataType::BigSerial => write!(f, "BIGSERIAL"),
             DataType::Array(ty) => write!(f, "{}[]", ty),
+            DataType::Multiset(ty) => write!(f, "{} MULTISET", ty),
             DataType::Custom(ty) => write!(f, "{}", ty),
+            DataType::Enum(vals) => format_type_with_string_list(f, "ENUM", vals),
+            DataType::Set(vals) => format_type_with_string_list(f, "SET", vals),
         }
     }
 }
@@ -129,3 +179,25 @@ fn format_type_with_optional_length(
     }
     Ok(())
 }
+
+fn format_type_with_string_list(
+    f: &mut fmt::Formatter,
+    sql_type: &'static str,
+    values: &[String],
+) -> fmt::Result {
+    write!(f, "{}(", sql_type)?;
+    let mut delim = "";
+    for value in values {
+        write!(f, "{}'{}'", delim, escape_single_quote_string(value))?;
+        delim = ", ";
+    }
+    write!(f, ")")
+}
+
+fn format_time_zone(tz: bool) -> &'static str {
+    if tz {
+        " WITH TIME ZONE"
+    } else {
+        ""
+    }
+}
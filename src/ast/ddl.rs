@@ -14,13 +14,22 @@
 //! (commonly referred to as Data Definition Language, or DDL)
 
 #[cfg(not(feature = "std"))]
-use alloc::{boxed::Box, string::ToString, vec::Vec};
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 use core::fmt;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::ast::{display_comma_separated, display_separated, DataType, Expr, Ident, ObjectName};
+use crate::ast::{
+    display_comma_separated, display_separated, DataType, Expr, Function, FunctionArg, Ident,
+    ObjectName, Value,
+};
 use crate::tokenizer::Token;
 
 /// An `ALTER TABLE` (`Statement::AlterTable`) operation
@@ -60,6 +69,20 @@ pub enum AlterTableOperation {
     },
     /// `RENAME TO <table_name>`
     RenameTable { table_name: ObjectName },
+    /// `ALTER [ COLUMN ] <column_name> <op>`
+    AlterColumn {
+        column_name: Ident,
+        op: AlterColumnOperation,
+    },
+    /// `ALTER CONSTRAINT <name> <characteristics>` (PostgreSQL)
+    AlterConstraint {
+        name: Ident,
+        characteristics: ConstraintCharacteristics,
+    },
+    /// `VALIDATE CONSTRAINT <name>` (PostgreSQL)
+    ValidateConstraint { name: Ident },
+    /// `SET SCHEMA <new_schema>` (PostgreSQL)
+    SetSchema { new_schema: ObjectName },
 }
 
 impl fmt::Display for AlterTableOperation {
@@ -76,7 +99,7 @@ impl fmt::Display for AlterTableOperation {
             ),
             AlterTableOperation::AddConstraint(c) => write!(f, "ADD {}", c),
             AlterTableOperation::AddColumn { column_def } => {
-                write!(f, "ADD COLUMN {}", column_def.to_string())
+                write!(f, "ADD COLUMN {}", column_def)
             }
             AlterTableOperation::DropPartitions {
                 partitions,
@@ -119,6 +142,49 @@ impl fmt::Display for AlterTableOperation {
             AlterTableOperation::RenameTable { table_name } => {
                 write!(f, "RENAME TO {}", table_name)
             }
+            AlterTableOperation::AlterColumn { column_name, op } => {
+                write!(f, "ALTER COLUMN {} {}", column_name, op)
+            }
+            AlterTableOperation::AlterConstraint {
+                name,
+                characteristics,
+            } => write!(f, "ALTER CONSTRAINT {} {}", name, characteristics),
+            AlterTableOperation::ValidateConstraint { name } => {
+                write!(f, "VALIDATE CONSTRAINT {}", name)
+            }
+            AlterTableOperation::SetSchema { new_schema } => {
+                write!(f, "SET SCHEMA {}", new_schema)
+            }
+        }
+    }
+}
+
+/// An `ALTER COLUMN` (`Statement::AlterTable`) operation
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AlterColumnOperation {
+    /// `SET NOT NULL`
+    SetNotNull,
+    /// `DROP NOT NULL`
+    DropNotNull,
+    /// `SET DEFAULT <expr>`
+    SetDefault { value: Expr },
+    /// `DROP DEFAULT`
+    DropDefault,
+    /// `[ SET DATA ] TYPE <data_type>`
+    SetDataType { data_type: DataType },
+}
+
+impl fmt::Display for AlterColumnOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlterColumnOperation::SetNotNull => write!(f, "SET NOT NULL"),
+            AlterColumnOperation::DropNotNull => write!(f, "DROP NOT NULL"),
+            AlterColumnOperation::SetDefault { value } => write!(f, "SET DEFAULT {}", value),
+            AlterColumnOperation::DropDefault => write!(f, "DROP DEFAULT"),
+            AlterColumnOperation::SetDataType { data_type } => {
+                write!(f, "SET DATA TYPE {}", data_type)
+            }
         }
     }
 }
@@ -128,20 +194,28 @@ impl fmt::Display for AlterTableOperation {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TableConstraint {
-    /// `[ CONSTRAINT <name> ] { PRIMARY KEY | UNIQUE } (<columns>)`
+    /// `[ CONSTRAINT <name> ] { PRIMARY KEY | UNIQUE } (<columns>)
+    /// [ <characteristics> ]`
     Unique {
         name: Option<Ident>,
         columns: Vec<Ident>,
         /// Whether this is a `PRIMARY KEY` or just a `UNIQUE` constraint
         is_primary: bool,
+        characteristics: Option<ConstraintCharacteristics>,
     },
     /// A referential integrity constraint (`[ CONSTRAINT <name> ] FOREIGN KEY (<columns>)
-    /// REFERENCES <foreign_table> (<referred_columns>)`)
+    /// REFERENCES <foreign_table> (<referred_columns>)
+    /// { [ON DELETE <referential_action>] [ON UPDATE <referential_action>] |
+    ///   [ON UPDATE <referential_action>] [ON DELETE <referential_action>]
+    /// } [ <characteristics> ]`).
     ForeignKey {
         name: Option<Ident>,
         columns: Vec<Ident>,
         foreign_table: ObjectName,
         referred_columns: Vec<Ident>,
+        on_delete: Option<ReferentialAction>,
+        on_update: Option<ReferentialAction>,
+        characteristics: Option<ConstraintCharacteristics>,
     },
     /// `[ CONSTRAINT <name> ] CHECK (<expr>)`
     Check {
@@ -157,26 +231,48 @@ impl fmt::Display for TableConstraint {
                 name,
                 columns,
                 is_primary,
-            } => write!(
-                f,
-                "{}{} ({})",
-                display_constraint_name(name),
-                if *is_primary { "PRIMARY KEY" } else { "UNIQUE" },
-                display_comma_separated(columns)
-            ),
+                characteristics,
+            } => {
+                write!(
+                    f,
+                    "{}{} ({})",
+                    display_constraint_name(name),
+                    if *is_primary { "PRIMARY KEY" } else { "UNIQUE" },
+                    display_comma_separated(columns)
+                )?;
+                if let Some(characteristics) = characteristics {
+                    write!(f, " {}", characteristics)?;
+                }
+                Ok(())
+            }
             TableConstraint::ForeignKey {
                 name,
                 columns,
                 foreign_table,
                 referred_columns,
-            } => write!(
-                f,
-                "{}FOREIGN KEY ({}) REFERENCES {}({})",
-                display_constraint_name(name),
-                display_comma_separated(columns),
-                foreign_table,
-                display_comma_separated(referred_columns)
-            ),
+                on_delete,
+                on_update,
+                characteristics,
+            } => {
+                write!(
+                    f,
+                    "{}FOREIGN KEY ({}) REFERENCES {}({})",
+                    display_constraint_name(name),
+                    display_comma_separated(columns),
+                    foreign_table,
+                    display_comma_separated(referred_columns)
+                )?;
+                if let Some(action) = on_delete {
+                    write!(f, " ON DELETE {}", action)?;
+                }
+                if let Some(action) = on_update {
+                    write!(f, " ON UPDATE {}", action)?;
+                }
+                if let Some(characteristics) = characteristics {
+                    write!(f, " {}", characteristics)?;
+                }
+                Ok(())
+            }
             TableConstraint::Check { name, expr } => {
                 write!(f, "{}CHECK ({})", display_constraint_name(name), expr)
             }
@@ -191,9 +287,70 @@ pub struct ColumnDef {
     pub name: Ident,
     pub data_type: DataType,
     pub collation: Option<ObjectName>,
+    /// The text of any comments directly preceding the column definition.
+    /// These are not part of the SQL output of the column.
+    pub leading_comments: Vec<String>,
     pub options: Vec<ColumnOptionDef>,
 }
 
+impl ColumnDef {
+    /// Expand a column of a serial pseudo-type (e.g. `id SERIAL`) into what
+    /// PostgreSQL actually creates for it: `id INT NOT NULL DEFAULT
+    /// nextval('<table>_id_seq')`. Returns `None` if the column is not serial.
+    pub fn expand_serial(&self, table_name: &ObjectName) -> Option<ColumnDef> {
+        let data_type = self.data_type.serial_base_type()?;
+        // The implicit sequence lives next to the table, e.g. `s.t_id_seq`
+        let mut sequence_name = table_name.0.clone();
+        let table = sequence_name.pop()?;
+        // Quoted names keep their case and spaces, so the sequence must too.
+        // Alongside a quoted part, an unquoted one is folded to lower case
+        // first, the way PostgreSQL resolves it.
+        let quote_style = table.quote_style.or(self.name.quote_style);
+        let part = |ident: &Ident| match (quote_style, ident.quote_style) {
+            (Some(_), None) => ident.value.to_lowercase(),
+            _ => ident.value.clone(),
+        };
+        sequence_name.push(Ident {
+            value: format!("{}_{}_seq", part(&table), part(&self.name)),
+            quote_style,
+        });
+        let nextval = Expr::Function(Function {
+            name: ObjectName(vec![Ident::new("nextval")]),
+            params: vec![],
+            args: vec![FunctionArg::Unnamed(Expr::Value(
+                Value::SingleQuotedString(ObjectName(sequence_name).to_string()),
+            ))],
+            over: None,
+            distinct: false,
+            null_treatment: None,
+            order_by: vec![],
+            filter: None,
+        });
+
+        let mut options = vec![];
+        if !self
+            .options
+            .iter()
+            .any(|o| matches!(o.option, ColumnOption::NotNull))
+        {
+            options.push(ColumnOptionDef {
+                name: None,
+                option: ColumnOption::NotNull,
+            });
+        }
+        options.push(ColumnOptionDef {
+            name: None,
+            option: ColumnOption::Default(nextval),
+        });
+        options.extend(self.options.iter().cloned());
+        Some(ColumnDef {
+            data_type,
+            options,
+            ..self.clone()
+        })
+    }
+}
+
 impl fmt::Display for ColumnDef {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{} {}", self.name, self.data_type)?;
@@ -250,15 +407,18 @@ pub enum ColumnOption {
     /// <foreign_table> (<referred_columns>)
     /// { [ON DELETE <referential_action>] [ON UPDATE <referential_action>] |
     ///   [ON UPDATE <referential_action>] [ON DELETE <referential_action>]
-    /// }`).
+    /// } [ <characteristics> ]`).
     ForeignKey {
         foreign_table: ObjectName,
         referred_columns: Vec<Ident>,
         on_delete: Option<ReferentialAction>,
         on_update: Option<ReferentialAction>,
+        characteristics: Option<ConstraintCharacteristics>,
     },
     /// `CHECK (<expr>)`
     Check(Expr),
+    /// MySQL's inline `COMMENT '<text>'`
+    Comment(String),
     /// Dialect-specific options, such as:
     /// - MySQL's `AUTO_INCREMENT` or SQLite's `AUTOINCREMENT`
     /// - ...
@@ -280,6 +440,7 @@ impl fmt::Display for ColumnOption {
                 referred_columns,
                 on_delete,
                 on_update,
+                characteristics,
             } => {
                 write!(f, "REFERENCES {}", foreign_table)?;
                 if !referred_columns.is_empty() {
@@ -291,9 +452,17 @@ impl fmt::Display for ColumnOption {
                 if let Some(action) = on_update {
                     write!(f, " ON UPDATE {}", action)?;
                 }
+                if let Some(characteristics) = characteristics {
+                    write!(f, " {}", characteristics)?;
+                }
                 Ok(())
             }
             Check(expr) => write!(f, "CHECK ({})", expr),
+            Comment(comment) => write!(
+                f,
+                "COMMENT '{}'",
+                crate::ast::value::escape_single_quote_string(comment)
+            ),
             DialectSpecific(val) => write!(f, "{}", display_separated(val, " ")),
         }
     }
@@ -337,3 +506,156 @@ impl fmt::Display for ReferentialAction {
         })
     }
 }
+
+/// When a constraint is checked: `[ NOT ] DEFERRABLE
+/// [ INITIALLY { DEFERRED | IMMEDIATE } ]`, in either order
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConstraintCharacteristics {
+    /// `DEFERRABLE` (true) or `NOT DEFERRABLE` (false), if given
+    pub deferrable: Option<bool>,
+    /// `INITIALLY DEFERRED` or `INITIALLY IMMEDIATE`, if given
+    pub initially: Option<DeferrableInitial>,
+}
+
+impl fmt::Display for ConstraintCharacteristics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut needs_space = false;
+        if let Some(deferrable) = self.deferrable {
+            if !deferrable {
+                write!(f, "NOT ")?;
+            }
+            write!(f, "DEFERRABLE")?;
+            needs_space = true;
+        }
+        if let Some(initially) = self.initially {
+            if needs_space {
+                write!(f, " ")?;
+            }
+            match initially {
+                DeferrableInitial::Immediate => write!(f, "INITIALLY IMMEDIATE")?,
+                DeferrableInitial::Deferred => write!(f, "INITIALLY DEFERRED")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The initial checking mode of a deferrable constraint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DeferrableInitial {
+    Immediate,
+    Deferred,
+}
+
+/// The table whose definition `CREATE TABLE ... LIKE` copies
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TableLike {
+    pub name: ObjectName,
+    /// Whether this was given in place of the column list, as in
+    /// `CREATE TABLE t (LIKE src)` (PostgreSQL), rather than after the
+    /// table name as in `CREATE TABLE t LIKE src`
+    pub parenthesized: bool,
+    /// `{ INCLUDING | EXCLUDING } <property>`, in the parenthesized form
+    pub options: Vec<TableLikeOption>,
+}
+
+impl fmt::Display for TableLike {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.parenthesized {
+            write!(f, "(LIKE {}", self.name)?;
+            for option in &self.options {
+                write!(f, " {}", option)?;
+            }
+            f.write_str(")")
+        } else {
+            write!(f, "LIKE {}", self.name)
+        }
+    }
+}
+
+/// Which properties `CREATE TABLE t (LIKE src ...)` copies, e.g.
+/// `INCLUDING DEFAULTS` or `EXCLUDING ALL`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TableLikeOption {
+    pub including: bool,
+    /// `DEFAULTS`, `CONSTRAINTS`, `INDEXES`, `ALL`, ...
+    pub property: Ident,
+}
+
+impl fmt::Display for TableLikeOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            if self.including {
+                "INCLUDING"
+            } else {
+                "EXCLUDING"
+            },
+            self.property
+        )
+    }
+}
+
+/// What happens to a temporary table at the end of a transaction:
+/// `ON COMMIT { PRESERVE ROWS | DELETE ROWS | DROP }`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OnCommit {
+    PreserveRows,
+    DeleteRows,
+    Drop,
+}
+
+impl fmt::Display for OnCommit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            OnCommit::PreserveRows => "ON COMMIT PRESERVE ROWS",
+            OnCommit::DeleteRows => "ON COMMIT DELETE ROWS",
+            OnCommit::Drop => "ON COMMIT DROP",
+        })
+    }
+}
+
+/// The partitioning of a table declared with `PARTITION BY
+/// { RANGE | LIST | HASH } ( <column_or_expression> [, ...] )`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PartitionSpec {
+    pub strategy: PartitionStrategy,
+    /// The partition key: column names, or parenthesized expressions
+    pub columns: Vec<Expr>,
+}
+
+impl fmt::Display for PartitionSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "PARTITION BY {} ({})",
+            self.strategy,
+            display_comma_separated(&self.columns)
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PartitionStrategy {
+    Range,
+    List,
+    Hash,
+}
+
+impl fmt::Display for PartitionStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            PartitionStrategy::Range => "RANGE",
+            PartitionStrategy::List => "LIST",
+            PartitionStrategy::Hash => "HASH",
+        })
+    }
+}
@@ -31,16 +31,19 @@ use serde::{Deserialize, Serialize};
 
 pub use self::data_type::DataType;
 pub use self::ddl::{
-    AlterTableOperation, ColumnDef, ColumnOption, ColumnOptionDef, ReferentialAction,
-    TableConstraint,
+    AlterColumnOperation, AlterTableOperation, ColumnDef, ColumnOption, ColumnOptionDef,
+    ConstraintCharacteristics, DeferrableInitial, OnCommit, PartitionSpec, PartitionStrategy,
+    ReferentialAction, TableConstraint, TableLike, TableLikeOption,
 };
 pub use self::operator::{BinaryOperator, UnaryOperator};
+pub(crate) use self::operator::{BETWEEN_PREC, IS_PREC, PLUS_MINUS_PREC, UNARY_NOT_PREC};
 pub use self::query::{
     Cte, Fetch, Join, JoinConstraint, JoinOperator, LateralView, Offset, OffsetRows, OrderByExpr,
-    Query, Select, SelectItem, SetExpr, SetOperator, TableAlias, TableFactor, TableWithJoins, Top,
-    Values, With,
+    Query, ReplaceSelectElement, Select, SelectItem, SetExpr, SetOperator, TableAlias, TableFactor,
+    TableWithJoins, Top, Values, WildcardAdditionalOptions, With,
 };
-pub use self::value::{DateTimeField, Value};
+pub(crate) use self::value::escape_quoted_string;
+pub use self::value::{DateTimeField, DollarQuotedString, Value};
 
 struct DisplaySeparated<'a, T>
 where
@@ -128,8 +131,16 @@ impl From<&str> for Ident {
 impl fmt::Display for Ident {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.quote_style {
-            Some(q) if q == '"' || q == '\'' || q == '`' => write!(f, "{}{}{}", q, self.value, q),
-            Some(q) if q == '[' => write!(f, "[{}]", self.value),
+            Some(q) if q == '"' || q == '\'' || q == '`' => {
+                write!(
+                    f,
+                    "{}{}{}",
+                    q,
+                    value::escape_quoted_string(&self.value, q),
+                    q
+                )
+            }
+            Some(q) if q == '[' => write!(f, "[{}]", value::escape_quoted_string(&self.value, ']')),
             None => f.write_str(&self.value),
             _ => panic!("unexpected quote style"),
         }
@@ -192,6 +203,30 @@ pub enum Expr {
         low: Box<Expr>,
         high: Box<Expr>,
     },
+    /// `<expr> [ NOT ] LIKE <pattern> [ ESCAPE '<char>' ]`
+    ///
+    /// `escape_char` is `None` when no `ESCAPE` clause was written.
+    Like {
+        negated: bool,
+        expr: Box<Expr>,
+        pattern: Box<Expr>,
+        escape_char: Option<char>,
+    },
+    /// `<expr> [ NOT ] ILIKE <pattern> [ ESCAPE '<char>' ]`, the
+    /// case-insensitive variant of `LIKE`
+    ILike {
+        negated: bool,
+        expr: Box<Expr>,
+        pattern: Box<Expr>,
+        escape_char: Option<char>,
+    },
+    /// `<expr> [ NOT ] SIMILAR TO <pattern> [ ESCAPE '<char>' ]`
+    SimilarTo {
+        negated: bool,
+        expr: Box<Expr>,
+        pattern: Box<Expr>,
+        escape_char: Option<char>,
+    },
     /// Binary operation e.g. `1 + 1` or `foo > bar`
     BinaryOp {
         left: Box<Expr>,
@@ -203,16 +238,14 @@ pub enum Expr {
         op: UnaryOperator,
         expr: Box<Expr>,
     },
-    /// CAST an expression to a different data type e.g. `CAST(foo AS VARCHAR(123))`
+    /// CAST an expression to a different data type e.g. `CAST(foo AS VARCHAR(123))`,
+    /// or one of its non-throwing variants `TRY_CAST` / `SAFE_CAST`
     Cast {
+        kind: CastKind,
         expr: Box<Expr>,
         data_type: DataType,
-    },
-    /// TRY_CAST an expression to a different data type e.g. `TRY_CAST(foo AS VARCHAR(123))`
-    //  this differs from CAST in the choice of how to implement invalid conversions
-    TryCast {
-        expr: Box<Expr>,
-        data_type: DataType,
+        /// Optional `FORMAT <string>` suffix, e.g. `CAST(d AS STRING FORMAT 'YYYY')` (BigQuery)
+        format: Option<Box<Expr>>,
     },
     /// EXTRACT(DateTimeField FROM <expr>)
     Extract {
@@ -240,6 +273,8 @@ pub enum Expr {
     },
     /// Nested expression e.g. `(foo > bar)` or `(1)`
     Nested(Box<Expr>),
+    /// A row constructor (tuple) e.g. `(1, 2)` or `(a, b)`
+    Tuple(Vec<Expr>),
     /// A literal value, such as string, number, date or NULL
     Value(Value),
     /// A constant of form `<data_type> 'value'`.
@@ -253,8 +288,29 @@ pub enum Expr {
         column: Box<Expr>,
         key: String,
     },
+    /// A parameter of a prepared statement, as written, e.g. `$1` or `?`
+    Parameter(String),
+    /// An array literal e.g. `ARRAY[1, 2]`
+    Array(Vec<Expr>),
+    /// An array subscript e.g. `a[1]` (PostgreSQL)
+    ArrayIndex {
+        obj: Box<Expr>,
+        index: Box<Expr>,
+    },
+    /// An array slice e.g. `a[1:3]`, `a[:2]` or `a[2:]` (PostgreSQL)
+    ArraySlice {
+        obj: Box<Expr>,
+        lower: Option<Box<Expr>>,
+        upper: Option<Box<Expr>>,
+    },
     /// Scalar function call e.g. `LEFT(foo, 5)`
     Function(Function),
+    /// A niladic function written without parentheses, e.g. `CURRENT_TIMESTAMP`.
+    /// (When followed by parentheses, e.g. `CURRENT_TIMESTAMP(6)`, these are
+    /// parsed as a regular [Expr::Function].)
+    NiladicFunction {
+        name: Ident,
+    },
     /// `CASE [<operand>] WHEN <condition> THEN <result> ... [ELSE <result>] END`
     ///
     /// Note we only recognize a complete single expression as `<condition>`,
@@ -274,6 +330,87 @@ pub enum Expr {
     Subquery(Box<Query>),
     /// The `LISTAGG` function `SELECT LISTAGG(...) WITHIN GROUP (ORDER BY ...)`
     ListAgg(ListAgg),
+    /// MySQL's `GROUP_CONCAT(...)` aggregate
+    GroupConcat(GroupConcat),
+    /// MySQL full-text search `MATCH (<col>, ...) AGAINST (<expr> [<search modifier>])`
+    ///
+    /// See <https://dev.mysql.com/doc/refman/8.0/en/fulltext-search.html>
+    MatchAgainst {
+        /// `(<col>, <col>, ...)`
+        columns: Vec<Ident>,
+        /// `<expr>`, a string literal
+        match_value: Value,
+        /// `<search modifier>`
+        modifier: Option<SearchModifier>,
+    },
+}
+
+impl Expr {
+    /// If this is a typed null, i.e. a cast of the `NULL` literal such as
+    /// `CAST(NULL AS INT)`, return the type it is cast to
+    pub fn is_typed_null(&self) -> Option<&DataType> {
+        match self {
+            Expr::Cast {
+                expr, data_type, ..
+            } if matches!(**expr, Expr::Value(Value::Null)) => Some(data_type),
+            _ => None,
+        }
+    }
+}
+
+/// Whether `expr` binds more loosely than the prefix operator `op`, so that
+/// writing it out as `<op> <expr>` would parse back as something else, e.g.
+/// `NOT a OR b` or `- a + b`. A custom operator's precedence is only known
+/// to the dialect, so such an operand is always parenthesized.
+fn needs_parens_after(op: &UnaryOperator, expr: &Expr) -> bool {
+    let op_precedence = match op {
+        UnaryOperator::Not => UNARY_NOT_PREC,
+        UnaryOperator::PGPostfixFactorial => return false,
+        _ => PLUS_MINUS_PREC,
+    };
+    let expr_precedence = match expr {
+        Expr::BinaryOp { op, .. } => match op.precedence() {
+            Some(precedence) => precedence,
+            None => return true,
+        },
+        Expr::IsNull(_) | Expr::IsNotNull(_) => IS_PREC,
+        Expr::InList { .. }
+        | Expr::InSubquery { .. }
+        | Expr::Between { .. }
+        | Expr::Like { .. }
+        | Expr::ILike { .. }
+        | Expr::SimilarTo { .. } => BETWEEN_PREC,
+        _ => return false,
+    };
+    expr_precedence <= op_precedence
+}
+
+/// Write `<expr> [ NOT ] <keyword> <pattern> [ ESCAPE '<char>' ]`, the
+/// shared form of `LIKE`, `ILIKE` and `SIMILAR TO`
+fn write_pattern_match(
+    f: &mut fmt::Formatter,
+    expr: &Expr,
+    negated: bool,
+    keyword: &str,
+    pattern: &Expr,
+    escape_char: &Option<char>,
+) -> fmt::Result {
+    write!(
+        f,
+        "{} {}{} {}",
+        expr,
+        if negated { "NOT " } else { "" },
+        keyword,
+        pattern
+    )?;
+    if let Some(ch) = escape_char {
+        write!(
+            f,
+            " ESCAPE '{}'",
+            value::escape_single_quote_string(ch.encode_utf8(&mut [0; 4]))
+        )?;
+    }
+    Ok(())
 }
 
 impl fmt::Display for Expr {
@@ -281,6 +418,20 @@ impl fmt::Display for Expr {
         match self {
             Expr::Identifier(s) => write!(f, "{}", s),
             Expr::MapAccess { column, key } => write!(f, "{}[\"{}\"]", column, key),
+            Expr::Parameter(s) => write!(f, "{}", s),
+            Expr::Array(elems) => write!(f, "ARRAY[{}]", display_comma_separated(elems)),
+            Expr::ArrayIndex { obj, index } => write!(f, "{}[{}]", obj, index),
+            Expr::ArraySlice { obj, lower, upper } => {
+                write!(f, "{}[", obj)?;
+                if let Some(lower) = lower {
+                    write!(f, "{}", lower)?;
+                }
+                write!(f, ":")?;
+                if let Some(upper) = upper {
+                    write!(f, "{}", upper)?;
+                }
+                write!(f, "]")
+            }
             Expr::Wildcard => f.write_str("*"),
             Expr::QualifiedWildcard(q) => write!(f, "{}.*", display_separated(q, ".")),
             Expr::CompoundIdentifier(s) => write!(f, "{}", display_separated(s, ".")),
@@ -308,6 +459,24 @@ impl fmt::Display for Expr {
                 if *negated { "NOT " } else { "" },
                 subquery
             ),
+            Expr::Like {
+                negated,
+                expr,
+                pattern,
+                escape_char,
+            } => write_pattern_match(f, expr, *negated, "LIKE", pattern, escape_char),
+            Expr::ILike {
+                negated,
+                expr,
+                pattern,
+                escape_char,
+            } => write_pattern_match(f, expr, *negated, "ILIKE", pattern, escape_char),
+            Expr::SimilarTo {
+                negated,
+                expr,
+                pattern,
+                escape_char,
+            } => write_pattern_match(f, expr, *negated, "SIMILAR TO", pattern, escape_char),
             Expr::Between {
                 expr,
                 negated,
@@ -325,21 +494,35 @@ impl fmt::Display for Expr {
             Expr::UnaryOp { op, expr } => {
                 if op == &UnaryOperator::PGPostfixFactorial {
                     write!(f, "{}{}", expr, op)
+                } else if needs_parens_after(op, expr) {
+                    write!(f, "{} ({})", op, expr)
                 } else {
                     write!(f, "{} {}", op, expr)
                 }
             }
-            Expr::Cast { expr, data_type } => write!(f, "CAST({} AS {})", expr, data_type),
-            Expr::TryCast { expr, data_type } => write!(f, "TRY_CAST({} AS {})", expr, data_type),
+            Expr::Cast {
+                kind,
+                expr,
+                data_type,
+                format,
+            } => {
+                write!(f, "{}({} AS {}", kind, expr, data_type)?;
+                if let Some(format) = format {
+                    write!(f, " FORMAT {}", format)?;
+                }
+                write!(f, ")")
+            }
             Expr::Extract { field, expr } => write!(f, "EXTRACT({} FROM {})", field, expr),
             Expr::Collate { expr, collation } => write!(f, "{} COLLATE {}", expr, collation),
             Expr::Nested(ast) => write!(f, "({})", ast),
+            Expr::Tuple(exprs) => write!(f, "({})", display_comma_separated(exprs)),
             Expr::Value(v) => write!(f, "{}", v),
             Expr::TypedString { data_type, value } => {
                 write!(f, "{}", data_type)?;
                 write!(f, " '{}'", &value::escape_single_quote_string(value))
             }
             Expr::Function(fun) => write!(f, "{}", fun),
+            Expr::NiladicFunction { name } => write!(f, "{}", name),
             Expr::Case {
                 operand,
                 conditions,
@@ -362,6 +545,23 @@ impl fmt::Display for Expr {
             Expr::Exists(s) => write!(f, "EXISTS ({})", s),
             Expr::Subquery(s) => write!(f, "({})", s),
             Expr::ListAgg(listagg) => write!(f, "{}", listagg),
+            Expr::GroupConcat(group_concat) => write!(f, "{}", group_concat),
+            Expr::MatchAgainst {
+                columns,
+                match_value,
+                modifier,
+            } => {
+                write!(
+                    f,
+                    "MATCH ({}) AGAINST ({}",
+                    display_comma_separated(columns),
+                    match_value
+                )?;
+                if let Some(modifier) = modifier {
+                    write!(f, " {}", modifier)?;
+                }
+                write!(f, ")")
+            }
             Expr::Substring {
                 expr,
                 substring_from,
@@ -391,6 +591,29 @@ impl fmt::Display for Expr {
     }
 }
 
+/// The flavour of a cast expression, which differs in how invalid
+/// conversions are handled
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CastKind {
+    /// `CAST(...)`: an invalid conversion raises an error
+    Cast,
+    /// `TRY_CAST(...)` (MSSQL): an invalid conversion returns NULL
+    TryCast,
+    /// `SAFE_CAST(...)` (BigQuery): an invalid conversion returns NULL
+    SafeCast,
+}
+
+impl fmt::Display for CastKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            CastKind::Cast => "CAST",
+            CastKind::TryCast => "TRY_CAST",
+            CastKind::SafeCast => "SAFE_CAST",
+        })
+    }
+}
+
 /// A window specification (i.e. `OVER (PARTITION BY .. ORDER BY .. etc.)`)
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -428,6 +651,9 @@ impl fmt::Display for WindowSpec {
                 f.write_str(delim)?;
                 write!(f, "{} {}", window_frame.units, window_frame.start_bound)?;
             }
+            if let Some(exclude) = &window_frame.exclude {
+                write!(f, " EXCLUDE {}", exclude)?;
+            }
         }
         Ok(())
     }
@@ -447,7 +673,8 @@ pub struct WindowFrame {
     /// indicates the shorthand form (e.g. `ROWS 1 PRECEDING`), which must
     /// behave the same as `end_bound = WindowFrameBound::CurrentRow`.
     pub end_bound: Option<WindowFrameBound>,
-    // TBD: EXCLUDE
+    /// The optional `EXCLUDE` clause, e.g. `EXCLUDE CURRENT ROW`
+    pub exclude: Option<WindowFrameExclude>,
 }
 
 impl Default for WindowFrame {
@@ -459,6 +686,7 @@ impl Default for WindowFrame {
             units: WindowFrameUnits::Range,
             start_bound: WindowFrameBound::Preceding(None),
             end_bound: None,
+            exclude: None,
         }
     }
 }
@@ -505,6 +733,31 @@ impl fmt::Display for WindowFrameBound {
     }
 }
 
+/// Specifies the rows excluded from a [WindowFrame] by the `EXCLUDE` clause
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum WindowFrameExclude {
+    /// `EXCLUDE CURRENT ROW`
+    CurrentRow,
+    /// `EXCLUDE GROUP`
+    Group,
+    /// `EXCLUDE TIES`
+    Ties,
+    /// `EXCLUDE NO OTHERS`
+    NoOthers,
+}
+
+impl fmt::Display for WindowFrameExclude {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            WindowFrameExclude::CurrentRow => "CURRENT ROW",
+            WindowFrameExclude::Group => "GROUP",
+            WindowFrameExclude::Ties => "TIES",
+            WindowFrameExclude::NoOthers => "NO OTHERS",
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AddDropSync {
@@ -528,8 +781,9 @@ impl fmt::Display for AddDropSync {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Statement {
-    /// Analyze (Hive)
+    /// `ANALYZE TABLE ...` (Hive) or `ANALYZE table [ ( column [, ...] ) ]` (PostgreSQL)
     Analyze {
+        has_table_keyword: bool,
         table_name: ObjectName,
         partitions: Option<Vec<Expr>>,
         for_columns: bool,
@@ -538,6 +792,14 @@ pub enum Statement {
         noscan: bool,
         compute_statistics: bool,
     },
+    /// `VACUUM [ FULL ] [ FREEZE ] [ VERBOSE ] [ ANALYZE ] [ table [ ( column [, ...] ) ] ]`
+    ///
+    /// Note: this is a PostgreSQL-specific statement.
+    Vacuum {
+        options: Vec<VacuumOption>,
+        table_name: Option<ObjectName>,
+        columns: Vec<Ident>,
+    },
     /// Truncate (Hive)
     Truncate {
         table_name: ObjectName,
@@ -555,6 +817,8 @@ pub enum Statement {
     Insert {
         /// Only for Sqlite
         or: Option<SqliteOnConflict>,
+        /// `REPLACE INTO` instead of `INSERT INTO` (MySQL)
+        replace_into: bool,
         /// TABLE
         table_name: ObjectName,
         /// COLUMNS
@@ -571,6 +835,16 @@ pub enum Statement {
         after_columns: Vec<Ident>,
         /// whether the insert has the table keyword (Hive)
         table: bool,
+        /// Alias for the inserted `VALUES` rows, e.g. `VALUES (1, 2) AS new (a, b)` (MySQL)
+        values_alias: Option<TableAlias>,
+        /// `ON CONFLICT` clause (PostgreSQL)
+        on_conflict: Option<OnConflict>,
+        /// `SET col = value, ...` given instead of a source (MySQL)
+        assignments: Vec<Assignment>,
+        /// `OVERRIDING { SYSTEM | USER } VALUE` before the source
+        overriding: Option<Overriding>,
+        /// RETURNING
+        returning: Option<Vec<SelectItem>>,
     },
     // TODO: Support ROW FORMAT
     Directory {
@@ -585,6 +859,12 @@ pub enum Statement {
         table_name: ObjectName,
         /// COLUMNS
         columns: Vec<Ident>,
+        /// If true, is a 'COPY TO' statement. If false is a 'COPY FROM'
+        to: bool,
+        /// The source of 'COPY FROM', or the target of 'COPY TO'
+        target: CopyTarget,
+        /// WITH options
+        options: Vec<CopyOption>,
         /// VALUES a vector of values to be copied
         values: Vec<Option<String>>,
     },
@@ -594,15 +874,23 @@ pub enum Statement {
         table_name: ObjectName,
         /// Column assignments
         assignments: Vec<Assignment>,
+        /// FROM, the other tables to join with
+        from: Vec<TableWithJoins>,
         /// WHERE
         selection: Option<Expr>,
+        /// RETURNING
+        returning: Option<Vec<SelectItem>>,
     },
     /// DELETE
     Delete {
         /// FROM
         table_name: ObjectName,
+        /// USING, the other tables to join with
+        using: Vec<TableWithJoins>,
         /// WHERE
         selection: Option<Expr>,
+        /// RETURNING
+        returning: Option<Vec<SelectItem>>,
     },
     /// CREATE VIEW
     CreateView {
@@ -613,6 +901,8 @@ pub enum Statement {
         columns: Vec<Ident>,
         query: Box<Query>,
         with_options: Vec<SqlOption>,
+        /// `WITH [ CASCADED | LOCAL ] CHECK OPTION`
+        with_check_option: Option<CheckOption>,
     },
     /// CREATE TABLE
     CreateTable {
@@ -633,7 +923,19 @@ pub enum Statement {
         location: Option<String>,
         query: Option<Box<Query>>,
         without_rowid: bool,
-        like: Option<ObjectName>,
+        like: Option<TableLike>,
+        /// `INHERITS (<parent> [, ...])` (PostgreSQL)
+        inherits: Vec<ObjectName>,
+        /// `PARTITION BY { RANGE | LIST | HASH } (...)`
+        partition_by: Option<PartitionSpec>,
+        /// `ON COMMIT ...`, only for temporary tables
+        on_commit: Option<OnCommit>,
+        /// `TABLESPACE <name>`
+        tablespace: Option<Ident>,
+        /// `ENGINE=<name>` (MySQL)
+        engine: Option<Ident>,
+        /// `DEFAULT CHARSET=<name>` (MySQL)
+        default_charset: Option<Ident>,
     },
     /// SQLite's `CREATE VIRTUAL TABLE .. USING <module_name> (<module_args>)`
     CreateVirtualTable {
@@ -647,14 +949,31 @@ pub enum Statement {
         /// index name
         name: ObjectName,
         table_name: ObjectName,
+        /// `USING <method>`, e.g. `btree` or `gin`
+        using: Option<Ident>,
         columns: Vec<OrderByExpr>,
         unique: bool,
         if_not_exists: bool,
+        /// `INCLUDE (<columns>)`, the non-key columns of a covering index
+        include: Vec<Ident>,
+        /// `TABLESPACE <name>`
+        tablespace: Option<Ident>,
+        /// `WHERE <predicate>` of a partial index
+        predicate: Option<Expr>,
     },
     /// ALTER TABLE
     AlterTable {
         /// Table name
         name: ObjectName,
+        /// The comma-separated operations, e.g. `ADD COLUMN a INT, DROP COLUMN b`
+        operations: Vec<AlterTableOperation>,
+    },
+    /// `ALTER { VIEW | SEQUENCE | INDEX } <name> <operation>`, where the
+    /// operation is `RENAME TO` (`AlterTableOperation::RenameTable`) or
+    /// `SET SCHEMA`
+    AlterObject {
+        object_type: ObjectType,
+        name: ObjectName,
         operation: AlterTableOperation,
     },
     /// DROP
@@ -665,9 +984,8 @@ pub enum Statement {
         if_exists: bool,
         /// One or more objects to drop. (ANSI SQL requires exactly one.)
         names: Vec<ObjectName>,
-        /// Whether `CASCADE` was specified. This will be `false` when
-        /// `RESTRICT` or no drop behavior at all was specified.
-        cascade: bool,
+        /// The `CASCADE` or `RESTRICT` behavior, if specified
+        behavior: Option<DropBehavior>,
         /// Hive allows you specify whether the table's stored data will be
         /// deleted along with the dropped table
         purge: bool,
@@ -696,6 +1014,18 @@ pub enum Statement {
         table_name: ObjectName,
         filter: Option<ShowStatementFilter>,
     },
+    /// SHOW [ FULL ] TABLES [ { FROM | IN } db_name ] [ filter ]
+    ///
+    /// Note: this is a MySQL-specific statement.
+    ShowTables {
+        full: bool,
+        db_name: Option<ObjectName>,
+        filter: Option<ShowStatementFilter>,
+    },
+    /// SHOW VARIABLES [ filter ]
+    ///
+    /// Note: this is a MySQL-specific statement.
+    ShowVariables { filter: Option<ShowStatementFilter> },
     /// `{ BEGIN [ TRANSACTION | WORK ] | START TRANSACTION } ...`
     StartTransaction { modes: Vec<TransactionMode> },
     /// `SET TRANSACTION ...`
@@ -704,6 +1034,23 @@ pub enum Statement {
     Commit { chain: bool },
     /// `ROLLBACK [ TRANSACTION | WORK ] [ AND [ NO ] CHAIN ]`
     Rollback { chain: bool },
+    /// `CREATE [ OR REPLACE ] FUNCTION name ( [ [ argname ] argtype [, ...] ] )
+    /// [ RETURNS rettype ] AS definition [ LANGUAGE lang_name ]` (PostgreSQL)
+    CreateFunction {
+        or_replace: bool,
+        name: ObjectName,
+        args: Vec<CreateFunctionArg>,
+        return_type: Option<DataType>,
+        body: FunctionDefinition,
+        language: Option<Ident>,
+    },
+    /// `CREATE [ TEMPORARY ] SEQUENCE [ IF NOT EXISTS ] name [ option ... ]`
+    CreateSequence {
+        temporary: bool,
+        if_not_exists: bool,
+        name: ObjectName,
+        options: Vec<SequenceOptions>,
+    },
     /// CREATE SCHEMA
     CreateSchema {
         schema_name: ObjectName,
@@ -746,6 +1093,73 @@ pub enum Statement {
         /// A SQL query that specifies what to explain
         statement: Box<Statement>,
     },
+    /// `DESCRIBE <table>` (or `DESC <table>`)
+    ///
+    /// Note: this is a MySQL-specific statement.
+    Describe {
+        /// Table name
+        table_name: ObjectName,
+    },
+    /// `GRANT privileges ON objects TO grantees [ WITH GRANT OPTION ]`
+    Grant {
+        privileges: Privileges,
+        objects: GrantObjects,
+        grantees: Vec<Ident>,
+        with_grant_option: bool,
+    },
+    /// `REVOKE privileges ON objects FROM grantees [ CASCADE | RESTRICT ]`
+    Revoke {
+        privileges: Privileges,
+        objects: GrantObjects,
+        grantees: Vec<Ident>,
+        /// The `CASCADE` or `RESTRICT` behavior, if specified
+        behavior: Option<DropBehavior>,
+    },
+}
+
+impl Statement {
+    /// Whether this statement modifies table data, e.g. `INSERT`, `UPDATE`,
+    /// `DELETE` or `COPY`, also when following a `WITH` clause
+    pub fn is_dml(&self) -> bool {
+        match self {
+            Statement::Insert { .. }
+            | Statement::Directory { .. }
+            | Statement::Copy { .. }
+            | Statement::Update { .. }
+            | Statement::Delete { .. } => true,
+            Statement::Query(query) => matches!(
+                query.body,
+                SetExpr::Insert(_) | SetExpr::Update(_) | SetExpr::Delete(_)
+            ),
+            _ => false,
+        }
+    }
+
+    /// Whether this statement defines or changes schema objects, e.g.
+    /// `CREATE TABLE`, `ALTER TABLE`, `DROP` or `TRUNCATE`
+    pub fn is_ddl(&self) -> bool {
+        matches!(
+            self,
+            Statement::Truncate { .. }
+                | Statement::CreateView { .. }
+                | Statement::CreateTable { .. }
+                | Statement::CreateVirtualTable { .. }
+                | Statement::CreateIndex { .. }
+                | Statement::AlterTable { .. }
+                | Statement::AlterObject { .. }
+                | Statement::Drop { .. }
+                | Statement::CreateFunction { .. }
+                | Statement::CreateSequence { .. }
+                | Statement::CreateSchema { .. }
+                | Statement::CreateDatabase { .. }
+        )
+    }
+
+    /// Whether this statement is a query, i.e. `SELECT`, `VALUES` or a set
+    /// operation, optionally with a `WITH` clause
+    pub fn is_query(&self) -> bool {
+        matches!(self, Statement::Query(_)) && !self.is_dml()
+    }
 }
 
 impl fmt::Display for Statement {
@@ -771,6 +1185,7 @@ impl fmt::Display for Statement {
 
                 write!(f, "{}", statement)
             }
+            Statement::Describe { table_name } => write!(f, "DESCRIBE {}", table_name),
             Statement::Query(s) => write!(f, "{}", s),
             Statement::Directory {
                 overwrite,
@@ -820,6 +1235,7 @@ impl fmt::Display for Statement {
                 Ok(())
             }
             Statement::Analyze {
+                has_table_keyword,
                 table_name,
                 partitions,
                 for_columns,
@@ -828,7 +1244,15 @@ impl fmt::Display for Statement {
                 noscan,
                 compute_statistics,
             } => {
-                write!(f, "ANALYZE TABLE {}", table_name)?;
+                write!(
+                    f,
+                    "ANALYZE {table_keyword}{name}",
+                    table_keyword = if *has_table_keyword { "TABLE " } else { "" },
+                    name = table_name
+                )?;
+                if !*for_columns && !columns.is_empty() {
+                    write!(f, " ({})", display_comma_separated(columns))?;
+                }
                 if let Some(ref parts) = partitions {
                     if !parts.is_empty() {
                         write!(f, " PARTITION ({})", display_comma_separated(parts))?;
@@ -852,8 +1276,26 @@ impl fmt::Display for Statement {
                 }
                 Ok(())
             }
+            Statement::Vacuum {
+                options,
+                table_name,
+                columns,
+            } => {
+                write!(f, "VACUUM")?;
+                for option in options {
+                    write!(f, " {}", option)?;
+                }
+                if let Some(table_name) = table_name {
+                    write!(f, " {}", table_name)?;
+                    if !columns.is_empty() {
+                        write!(f, " ({})", display_comma_separated(columns))?;
+                    }
+                }
+                Ok(())
+            }
             Statement::Insert {
                 or,
+                replace_into,
                 table_name,
                 overwrite,
                 partitioned,
@@ -862,9 +1304,16 @@ impl fmt::Display for Statement {
                 source,
                 table,
                 format,
+                values_alias,
+                on_conflict,
+                assignments,
+                overriding,
+                returning,
             } => {
                 if let Some(action) = or {
                     write!(f, "INSERT OR {} INTO {} ", action, table_name)?;
+                } else if *replace_into {
+                    write!(f, "REPLACE INTO {} ", table_name)?;
                 } else {
                     write!(
                         f,
@@ -894,9 +1343,24 @@ impl fmt::Display for Statement {
                         write!(f, "({}) ", display_comma_separated(after_columns))?;
                     }
 
+                    if let Some(overriding) = overriding {
+                        write!(f, "{} ", overriding)?;
+                    }
                     if let Some(source) = source {
                         write!(f, "{}", source)?;
                     }
+                    if !assignments.is_empty() {
+                        write!(f, "SET {}", display_comma_separated(assignments))?;
+                    }
+                    if let Some(alias) = values_alias {
+                        write!(f, " AS {}", alias)?;
+                    }
+                    if let Some(on_conflict) = on_conflict {
+                        write!(f, " {}", on_conflict)?;
+                    }
+                }
+                if let Some(returning) = returning {
+                    write!(f, " RETURNING {}", display_comma_separated(returning))?;
                 }
                 Ok(())
             }
@@ -904,14 +1368,21 @@ impl fmt::Display for Statement {
             Statement::Copy {
                 table_name,
                 columns,
+                to,
+                target,
+                options,
                 values,
             } => {
                 write!(f, "COPY {}", table_name)?;
                 if !columns.is_empty() {
                     write!(f, " ({})", display_comma_separated(columns))?;
                 }
-                write!(f, " FROM stdin; ")?;
+                write!(f, " {} {}", if *to { "TO" } else { "FROM" }, target)?;
+                if !options.is_empty() {
+                    write!(f, " WITH ({})", display_comma_separated(options))?;
+                }
                 if !values.is_empty() {
+                    write!(f, "; ")?;
                     writeln!(f)?;
                     let mut delim = "";
                     for v in values {
@@ -923,31 +1394,48 @@ impl fmt::Display for Statement {
                             write!(f, "\\N")?;
                         }
                     }
+                    write!(f, "\n\\.")?;
                 }
-                write!(f, "\n\\.")
+                Ok(())
             }
             Statement::Update {
                 table_name,
                 assignments,
+                from,
                 selection,
+                returning,
             } => {
                 write!(f, "UPDATE {}", table_name)?;
                 if !assignments.is_empty() {
                     write!(f, " SET {}", display_comma_separated(assignments))?;
                 }
+                if !from.is_empty() {
+                    write!(f, " FROM {}", display_comma_separated(from))?;
+                }
                 if let Some(selection) = selection {
                     write!(f, " WHERE {}", selection)?;
                 }
+                if let Some(returning) = returning {
+                    write!(f, " RETURNING {}", display_comma_separated(returning))?;
+                }
                 Ok(())
             }
             Statement::Delete {
                 table_name,
+                using,
                 selection,
+                returning,
             } => {
                 write!(f, "DELETE FROM {}", table_name)?;
+                if !using.is_empty() {
+                    write!(f, " USING {}", display_comma_separated(using))?;
+                }
                 if let Some(selection) = selection {
                     write!(f, " WHERE {}", selection)?;
                 }
+                if let Some(returning) = returning {
+                    write!(f, " RETURNING {}", display_comma_separated(returning))?;
+                }
                 Ok(())
             }
             Statement::CreateDatabase {
@@ -976,6 +1464,7 @@ impl fmt::Display for Statement {
                 query,
                 materialized,
                 with_options,
+                with_check_option,
             } => {
                 write!(
                     f,
@@ -990,7 +1479,11 @@ impl fmt::Display for Statement {
                 if !columns.is_empty() {
                     write!(f, " ({})", display_comma_separated(columns))?;
                 }
-                write!(f, " AS {}", query)
+                write!(f, " AS {}", query)?;
+                if let Some(check_option) = with_check_option {
+                    write!(f, " WITH {} CHECK OPTION", check_option)?;
+                }
+                Ok(())
             }
             Statement::CreateTable {
                 name,
@@ -1009,6 +1502,12 @@ impl fmt::Display for Statement {
                 query,
                 without_rowid,
                 like,
+                inherits,
+                partition_by,
+                on_commit,
+                tablespace,
+                engine,
+                default_charset,
             } => {
                 // We want to allow the following options
                 // Empty column list, allowed by PostgreSQL:
@@ -1036,14 +1535,23 @@ impl fmt::Display for Statement {
                     // PostgreSQL allows `CREATE TABLE t ();`, but requires empty parens
                     write!(f, " ()")?;
                 }
+                if let Some(like) = like.as_ref().filter(|like| like.parenthesized) {
+                    write!(f, " {}", like)?;
+                }
                 // Only for SQLite
                 if *without_rowid {
                     write!(f, " WITHOUT ROWID")?;
                 }
 
                 // Only for Hive
-                if let Some(l) = like {
-                    write!(f, " LIKE {}", l)?;
+                if let Some(like) = like.as_ref().filter(|like| !like.parenthesized) {
+                    write!(f, " {}", like)?;
+                }
+                if !inherits.is_empty() {
+                    write!(f, " INHERITS ({})", display_comma_separated(inherits))?;
+                }
+                if let Some(partition_by) = partition_by {
+                    write!(f, " {}", partition_by)?;
                 }
                 match hive_distribution {
                     HiveDistributionStyle::PARTITIONED { columns } => {
@@ -1131,6 +1639,18 @@ impl fmt::Display for Statement {
                 if !with_options.is_empty() {
                     write!(f, " WITH ({})", display_comma_separated(with_options))?;
                 }
+                if let Some(on_commit) = on_commit {
+                    write!(f, " {}", on_commit)?;
+                }
+                if let Some(tablespace) = tablespace {
+                    write!(f, " TABLESPACE {}", tablespace)?;
+                }
+                if let Some(engine) = engine {
+                    write!(f, " ENGINE={}", engine)?;
+                }
+                if let Some(default_charset) = default_charset {
+                    write!(f, " DEFAULT CHARSET={}", default_charset)?;
+                }
                 if let Some(query) = query {
                     write!(f, " AS {}", query)?;
                 }
@@ -1157,36 +1677,72 @@ impl fmt::Display for Statement {
             Statement::CreateIndex {
                 name,
                 table_name,
+                using,
                 columns,
                 unique,
                 if_not_exists,
-            } => write!(
-                f,
-                "CREATE {unique}INDEX {if_not_exists}{name} ON {table_name}({columns})",
-                unique = if *unique { "UNIQUE " } else { "" },
-                if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
-                name = name,
-                table_name = table_name,
-                columns = display_separated(columns, ",")
-            ),
-            Statement::AlterTable { name, operation } => {
-                write!(f, "ALTER TABLE {} {}", name, operation)
+                include,
+                tablespace,
+                predicate,
+            } => {
+                write!(
+                    f,
+                    "CREATE {unique}INDEX {if_not_exists}{name} ON {table_name}",
+                    unique = if *unique { "UNIQUE " } else { "" },
+                    if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                    name = name,
+                    table_name = table_name,
+                )?;
+                if let Some(using) = using {
+                    write!(f, " USING {} ", using)?;
+                }
+                write!(f, "({})", display_separated(columns, ","))?;
+                if !include.is_empty() {
+                    write!(f, " INCLUDE ({})", display_comma_separated(include))?;
+                }
+                if let Some(tablespace) = tablespace {
+                    write!(f, " TABLESPACE {}", tablespace)?;
+                }
+                if let Some(predicate) = predicate {
+                    write!(f, " WHERE {}", predicate)?;
+                }
+                Ok(())
+            }
+            Statement::AlterTable { name, operations } => {
+                write!(
+                    f,
+                    "ALTER TABLE {} {}",
+                    name,
+                    display_comma_separated(operations)
+                )
             }
+            Statement::AlterObject {
+                object_type,
+                name,
+                operation,
+            } => write!(f, "ALTER {} {} {}", object_type, name, operation),
             Statement::Drop {
                 object_type,
                 if_exists,
                 names,
-                cascade,
+                behavior,
                 purge,
-            } => write!(
-                f,
-                "DROP {}{} {}{}{}",
-                object_type,
-                if *if_exists { " IF EXISTS" } else { "" },
-                display_comma_separated(names),
-                if *cascade { " CASCADE" } else { "" },
-                if *purge { " PURGE" } else { "" }
-            ),
+            } => {
+                write!(
+                    f,
+                    "DROP {}{} {}",
+                    object_type,
+                    if *if_exists { " IF EXISTS" } else { "" },
+                    display_comma_separated(names),
+                )?;
+                if let Some(behavior) = behavior {
+                    write!(f, " {}", behavior)?;
+                }
+                if *purge {
+                    f.write_str(" PURGE")?;
+                }
+                Ok(())
+            }
             Statement::SetVariable {
                 local,
                 variable,
@@ -1230,6 +1786,27 @@ impl fmt::Display for Statement {
                 }
                 Ok(())
             }
+            Statement::ShowTables {
+                full,
+                db_name,
+                filter,
+            } => {
+                write!(f, "SHOW {}TABLES", if *full { "FULL " } else { "" })?;
+                if let Some(db_name) = db_name {
+                    write!(f, " FROM {}", db_name)?;
+                }
+                if let Some(filter) = filter {
+                    write!(f, " {}", filter)?;
+                }
+                Ok(())
+            }
+            Statement::ShowVariables { filter } => {
+                write!(f, "SHOW VARIABLES")?;
+                if let Some(filter) = filter {
+                    write!(f, " {}", filter)?;
+                }
+                Ok(())
+            }
             Statement::StartTransaction { modes } => {
                 write!(f, "START TRANSACTION")?;
                 if !modes.is_empty() {
@@ -1250,6 +1827,48 @@ impl fmt::Display for Statement {
             Statement::Rollback { chain } => {
                 write!(f, "ROLLBACK{}", if *chain { " AND CHAIN" } else { "" },)
             }
+            Statement::CreateFunction {
+                or_replace,
+                name,
+                args,
+                return_type,
+                body,
+                language,
+            } => {
+                write!(
+                    f,
+                    "CREATE {or_replace}FUNCTION {name}({args})",
+                    or_replace = if *or_replace { "OR REPLACE " } else { "" },
+                    name = name,
+                    args = display_comma_separated(args),
+                )?;
+                if let Some(return_type) = return_type {
+                    write!(f, " RETURNS {}", return_type)?;
+                }
+                write!(f, " AS {}", body)?;
+                if let Some(language) = language {
+                    write!(f, " LANGUAGE {}", language)?;
+                }
+                Ok(())
+            }
+            Statement::CreateSequence {
+                temporary,
+                if_not_exists,
+                name,
+                options,
+            } => {
+                write!(
+                    f,
+                    "CREATE {temporary}SEQUENCE {if_not_exists}{name}",
+                    temporary = if *temporary { "TEMPORARY " } else { "" },
+                    if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                    name = name,
+                )?;
+                for option in options {
+                    write!(f, " {}", option)?;
+                }
+                Ok(())
+            }
             Statement::CreateSchema {
                 schema_name,
                 if_not_exists,
@@ -1290,29 +1909,84 @@ impl fmt::Display for Statement {
                 }
                 write!(f, "AS {}", statement)
             }
+            Statement::Grant {
+                privileges,
+                objects,
+                grantees,
+                with_grant_option,
+            } => {
+                write!(f, "GRANT {} ", privileges)?;
+                write!(f, "ON {} ", objects)?;
+                write!(f, "TO {}", display_comma_separated(grantees))?;
+                if *with_grant_option {
+                    write!(f, " WITH GRANT OPTION")?;
+                }
+                Ok(())
+            }
+            Statement::Revoke {
+                privileges,
+                objects,
+                grantees,
+                behavior,
+            } => {
+                write!(f, "REVOKE {} ", privileges)?;
+                write!(f, "ON {} ", objects)?;
+                write!(f, "FROM {}", display_comma_separated(grantees))?;
+                if let Some(behavior) = behavior {
+                    write!(f, " {}", behavior)?;
+                }
+                Ok(())
+            }
         }
     }
 }
 
-/// SQL assignment `foo = expr` as used in SQLUpdate
+/// SQL assignment `foo = expr` as used in UPDATE, `ON CONFLICT DO UPDATE`
+/// and MySQL's `INSERT ... SET`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Assignment {
-    pub id: Ident,
+    pub target: AssignmentTarget,
     pub value: Expr,
 }
 
 impl fmt::Display for Assignment {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} = {}", self.id, self.value)
+        write!(f, "{} = {}", self.target, self.value)
+    }
+}
+
+/// The left-hand side of an [Assignment]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AssignmentTarget {
+    /// A single column, e.g. `a = 1`
+    ColumnName(Ident),
+    /// A parenthesized list of columns, e.g. `(a, b) = (1, 2)`
+    Tuple(Vec<Ident>),
+}
+
+impl fmt::Display for AssignmentTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AssignmentTarget::ColumnName(column) => write!(f, "{}", column),
+            AssignmentTarget::Tuple(columns) => write!(f, "({})", display_comma_separated(columns)),
+        }
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FunctionArg {
-    Named { name: Ident, arg: Expr },
+    Named {
+        name: Ident,
+        arg: Expr,
+    },
     Unnamed(Expr),
+    /// A lone `*`, as in `COUNT(*)`
+    Wildcard,
+    /// `alias.*`, as in `COUNT(t.*)`
+    QualifiedWildcard(ObjectName),
 }
 
 impl fmt::Display for FunctionArg {
@@ -1320,6 +1994,8 @@ impl fmt::Display for FunctionArg {
         match self {
             FunctionArg::Named { name, arg } => write!(f, "{} => {}", name, arg),
             FunctionArg::Unnamed(unnamed_arg) => write!(f, "{}", unnamed_arg),
+            FunctionArg::Wildcard => f.write_str("*"),
+            FunctionArg::QualifiedWildcard(prefix) => write!(f, "{}.*", prefix),
         }
     }
 }
@@ -1334,6 +2010,14 @@ pub struct Function {
     pub over: Option<WindowSpec>,
     // aggregate functions may specify eg `COUNT(DISTINCT x)`
     pub distinct: bool,
+    /// `IGNORE NULLS` or `RESPECT NULLS` after the arguments of a window
+    /// function, e.g. `lag(x) IGNORE NULLS OVER (...)`
+    pub null_treatment: Option<NullTreatment>,
+    /// `ORDER BY` inside the argument list of an aggregate, e.g.
+    /// `array_agg(x ORDER BY y)`
+    pub order_by: Vec<OrderByExpr>,
+    /// `FILTER (WHERE <expr>)` after the arguments of an aggregate
+    pub filter: Option<Box<Expr>>,
 }
 
 impl fmt::Display for Function {
@@ -1344,11 +2028,21 @@ impl fmt::Display for Function {
         }
         write!(
             f,
-            "({}{})",
+            "({}{}",
             if self.distinct { "DISTINCT " } else { "" },
             display_comma_separated(&self.args),
         )?;
+        if !self.order_by.is_empty() {
+            write!(f, " ORDER BY {}", display_comma_separated(&self.order_by))?;
+        }
+        write!(f, ")")?;
 
+        if let Some(null_treatment) = &self.null_treatment {
+            write!(f, " {}", null_treatment)?;
+        }
+        if let Some(filter) = &self.filter {
+            write!(f, " FILTER (WHERE {})", filter)?;
+        }
         if let Some(o) = &self.over {
             write!(f, " OVER ({})", o)?;
         }
@@ -1356,6 +2050,23 @@ impl fmt::Display for Function {
     }
 }
 
+/// Whether a window function skips NULL input values
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NullTreatment {
+    IgnoreNulls,
+    RespectNulls,
+}
+
+impl fmt::Display for NullTreatment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            NullTreatment::IgnoreNulls => "IGNORE NULLS",
+            NullTreatment::RespectNulls => "RESPECT NULLS",
+        })
+    }
+}
+
 /// External table's available file format
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -1422,6 +2133,39 @@ impl fmt::Display for ListAgg {
     }
 }
 
+/// A MySQL `GROUP_CONCAT` invocation `GROUP_CONCAT( [ DISTINCT ] <expr>[, ...]
+/// [ ORDER BY <order_by>[, ...] ] [ SEPARATOR <separator> ] )`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GroupConcat {
+    pub distinct: bool,
+    pub exprs: Vec<Expr>,
+    pub order_by: Vec<OrderByExpr>,
+    pub separator: Option<String>,
+}
+
+impl fmt::Display for GroupConcat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "GROUP_CONCAT({}{}",
+            if self.distinct { "DISTINCT " } else { "" },
+            display_comma_separated(&self.exprs)
+        )?;
+        if !self.order_by.is_empty() {
+            write!(f, " ORDER BY {}", display_comma_separated(&self.order_by))?;
+        }
+        if let Some(separator) = &self.separator {
+            write!(
+                f,
+                " SEPARATOR '{}'",
+                value::escape_single_quote_string(separator)
+            )?;
+        }
+        write!(f, ")")
+    }
+}
+
 /// The `ON OVERFLOW` clause of a LISTAGG invocation
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -1457,6 +2201,33 @@ impl fmt::Display for ListAggOnOverflow {
     }
 }
 
+/// The search modifier of a MySQL `MATCH ... AGAINST` full-text search
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SearchModifier {
+    /// `IN NATURAL LANGUAGE MODE`
+    InNaturalLanguageMode,
+    /// `IN NATURAL LANGUAGE MODE WITH QUERY EXPANSION`
+    InNaturalLanguageModeWithQueryExpansion,
+    /// `IN BOOLEAN MODE`
+    InBooleanMode,
+    /// `WITH QUERY EXPANSION`
+    WithQueryExpansion,
+}
+
+impl fmt::Display for SearchModifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SearchModifier::InNaturalLanguageMode => "IN NATURAL LANGUAGE MODE",
+            SearchModifier::InNaturalLanguageModeWithQueryExpansion => {
+                "IN NATURAL LANGUAGE MODE WITH QUERY EXPANSION"
+            }
+            SearchModifier::InBooleanMode => "IN BOOLEAN MODE",
+            SearchModifier::WithQueryExpansion => "WITH QUERY EXPANSION",
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ObjectType {
@@ -1464,6 +2235,7 @@ pub enum ObjectType {
     View,
     Index,
     Schema,
+    Sequence,
 }
 
 impl fmt::Display for ObjectType {
@@ -1473,6 +2245,85 @@ impl fmt::Display for ObjectType {
             ObjectType::View => "VIEW",
             ObjectType::Index => "INDEX",
             ObjectType::Schema => "SCHEMA",
+            ObjectType::Sequence => "SEQUENCE",
+        })
+    }
+}
+
+/// Whether dropping an object also drops the objects that depend on it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DropBehavior {
+    Restrict,
+    Cascade,
+}
+
+impl fmt::Display for DropBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            DropBehavior::Restrict => "RESTRICT",
+            DropBehavior::Cascade => "CASCADE",
+        })
+    }
+}
+
+/// The source of a `COPY FROM` or the target of a `COPY TO`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CopyTarget {
+    Stdin,
+    Stdout,
+    File {
+        /// The path name of the input or output file
+        filename: String,
+    },
+}
+
+impl fmt::Display for CopyTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CopyTarget::Stdin => write!(f, "STDIN"),
+            CopyTarget::Stdout => write!(f, "STDOUT"),
+            CopyTarget::File { filename } => {
+                write!(f, "'{}'", value::escape_single_quote_string(filename))
+            }
+        }
+    }
+}
+
+/// An option in the `WITH ( ... )` list of a `COPY` statement, such as
+/// `FORMAT csv` or `HEADER`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CopyOption {
+    pub name: Ident,
+    pub value: Option<Expr>,
+}
+
+impl fmt::Display for CopyOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(value) = &self.value {
+            write!(f, " {}", value)?;
+        }
+        Ok(())
+    }
+}
+
+/// The scope of a view's `WITH CHECK OPTION`. A bare `WITH CHECK OPTION`
+/// is equivalent to `WITH CASCADED CHECK OPTION`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CheckOption {
+    Cascaded,
+    Local,
+}
+
+impl fmt::Display for CheckOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            CheckOption::Cascaded => "CASCADED",
+            CheckOption::Local => "LOCAL",
         })
     }
 }
@@ -1663,6 +2514,334 @@ impl fmt::Display for SqliteOnConflict {
     }
 }
 
+/// An option of `VACUUM`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum VacuumOption {
+    Full,
+    Freeze,
+    Verbose,
+    Analyze,
+}
+
+impl fmt::Display for VacuumOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            VacuumOption::Full => "FULL",
+            VacuumOption::Freeze => "FREEZE",
+            VacuumOption::Verbose => "VERBOSE",
+            VacuumOption::Analyze => "ANALYZE",
+        })
+    }
+}
+
+/// An option of `CREATE SEQUENCE`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SequenceOptions {
+    /// `INCREMENT [ BY ] increment`; the flag records whether `BY` was present
+    IncrementBy(Expr, bool),
+    /// `MINVALUE minvalue` or `NO MINVALUE` (`None`)
+    MinValue(Option<Expr>),
+    /// `MAXVALUE maxvalue` or `NO MAXVALUE` (`None`)
+    MaxValue(Option<Expr>),
+    /// `START [ WITH ] start`; the flag records whether `WITH` was present
+    StartWith(Expr, bool),
+    /// `CACHE cache`
+    Cache(Expr),
+    /// `CYCLE` (`true`) or `NO CYCLE` (`false`)
+    Cycle(bool),
+}
+
+impl fmt::Display for SequenceOptions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SequenceOptions::IncrementBy(increment, by) => write!(
+                f,
+                "INCREMENT {by}{increment}",
+                by = if *by { "BY " } else { "" },
+                increment = increment
+            ),
+            SequenceOptions::MinValue(Some(value)) => write!(f, "MINVALUE {}", value),
+            SequenceOptions::MinValue(None) => write!(f, "NO MINVALUE"),
+            SequenceOptions::MaxValue(Some(value)) => write!(f, "MAXVALUE {}", value),
+            SequenceOptions::MaxValue(None) => write!(f, "NO MAXVALUE"),
+            SequenceOptions::StartWith(start, with) => write!(
+                f,
+                "START {with}{start}",
+                with = if *with { "WITH " } else { "" },
+                start = start
+            ),
+            SequenceOptions::Cache(cache) => write!(f, "CACHE {}", cache),
+            SequenceOptions::Cycle(true) => write!(f, "CYCLE"),
+            SequenceOptions::Cycle(false) => write!(f, "NO CYCLE"),
+        }
+    }
+}
+
+/// An argument in the signature of `CREATE FUNCTION`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CreateFunctionArg {
+    pub name: Option<Ident>,
+    pub data_type: DataType,
+}
+
+impl fmt::Display for CreateFunctionArg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(name) = &self.name {
+            write!(f, "{} ", name)?;
+        }
+        write!(f, "{}", self.data_type)
+    }
+}
+
+/// The body of `CREATE FUNCTION`, kept as the raw text of the string it was
+/// written as
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FunctionDefinition {
+    /// `'...'`
+    SingleQuoted(String),
+    /// `$$...$$` or `$tag$...$tag$`
+    DollarQuoted(DollarQuotedString),
+}
+
+impl fmt::Display for FunctionDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FunctionDefinition::SingleQuoted(s) => {
+                write!(f, "'{}'", value::escape_single_quote_string(s))
+            }
+            FunctionDefinition::DollarQuoted(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// `OVERRIDING { SYSTEM | USER } VALUE` clause of INSERT, which decides
+/// whether the supplied values or the defaults win for identity columns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Overriding {
+    SystemValue,
+    UserValue,
+}
+
+impl fmt::Display for Overriding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Overriding::SystemValue => write!(f, "OVERRIDING SYSTEM VALUE"),
+            Overriding::UserValue => write!(f, "OVERRIDING USER VALUE"),
+        }
+    }
+}
+
+/// PostgreSQL `ON CONFLICT [ conflict_target ] conflict_action` clause of INSERT
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OnConflict {
+    pub conflict_target: Option<ConflictTarget>,
+    pub action: OnConflictAction,
+}
+
+impl fmt::Display for OnConflict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ON CONFLICT")?;
+        if let Some(target) = &self.conflict_target {
+            write!(f, " {}", target)?;
+        }
+        write!(f, " {}", self.action)
+    }
+}
+
+/// The unique index (or constraint) whose violation an `ON CONFLICT`
+/// clause handles
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ConflictTarget {
+    /// `(col1, col2, ...) [ WHERE index_predicate ]`
+    Columns {
+        columns: Vec<Ident>,
+        predicate: Option<Expr>,
+    },
+    /// `ON CONSTRAINT constraint_name`
+    OnConstraint(ObjectName),
+}
+
+impl fmt::Display for ConflictTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConflictTarget::Columns { columns, predicate } => {
+                write!(f, "({})", display_comma_separated(columns))?;
+                if let Some(predicate) = predicate {
+                    write!(f, " WHERE {}", predicate)?;
+                }
+                Ok(())
+            }
+            ConflictTarget::OnConstraint(name) => write!(f, "ON CONSTRAINT {}", name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OnConflictAction {
+    DoNothing,
+    DoUpdate {
+        assignments: Vec<Assignment>,
+        selection: Option<Box<Expr>>,
+    },
+}
+
+impl fmt::Display for OnConflictAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OnConflictAction::DoNothing => write!(f, "DO NOTHING"),
+            OnConflictAction::DoUpdate {
+                assignments,
+                selection,
+            } => {
+                write!(f, "DO UPDATE SET {}", display_comma_separated(assignments))?;
+                if let Some(selection) = selection {
+                    write!(f, " WHERE {}", selection)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Privileges granted in a GRANT statement or revoked in a REVOKE statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Privileges {
+    /// All privileges applicable to the object type
+    All {
+        /// Optional keyword from the spec, ignored in practice
+        with_privileges_keyword: bool,
+    },
+    /// Specific privileges (e.g. `SELECT`, `INSERT`)
+    Actions(Vec<Action>),
+}
+
+impl fmt::Display for Privileges {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Privileges::All {
+                with_privileges_keyword,
+            } => {
+                write!(
+                    f,
+                    "ALL{}",
+                    if *with_privileges_keyword {
+                        " PRIVILEGES"
+                    } else {
+                        ""
+                    }
+                )
+            }
+            Privileges::Actions(actions) => {
+                write!(f, "{}", display_comma_separated(actions))
+            }
+        }
+    }
+}
+
+/// A privilege on a database object (table, sequence, etc.).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Action {
+    Connect,
+    Create,
+    Delete,
+    Execute,
+    Insert { columns: Option<Vec<Ident>> },
+    References { columns: Option<Vec<Ident>> },
+    Select { columns: Option<Vec<Ident>> },
+    Temporary,
+    Trigger,
+    Truncate,
+    Update { columns: Option<Vec<Ident>> },
+    Usage,
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Action::Connect => f.write_str("CONNECT")?,
+            Action::Create => f.write_str("CREATE")?,
+            Action::Delete => f.write_str("DELETE")?,
+            Action::Execute => f.write_str("EXECUTE")?,
+            Action::Insert { .. } => f.write_str("INSERT")?,
+            Action::References { .. } => f.write_str("REFERENCES")?,
+            Action::Select { .. } => f.write_str("SELECT")?,
+            Action::Temporary => f.write_str("TEMPORARY")?,
+            Action::Trigger => f.write_str("TRIGGER")?,
+            Action::Truncate => f.write_str("TRUNCATE")?,
+            Action::Update { .. } => f.write_str("UPDATE")?,
+            Action::Usage => f.write_str("USAGE")?,
+        };
+        match self {
+            Action::Insert { columns }
+            | Action::References { columns }
+            | Action::Select { columns }
+            | Action::Update { columns } => {
+                if let Some(columns) = columns {
+                    write!(f, " ({})", display_comma_separated(columns))?;
+                }
+            }
+            _ => (),
+        };
+        Ok(())
+    }
+}
+
+/// Objects on which privileges are granted in a GRANT statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GrantObjects {
+    /// Grant privileges on `ALL SEQUENCES IN SCHEMA <schema_name> [, ...]`
+    AllSequencesInSchema { schemas: Vec<ObjectName> },
+    /// Grant privileges on `ALL TABLES IN SCHEMA <schema_name> [, ...]`
+    AllTablesInSchema { schemas: Vec<ObjectName> },
+    /// Grant privileges on specific schemas
+    Schemas(Vec<ObjectName>),
+    /// Grant privileges on specific sequences
+    Sequences(Vec<ObjectName>),
+    /// Grant privileges on specific tables
+    Tables(Vec<ObjectName>),
+}
+
+impl fmt::Display for GrantObjects {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GrantObjects::Sequences(sequences) => {
+                write!(f, "SEQUENCE {}", display_comma_separated(sequences))
+            }
+            GrantObjects::Schemas(schemas) => {
+                write!(f, "SCHEMA {}", display_comma_separated(schemas))
+            }
+            GrantObjects::Tables(tables) => {
+                write!(f, "{}", display_comma_separated(tables))
+            }
+            GrantObjects::AllSequencesInSchema { schemas } => {
+                write!(
+                    f,
+                    "ALL SEQUENCES IN SCHEMA {}",
+                    display_comma_separated(schemas)
+                )
+            }
+            GrantObjects::AllTablesInSchema { schemas } => {
+                write!(
+                    f,
+                    "ALL TABLES IN SCHEMA {}",
+                    display_comma_separated(schemas)
+                )
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1671,5 +2850,6 @@ mod tests {
     fn test_window_frame_default() {
         let window_frame = WindowFrame::default();
         assert_eq!(WindowFrameBound::Preceding(None), window_frame.start_bound);
+        assert_eq!(None, window_frame.exclude);
     }
 }
@@ -10,11 +10,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
 use core::fmt;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// The binding power of prefix `NOT`
+pub(crate) const UNARY_NOT_PREC: u8 = 15;
+/// The binding power of `IS [ NOT ] NULL`
+pub(crate) const IS_PREC: u8 = 17;
+/// The binding power of `BETWEEN`, `IN` and the pattern matching operators,
+/// which is that of the comparison operators
+pub(crate) const BETWEEN_PREC: u8 = 20;
+/// The binding power of binary `+` / `-` and of the prefix operators other
+/// than `NOT`
+pub(crate) const PLUS_MINUS_PREC: u8 = 30;
+
 /// Unary operators
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -71,10 +84,6 @@ pub enum BinaryOperator {
     NotEq,
     And,
     Or,
-    Like,
-    NotLike,
-    ILike,
-    NotILike,
     BitwiseOr,
     BitwiseAnd,
     BitwiseXor,
@@ -85,6 +94,75 @@ pub enum BinaryOperator {
     PGRegexIMatch,
     PGRegexNotMatch,
     PGRegexNotIMatch,
+    /// `@>`, e.g. an array or range containing another
+    PGContains,
+    /// `<@`, e.g. an array or range contained by another
+    PGContainedBy,
+    /// `&&`, e.g. arrays or ranges that overlap
+    PGOverlap,
+    /// `?`, a jsonb value containing a key
+    PGExists,
+    /// `?|`, a jsonb value containing any of the keys
+    PGExistsAny,
+    /// `?&`, a jsonb value containing all of the keys
+    PGExistsAll,
+    /// `->`, a json object field (or array element)
+    PGJsonGet,
+    /// `->>`, a json object field (or array element) as text
+    PGJsonGetText,
+    /// `#>`, the json object at a path
+    PGJsonGetPath,
+    /// `#>>`, the json object at a path as text
+    PGJsonGetPathText,
+    /// An operator declared by the dialect, see `Dialect::custom_operators`
+    Custom(String),
+}
+
+impl BinaryOperator {
+    /// The binding power of the operator, the higher the tighter: the parser
+    /// builds the tree from it, and `Display` adds the parentheses it needs.
+    /// `None` for a `Custom` operator, whose precedence is up to the dialect.
+    pub fn precedence(&self) -> Option<u8> {
+        Some(match self {
+            BinaryOperator::Or => 5,
+            BinaryOperator::And => 10,
+            BinaryOperator::Eq
+            | BinaryOperator::NotEq
+            | BinaryOperator::Gt
+            | BinaryOperator::Lt
+            | BinaryOperator::GtEq
+            | BinaryOperator::LtEq
+            | BinaryOperator::Spaceship
+            | BinaryOperator::PGRegexMatch
+            | BinaryOperator::PGRegexIMatch
+            | BinaryOperator::PGRegexNotMatch
+            | BinaryOperator::PGRegexNotIMatch => BETWEEN_PREC,
+            // PostgreSQL groups these with the other operators it has no
+            // dedicated precedence for, between comparisons and `+`/`-`
+            BinaryOperator::BitwiseOr
+            | BinaryOperator::PGContains
+            | BinaryOperator::PGContainedBy
+            | BinaryOperator::PGOverlap
+            | BinaryOperator::PGExists
+            | BinaryOperator::PGExistsAny
+            | BinaryOperator::PGExistsAll
+            | BinaryOperator::PGJsonGet
+            | BinaryOperator::PGJsonGetText
+            | BinaryOperator::PGJsonGetPath
+            | BinaryOperator::PGJsonGetPathText => 21,
+            BinaryOperator::BitwiseXor
+            | BinaryOperator::PGBitwiseXor
+            | BinaryOperator::PGBitwiseShiftLeft
+            | BinaryOperator::PGBitwiseShiftRight => 22,
+            BinaryOperator::BitwiseAnd => 23,
+            BinaryOperator::Plus | BinaryOperator::Minus => PLUS_MINUS_PREC,
+            BinaryOperator::Multiply
+            | BinaryOperator::Divide
+            | BinaryOperator::Modulo
+            | BinaryOperator::StringConcat => 40,
+            BinaryOperator::Custom(_) => return None,
+        })
+    }
 }
 
 impl fmt::Display for BinaryOperator {
@@ -105,10 +183,6 @@ impl fmt::Display for BinaryOperator {
             BinaryOperator::NotEq => "<>",
             BinaryOperator::And => "AND",
             BinaryOperator::Or => "OR",
-            BinaryOperator::Like => "LIKE",
-            BinaryOperator::NotLike => "NOT LIKE",
-            BinaryOperator::ILike => "ILIKE",
-            BinaryOperator::NotILike => "NOT ILIKE",
             BinaryOperator::BitwiseOr => "|",
             BinaryOperator::BitwiseAnd => "&",
             BinaryOperator::BitwiseXor => "^",
@@ -119,6 +193,17 @@ impl fmt::Display for BinaryOperator {
             BinaryOperator::PGRegexIMatch => "~*",
             BinaryOperator::PGRegexNotMatch => "!~",
             BinaryOperator::PGRegexNotIMatch => "!~*",
+            BinaryOperator::PGContains => "@>",
+            BinaryOperator::PGContainedBy => "<@",
+            BinaryOperator::PGOverlap => "&&",
+            BinaryOperator::PGExists => "?",
+            BinaryOperator::PGExistsAny => "?|",
+            BinaryOperator::PGExistsAll => "?&",
+            BinaryOperator::PGJsonGet => "->",
+            BinaryOperator::PGJsonGetText => "->>",
+            BinaryOperator::PGJsonGetPath => "#>",
+            BinaryOperator::PGJsonGetPathText => "#>>",
+            BinaryOperator::Custom(op) => op,
         })
     }
 }
@@ -11,7 +11,7 @@
 // limitations under the License.
 
 #[cfg(not(feature = "std"))]
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, string::ToString, vec::Vec};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -78,7 +78,11 @@ pub enum SetExpr {
         right: Box<SetExpr>,
     },
     Values(Values),
+    /// A data-modifying statement following a `WITH` clause, e.g.
+    /// `WITH x AS (...) INSERT INTO t SELECT * FROM x`
     Insert(Statement),
+    Update(Statement),
+    Delete(Statement),
     // TODO: ANSI SQL supports `TABLE` here.
 }
 
@@ -89,6 +93,8 @@ impl fmt::Display for SetExpr {
             SetExpr::Query(q) => write!(f, "({})", q),
             SetExpr::Values(v) => write!(f, "{}", v),
             SetExpr::Insert(v) => write!(f, "{}", v),
+            SetExpr::Update(v) => write!(f, "{}", v),
+            SetExpr::Delete(v) => write!(f, "{}", v),
             SetExpr::SetOperation {
                 left,
                 right,
@@ -256,11 +262,19 @@ pub struct Cte {
     pub alias: TableAlias,
     pub query: Query,
     pub from: Option<Ident>,
+    /// `MATERIALIZED` (true) or `NOT MATERIALIZED` (false) (PostgreSQL)
+    pub materialized: Option<bool>,
 }
 
 impl fmt::Display for Cte {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} AS ({})", self.alias, self.query)?;
+        write!(f, "{} AS ", self.alias)?;
+        match self.materialized {
+            Some(true) => f.write_str("MATERIALIZED ")?,
+            Some(false) => f.write_str("NOT MATERIALIZED ")?,
+            None => (),
+        }
+        write!(f, "({})", self.query)?;
         if let Some(ref fr) = self.from {
             write!(f, " FROM {}", fr)?;
         }
@@ -277,9 +291,9 @@ pub enum SelectItem {
     /// An expression, followed by `[ AS ] alias`
     ExprWithAlias { expr: Expr, alias: Ident },
     /// `alias.*` or even `schema.table.*`
-    QualifiedWildcard(ObjectName),
+    QualifiedWildcard(ObjectName, WildcardAdditionalOptions),
     /// An unqualified `*`
-    Wildcard,
+    Wildcard(WildcardAdditionalOptions),
 }
 
 impl fmt::Display for SelectItem {
@@ -287,9 +301,48 @@ impl fmt::Display for SelectItem {
         match &self {
             SelectItem::UnnamedExpr(expr) => write!(f, "{}", expr),
             SelectItem::ExprWithAlias { expr, alias } => write!(f, "{} AS {}", expr, alias),
-            SelectItem::QualifiedWildcard(prefix) => write!(f, "{}.*", prefix),
-            SelectItem::Wildcard => write!(f, "*"),
+            SelectItem::QualifiedWildcard(prefix, additional_options) => {
+                write!(f, "{}.*{}", prefix, additional_options)
+            }
+            SelectItem::Wildcard(additional_options) => write!(f, "*{}", additional_options),
+        }
+    }
+}
+
+/// Modifiers of a wildcard in a projection, e.g. `* EXCLUDE (a) REPLACE (b + 1 AS b)`
+/// (BigQuery, Snowflake)
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WildcardAdditionalOptions {
+    /// Columns left out of the wildcard: `EXCLUDE (col, ...)`
+    pub exclude: Option<Vec<Ident>>,
+    /// Columns whose value is replaced: `REPLACE (expr AS col, ...)`
+    pub replace: Option<Vec<ReplaceSelectElement>>,
+}
+
+impl fmt::Display for WildcardAdditionalOptions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(exclude) = &self.exclude {
+            write!(f, " EXCLUDE ({})", display_comma_separated(exclude))?;
         }
+        if let Some(replace) = &self.replace {
+            write!(f, " REPLACE ({})", display_comma_separated(replace))?;
+        }
+        Ok(())
+    }
+}
+
+/// One `expr AS column` element of a wildcard's `REPLACE` modifier
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ReplaceSelectElement {
+    pub expr: Expr,
+    pub column_name: Ident,
+}
+
+impl fmt::Display for ReplaceSelectElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} AS {}", self.expr, self.column_name)
     }
 }
 
@@ -505,6 +558,19 @@ pub struct OrderByExpr {
     pub asc: Option<bool>,
     /// Optional `NULLS FIRST` or `NULLS LAST`
     pub nulls_first: Option<bool>,
+    /// Postgres-specific `USING <operator>`, in place of `ASC` or `DESC`
+    pub using: Option<BinaryOperator>,
+}
+
+impl OrderByExpr {
+    /// The position of the output column to sort by, if this is an
+    /// ordinal such as the `1` in `ORDER BY 1`
+    pub fn ordinal(&self) -> Option<u64> {
+        match &self.expr {
+            Expr::Value(Value::Number(n, _)) => n.to_string().parse().ok(),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for OrderByExpr {
@@ -515,6 +581,9 @@ impl fmt::Display for OrderByExpr {
             Some(false) => write!(f, " DESC")?,
             None => (),
         }
+        if let Some(op) = &self.using {
+            write!(f, " USING {}", op)?;
+        }
         match self.nulls_first {
             Some(true) => write!(f, " NULLS FIRST")?,
             Some(false) => write!(f, " NULLS LAST")?,
@@ -67,7 +67,7 @@ impl fmt::Display for Value {
             Value::Number(v, l) => write!(f, "{}{long}", v, long = if *l { "L" } else { "" }),
             Value::DoubleQuotedString(v) => write!(f, "\"{}\"", v),
             Value::SingleQuotedString(v) => write!(f, "'{}'", escape_single_quote_string(v)),
-            Value::NationalStringLiteral(v) => write!(f, "N'{}'", v),
+            Value::NationalStringLiteral(v) => write!(f, "N'{}'", escape_single_quote_string(v)),
             Value::HexStringLiteral(v) => write!(f, "X'{}'", v),
             Value::Boolean(v) => write!(f, "{}", v),
             Value::Interval {
@@ -139,13 +139,31 @@ impl fmt::Display for DateTimeField {
     }
 }
 
-pub struct EscapeSingleQuoteString<'a>(&'a str);
+/// The contents of a dollar-quoted string, kept verbatim
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DollarQuotedString {
+    pub value: String,
+    pub tag: Option<String>,
+}
 
-impl<'a> fmt::Display for EscapeSingleQuoteString<'a> {
+impl fmt::Display for DollarQuotedString {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for c in self.0.chars() {
-            if c == '\'' {
-                write!(f, "\'\'")?;
+        let tag = self.tag.as_deref().unwrap_or("");
+        write!(f, "${}${}${}$", tag, self.value, tag)
+    }
+}
+
+pub struct EscapeQuotedString<'a> {
+    string: &'a str,
+    quote: char,
+}
+
+impl<'a> fmt::Display for EscapeQuotedString<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for c in self.string.chars() {
+            if c == self.quote {
+                write!(f, "{}{}", c, c)?;
             } else {
                 write!(f, "{}", c)?;
             }
@@ -154,6 +172,12 @@ impl<'a> fmt::Display for EscapeSingleQuoteString<'a> {
     }
 }
 
-pub fn escape_single_quote_string(s: &str) -> EscapeSingleQuoteString<'_> {
-    EscapeSingleQuoteString(s)
+/// Escape `string` for use between delimiters ending with `quote`, by
+/// doubling every occurrence of `quote`
+pub fn escape_quoted_string(string: &str, quote: char) -> EscapeQuotedString<'_> {
+    EscapeQuotedString { string, quote }
+}
+
+pub fn escape_single_quote_string(s: &str) -> EscapeQuotedString<'_> {
+    escape_quoted_string(s, '\'')
 }
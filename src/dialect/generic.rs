@@ -17,20 +17,19 @@ pub struct GenericDialect;
 
 impl Dialect for GenericDialect {
     fn is_identifier_start(&self, ch: char) -> bool {
-        ('a'..='z').contains(&ch)
-            || ('A'..='Z').contains(&ch)
-            || ch == '_'
-            || ch == '#'
-            || ch == '@'
+        if ch.is_ascii() {
+            ch.is_ascii_alphabetic() || ch == '_' || ch == '#' || ch == '@'
+        } else {
+            // Accept non-ASCII letters, e.g. `café` or `名前`
+            ch.is_alphabetic()
+        }
     }
 
     fn is_identifier_part(&self, ch: char) -> bool {
-        ('a'..='z').contains(&ch)
-            || ('A'..='Z').contains(&ch)
-            || ('0'..='9').contains(&ch)
-            || ch == '@'
-            || ch == '$'
-            || ch == '#'
-            || ch == '_'
+        if ch.is_ascii() {
+            ch.is_ascii_alphanumeric() || ch == '@' || ch == '$' || ch == '#' || ch == '_'
+        } else {
+            ch.is_alphanumeric()
+        }
     }
 }
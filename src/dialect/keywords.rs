@@ -70,6 +70,7 @@ define_keywords!(
     ABS,
     ACTION,
     ADD,
+    AGAINST,
     ALL,
     ALLOCATE,
     ALTER,
@@ -98,8 +99,10 @@ define_keywords!(
     BEGIN_PARTITION,
     BETWEEN,
     BIGINT,
+    BIGSERIAL,
     BINARY,
     BLOB,
+    BOOL,
     BOOLEAN,
     BOTH,
     BY,
@@ -118,8 +121,10 @@ define_keywords!(
     CHAR,
     CHARACTER,
     CHARACTER_LENGTH,
+    CHARSET,
     CHAR_LENGTH,
     CHECK,
+    CIDR,
     CLOB,
     CLOSE,
     CLUSTER,
@@ -128,10 +133,12 @@ define_keywords!(
     COLLECT,
     COLUMN,
     COLUMNS,
+    COMMENT,
     COMMIT,
     COMMITTED,
     COMPUTE,
     CONDITION,
+    CONFLICT,
     CONNECT,
     CONSTRAINT,
     CONTAINS,
@@ -161,6 +168,7 @@ define_keywords!(
     CURRENT_USER,
     CURSOR,
     CYCLE,
+    DATA,
     DATABASE,
     DATE,
     DAY,
@@ -169,6 +177,8 @@ define_keywords!(
     DECIMAL,
     DECLARE,
     DEFAULT,
+    DEFERRABLE,
+    DEFERRED,
     DELETE,
     DELIMITED,
     DENSE_RANK,
@@ -180,6 +190,7 @@ define_keywords!(
     DISCONNECT,
     DISTINCT,
     DISTRIBUTE,
+    DO,
     DOUBLE,
     DROP,
     DYNAMIC,
@@ -190,15 +201,20 @@ define_keywords!(
     END_EXEC = "END-EXEC",
     END_FRAME,
     END_PARTITION,
+    ENGINE,
+    ENUM,
     EQUALS,
     ERROR,
     ESCAPE,
     EVERY,
     EXCEPT,
+    EXCLUDE,
+    EXCLUDING,
     EXEC,
     EXECUTE,
     EXISTS,
     EXP,
+    EXPANSION,
     EXPLAIN,
     EXTENDED,
     EXTERNAL,
@@ -211,6 +227,8 @@ define_keywords!(
     FIRST,
     FIRST_VALUE,
     FLOAT,
+    FLOAT4,
+    FLOAT8,
     FLOOR,
     FOLLOWING,
     FOR,
@@ -218,6 +236,7 @@ define_keywords!(
     FORMAT,
     FRAME_ROW,
     FREE,
+    FREEZE,
     FROM,
     FULL,
     FUNCTION,
@@ -228,6 +247,8 @@ define_keywords!(
     GROUP,
     GROUPING,
     GROUPS,
+    GROUP_CONCAT,
+    HASH,
     HAVING,
     HEADER,
     HIVEVAR,
@@ -237,15 +258,25 @@ define_keywords!(
     IF,
     IGNORE,
     ILIKE,
+    IMMEDIATE,
     IN,
+    INCLUDE,
+    INCLUDING,
+    INCREMENT,
     INDEX,
     INDICATOR,
+    INET,
+    INHERITS,
+    INITIALLY,
     INNER,
     INOUT,
     INPUTFORMAT,
     INSENSITIVE,
     INSERT,
     INT,
+    INT2,
+    INT4,
+    INT8,
     INTEGER,
     INTERSECT,
     INTERSECTION,
@@ -254,6 +285,8 @@ define_keywords!(
     IS,
     ISOLATION,
     JOIN,
+    JSON,
+    JSONB,
     JSONFILE,
     KEY,
     LAG,
@@ -269,6 +302,7 @@ define_keywords!(
     LIKE,
     LIKE_REGEX,
     LIMIT,
+    LIST,
     LISTAGG,
     LN,
     LOCAL,
@@ -276,19 +310,24 @@ define_keywords!(
     LOCALTIMESTAMP,
     LOCATION,
     LOWER,
+    MACADDR,
     MANAGEDLOCATION,
     MATCH,
     MATERIALIZED,
     MAX,
+    MAXVALUE,
     MEMBER,
     MERGE,
     METADATA,
     METHOD,
     MIN,
     MINUTE,
+    MINVALUE,
     MOD,
+    MODE,
     MODIFIES,
     MODULE,
+    MONEY,
     MONTH,
     MSCK,
     MULTISET,
@@ -303,6 +342,7 @@ define_keywords!(
     NORMALIZE,
     NOSCAN,
     NOT,
+    NOTHING,
     NTH_VALUE,
     NTILE,
     NULL,
@@ -318,9 +358,11 @@ define_keywords!(
     ON,
     ONLY,
     OPEN,
+    OPTION,
     OR,
     ORC,
     ORDER,
+    OTHERS,
     OUT,
     OUTER,
     OUTPUTFORMAT,
@@ -328,6 +370,7 @@ define_keywords!(
     OVERFLOW,
     OVERLAPS,
     OVERLAY,
+    OVERRIDING,
     OVERWRITE,
     PARAMETER,
     PARQUET,
@@ -347,9 +390,12 @@ define_keywords!(
     PRECEDING,
     PRECISION,
     PREPARE,
+    PRESERVE,
     PRIMARY,
+    PRIVILEGES,
     PROCEDURE,
     PURGE,
+    QUERY,
     RANGE,
     RANK,
     RCFILE,
@@ -375,9 +421,11 @@ define_keywords!(
     REPAIR,
     REPEATABLE,
     REPLACE,
+    RESPECT,
     RESTRICT,
     RESULT,
     RETURN,
+    RETURNING,
     RETURNS,
     REVOKE,
     RIGHT,
@@ -387,6 +435,7 @@ define_keywords!(
     ROWID,
     ROWS,
     ROW_NUMBER,
+    SAFE_CAST,
     SAVEPOINT,
     SCHEMA,
     SCOPE,
@@ -395,8 +444,12 @@ define_keywords!(
     SECOND,
     SELECT,
     SENSITIVE,
+    SEPARATOR,
+    SEQUENCE,
     SEQUENCEFILE,
+    SEQUENCES,
     SERDE,
+    SERIAL,
     SERIALIZABLE,
     SESSION,
     SESSION_USER,
@@ -404,6 +457,7 @@ define_keywords!(
     SHOW,
     SIMILAR,
     SMALLINT,
+    SMALLSERIAL,
     SOME,
     SORT,
     SPECIFIC,
@@ -419,6 +473,7 @@ define_keywords!(
     STDDEV_POP,
     STDDEV_SAMP,
     STDIN,
+    STDOUT,
     STORED,
     STRING,
     SUBMULTISET,
@@ -432,7 +487,9 @@ define_keywords!(
     SYSTEM_TIME,
     SYSTEM_USER,
     TABLE,
+    TABLES,
     TABLESAMPLE,
+    TABLESPACE,
     TBLPROPERTIES,
     TEMP,
     TEMPORARY,
@@ -442,6 +499,8 @@ define_keywords!(
     TIES,
     TIME,
     TIMESTAMP,
+    TIMESTAMPTZ,
+    TIMETZ,
     TIMEZONE_HOUR,
     TIMEZONE_MINUTE,
     TINYINT,
@@ -459,6 +518,7 @@ define_keywords!(
     TRUE,
     TRUNCATE,
     TRY_CAST,
+    TYPE,
     UESCAPE,
     UNBOUNDED,
     UNCOMMITTED,
@@ -468,14 +528,18 @@ define_keywords!(
     UNNEST,
     UPDATE,
     UPPER,
+    USAGE,
     USER,
     USING,
     UUID,
+    VACUUM,
+    VALIDATE,
     VALUE,
     VALUES,
     VALUE_OF,
     VARBINARY,
     VARCHAR,
+    VARIABLES,
     VARYING,
     VAR_POP,
     VAR_SAMP,
@@ -519,6 +583,7 @@ pub const RESERVED_FOR_TABLE_ALIAS: &[Keyword] = &[
     Keyword::UNION,
     Keyword::EXCEPT,
     Keyword::INTERSECT,
+    Keyword::RETURNING,
     // Reserved only as a table alias in the `FROM`/`JOIN` clauses:
     Keyword::ON,
     Keyword::JOIN,
@@ -557,6 +622,7 @@ pub const RESERVED_FOR_COLUMN_ALIAS: &[Keyword] = &[
     Keyword::UNION,
     Keyword::EXCEPT,
     Keyword::INTERSECT,
+    Keyword::RETURNING,
     Keyword::CLUSTER,
     Keyword::DISTRIBUTE,
     // Reserved only as a column alias in the `SELECT` clause
@@ -11,6 +11,7 @@
 // limitations under the License.
 
 mod ansi;
+mod bigquery;
 mod generic;
 mod hive;
 pub mod keywords;
@@ -23,7 +24,10 @@ mod sqlite;
 use core::any::{Any, TypeId};
 use core::fmt::Debug;
 
+use crate::tokenizer::Token;
+
 pub use self::ansi::AnsiDialect;
+pub use self::bigquery::BigQueryDialect;
 pub use self::generic::GenericDialect;
 pub use self::hive::HiveDialect;
 pub use self::mssql::MsSqlDialect;
@@ -53,6 +57,60 @@ pub trait Dialect: Debug + Any {
     fn is_identifier_start(&self, ch: char) -> bool;
     /// Determine if a character is a valid unquoted identifier character
     fn is_identifier_part(&self, ch: char) -> bool;
+    /// Determine if `kw` (an unquoted word, in any case) is reserved in this
+    /// dialect, and so can't be used as a bare identifier or alias. The
+    /// keywords handled specially by the parser are always recognized as
+    /// such; this only covers words that would otherwise be parsed as
+    /// identifiers. The default implementation reserves nothing.
+    fn is_reserved_keyword(&self, _kw: &str) -> bool {
+        false
+    }
+    /// Does the dialect allow a trailing comma at the end of a comma-separated
+    /// list, e.g. `SELECT a, b, FROM t`? The default is `false`.
+    fn supports_trailing_comma(&self) -> bool {
+        false
+    }
+    /// Does the dialect accept numeric literals without digits on one side
+    /// of the decimal point, e.g. `.5` or `5.`? The parser records a warning
+    /// when it accepts one. The default is `true`.
+    fn supports_numeric_dot_shorthand(&self) -> bool {
+        true
+    }
+    /// Can block comments nest, as in `/* outer /* inner */ still outer */`?
+    /// If so, every `/*` needs its own `*/`; otherwise the first `*/` ends
+    /// the comment. The default is `false`.
+    fn supports_nested_comments(&self) -> bool {
+        false
+    }
+    /// Multi-character operators specific to the dialect, such as `===`.
+    /// The tokenizer emits the longest one matching the input as a
+    /// `Token::CustomOperator`, ahead of any built-in token, and the parser
+    /// treats it as a binary operator. The default is none.
+    fn custom_operators(&self) -> &[&str] {
+        &[]
+    }
+    /// The binding power of one of `custom_operators` when used as a binary
+    /// operator. The default matches the comparison operators.
+    fn custom_operator_precedence(&self, _op: &str) -> u8 {
+        20
+    }
+    /// The binding power of `token` when it follows an expression,
+    /// overriding the parser's built-in table, e.g. to make `OR` bind
+    /// tighter than `AND`. The default is `None`, i.e. no override.
+    fn precedence_of(&self, _token: &Token) -> Option<u8> {
+        None
+    }
+    /// Must a subquery in `FROM` be given an alias, as in
+    /// `FROM (SELECT ...) AS t`? The default is `false`.
+    fn requires_derived_table_alias(&self) -> bool {
+        false
+    }
+    /// The longest identifier, in bytes, that the dialect's backend keeps
+    /// without truncating. The parser records a warning for each longer
+    /// identifier. The default is `None`, i.e. no limit.
+    fn max_identifier_length(&self) -> Option<usize> {
+        None
+    }
 }
 
 impl dyn Dialect {
@@ -15,6 +15,10 @@ use crate::dialect::Dialect;
 #[derive(Debug)]
 pub struct MsSqlDialect {}
 
+/// Words reserved in this dialect that would otherwise be parsed as identifiers.
+/// See https://docs.microsoft.com/en-us/sql/t-sql/language-elements/reserved-keywords-transact-sql
+const RESERVED_KEYWORDS: &[&str] = &["USER"];
+
 impl Dialect for MsSqlDialect {
     fn is_delimited_identifier_start(&self, ch: char) -> bool {
         ch == '"' || ch == '['
@@ -39,4 +43,8 @@ impl Dialect for MsSqlDialect {
             || ch == '#'
             || ch == '_'
     }
+
+    fn is_reserved_keyword(&self, kw: &str) -> bool {
+        RESERVED_KEYWORDS.iter().any(|r| r.eq_ignore_ascii_case(kw))
+    }
 }
@@ -34,4 +34,9 @@ impl Dialect for MySqlDialect {
     fn is_delimited_identifier_start(&self, ch: char) -> bool {
         ch == '`'
     }
+
+    /// "Every derived table must have its own alias"
+    fn requires_derived_table_alias(&self) -> bool {
+        true
+    }
 }
@@ -15,19 +15,40 @@ use crate::dialect::Dialect;
 #[derive(Debug)]
 pub struct PostgreSqlDialect {}
 
+/// Words reserved in this dialect that would otherwise be parsed as identifiers.
+/// See https://www.postgresql.org/docs/current/sql-keywords-appendix.html
+const RESERVED_KEYWORDS: &[&str] = &["USER"];
+
 impl Dialect for PostgreSqlDialect {
     fn is_identifier_start(&self, ch: char) -> bool {
         // See https://www.postgresql.org/docs/11/sql-syntax-lexical.html#SQL-SYNTAX-IDENTIFIERS
-        // We don't yet support identifiers beginning with "letters with
-        // diacritical marks and non-Latin letters"
-        ('a'..='z').contains(&ch) || ('A'..='Z').contains(&ch) || ch == '_'
+        // Letters with diacritical marks and non-Latin letters are accepted
+        // as well, with a fast path for the common ASCII case.
+        if ch.is_ascii() {
+            ch.is_ascii_alphabetic() || ch == '_'
+        } else {
+            ch.is_alphabetic()
+        }
     }
 
     fn is_identifier_part(&self, ch: char) -> bool {
-        ('a'..='z').contains(&ch)
-            || ('A'..='Z').contains(&ch)
-            || ('0'..='9').contains(&ch)
-            || ch == '$'
-            || ch == '_'
+        if ch.is_ascii() {
+            ch.is_ascii_alphanumeric() || ch == '$' || ch == '_'
+        } else {
+            ch.is_alphanumeric()
+        }
+    }
+
+    fn is_reserved_keyword(&self, kw: &str) -> bool {
+        RESERVED_KEYWORDS.iter().any(|r| r.eq_ignore_ascii_case(kw))
+    }
+
+    fn supports_nested_comments(&self) -> bool {
+        true
+    }
+
+    fn max_identifier_length(&self) -> Option<usize> {
+        // NAMEDATALEN - 1, see https://www.postgresql.org/docs/current/limits.html
+        Some(63)
     }
 }
@@ -33,6 +33,8 @@ use crate::tokenizer::*;
 pub enum ParserError {
     TokenizerError(String),
     ParserError(String),
+    /// The input has more tokens than allowed, see [Parser::parse_sql_with_max_tokens]
+    TooManyTokens(usize),
 }
 
 // Use `Parser::expected` instead, if possible
@@ -68,81 +70,292 @@ use IsLateral::*;
 
 impl From<TokenizerError> for ParserError {
     fn from(e: TokenizerError) -> Self {
-        ParserError::TokenizerError(format!(
-            "{} at Line: {}, Column {}",
-            e.message, e.line, e.col
-        ))
+        ParserError::TokenizerError(e.to_string())
     }
 }
 
 impl fmt::Display for ParserError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "sql parser error: {}",
-            match self {
-                ParserError::TokenizerError(s) => s,
-                ParserError::ParserError(s) => s,
+        match self {
+            ParserError::TokenizerError(s) | ParserError::ParserError(s) => {
+                write!(f, "sql parser error: {}", s)
             }
-        )
+            ParserError::TooManyTokens(max_tokens) => write!(
+                f,
+                "sql parser error: Input too large: more than {} tokens",
+                max_tokens
+            ),
+        }
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for ParserError {}
 
+/// A [ParserError] along with the tokens surrounding the position where it
+/// occurred, as returned by [Parser::parse_sql_with_context].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParserErrorWithContext {
+    pub error: ParserError,
+    /// Up to `ERROR_CONTEXT_RADIUS` non-whitespace tokens on either side of
+    /// the error position (empty for tokenizer errors)
+    pub context: Vec<Token>,
+}
+
+/// The number of tokens on either side of the error position to report
+/// in [ParserErrorWithContext]
+const ERROR_CONTEXT_RADIUS: usize = 3;
+
+impl fmt::Display for ParserErrorWithContext {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.error)?;
+        if !self.context.is_empty() {
+            write!(f, " near `")?;
+            let mut delim = "";
+            for token in &self.context {
+                write!(f, "{}{}", delim, token)?;
+                delim = " ";
+            }
+            write!(f, "`")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParserErrorWithContext {}
+
+/// A SQL parser over a sequence of tokens.
+///
+/// The dialect is fixed when the parser is constructed and applies to every
+/// statement it parses: to parse with a different dialect, construct a new
+/// parser (re-tokenizing the input, since tokenization is dialect-specific
+/// too).
 pub struct Parser<'a> {
     tokens: Vec<Token>,
     /// The index of the first unprocessed token in `self.tokens`
     index: usize,
     dialect: &'a dyn Dialect,
+    /// Non-fatal diagnostics about accepted, but nonstandard, input
+    warnings: Vec<String>,
+    /// The token sequence separating statements, `;` by default
+    statement_terminator: Vec<Token>,
 }
 
 impl<'a> Parser<'a> {
     /// Parse the specified tokens
     pub fn new(tokens: Vec<Token>, dialect: &'a dyn Dialect) -> Self {
+        let mut warnings = vec![];
+        if let Some(max) = dialect.max_identifier_length() {
+            for token in &tokens {
+                match token {
+                    Token::Word(w) if w.value.len() > max => warnings.push(format!(
+                        "Identifier '{}' is longer than {} bytes",
+                        w.value, max
+                    )),
+                    _ => {}
+                }
+            }
+        }
         Parser {
             tokens,
             index: 0,
             dialect,
+            warnings,
+            statement_terminator: vec![Token::SemiColon],
         }
     }
 
+    /// Parse tokens which needn't come from [Tokenizer]: tools that lex
+    /// incrementally can hand over the tokens they already have. The same
+    /// as [Parser::new].
+    pub fn from_tokens(tokens: Vec<Token>, dialect: &'a dyn Dialect) -> Self {
+        Parser::new(tokens, dialect)
+    }
+
+    /// Separate statements with the given (non-empty) sequence of tokens
+    /// instead of `;`, e.g. `vec![Token::Div, Token::Div]` for input using a
+    /// custom delimiter such as one set by MySQL's `DELIMITER //`.
+    pub fn with_statement_terminator(mut self, terminator: Vec<Token>) -> Self {
+        self.statement_terminator = terminator;
+        self
+    }
+
+    /// The warnings recorded so far, such as for nonstandard numeric literals
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
     /// Parse a SQL statement and produce an Abstract Syntax Tree (AST)
     pub fn parse_sql(dialect: &dyn Dialect, sql: &str) -> Result<Vec<Statement>, ParserError> {
         let mut tokenizer = Tokenizer::new(dialect, sql);
         let tokens = tokenizer.tokenize()?;
         let mut parser = Parser::new(tokens, dialect);
+        debug!("Parsing sql '{}'...", sql);
+        parser.parse_statements()
+    }
+
+    /// Same as [Parser::parse_sql], but fails with [ParserError::TooManyTokens]
+    /// without parsing anything once the input has more than `max_tokens`
+    /// tokens (whitespace and comments included), to bound the work done on
+    /// untrusted input
+    pub fn parse_sql_with_max_tokens(
+        dialect: &dyn Dialect,
+        sql: &str,
+        max_tokens: usize,
+    ) -> Result<Vec<Statement>, ParserError> {
+        let mut tokenizer = Tokenizer::new(dialect, sql).with_max_tokens(max_tokens);
+        let tokens = match tokenizer.tokenize() {
+            Ok(tokens) => tokens,
+            Err(_) if tokenizer.max_tokens_exceeded() => {
+                return Err(ParserError::TooManyTokens(max_tokens))
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let mut parser = Parser::new(tokens, dialect);
+        debug!("Parsing sql '{}'...", sql);
+        parser.parse_statements()
+    }
+
+    /// Same as [Parser::parse_sql], but tokenizing and parsing one statement
+    /// at a time as the returned iterator is advanced, so that only the
+    /// tokens of the current statement are held in memory. Statements must
+    /// be separated by `;`, and `COPY ... FROM STDIN` data is not supported.
+    pub fn parse_sql_lazily(dialect: &'a dyn Dialect, sql: &'a str) -> StatementStream<'a> {
+        StatementStream {
+            dialect,
+            cursor: Cursor::new(dialect, sql),
+            warnings: vec![],
+            max_tokens: None,
+            done: false,
+        }
+    }
+
+    /// Same as [Parser::parse_sql], but also returns the warnings recorded
+    /// for the accepted, but nonstandard, input
+    pub fn parse_sql_with_warnings(
+        dialect: &dyn Dialect,
+        sql: &str,
+    ) -> Result<(Vec<Statement>, Vec<String>), ParserError> {
+        let mut tokenizer = Tokenizer::new(dialect, sql);
+        let tokens = tokenizer.tokenize()?;
+        let mut parser = Parser::new(tokens, dialect);
+        debug!("Parsing sql '{}'...", sql);
+        let statements = parser.parse_statements()?;
+        Ok((statements, parser.warnings))
+    }
+
+    /// Same as [Parser::parse_sql], but on failure also reports the tokens
+    /// surrounding the error position.
+    pub fn parse_sql_with_context(
+        dialect: &dyn Dialect,
+        sql: &str,
+    ) -> Result<Vec<Statement>, ParserErrorWithContext> {
+        let mut tokenizer = Tokenizer::new(dialect, sql);
+        let tokens = tokenizer.tokenize().map_err(|e| ParserErrorWithContext {
+            error: e.into(),
+            context: vec![],
+        })?;
+        let mut parser = Parser::new(tokens, dialect);
+        debug!("Parsing sql '{}'...", sql);
+        parser
+            .parse_statements()
+            .map_err(|error| ParserErrorWithContext {
+                error,
+                context: parser.error_context(),
+            })
+    }
+
+    /// Parse statements separated by the statement terminator (`;` unless
+    /// configured with [Parser::with_statement_terminator]) until the end of input
+    pub fn parse_statements(&mut self) -> Result<Vec<Statement>, ParserError> {
         let mut stmts = Vec::new();
         let mut expecting_statement_delimiter = false;
-        debug!("Parsing sql '{}'...", sql);
         loop {
             // ignore empty statements (between successive statement delimiters)
-            while parser.consume_token(&Token::SemiColon) {
+            while self.consume_statement_terminator() {
                 expecting_statement_delimiter = false;
             }
 
-            if parser.peek_token() == Token::EOF {
+            if self.peek_token() == Token::EOF {
                 break;
             }
             if expecting_statement_delimiter {
-                return parser.expected("end of statement", parser.peek_token());
+                return self.expected("end of statement", self.peek_token());
             }
 
-            let statement = parser.parse_statement()?;
+            let statement = self.parse_statement()?;
             stmts.push(statement);
             expecting_statement_delimiter = true;
         }
         Ok(stmts)
     }
 
+    /// Check whether the next tokens are the statement terminator. Only
+    /// whitespace before it is skipped: its tokens must be adjacent, so that
+    /// e.g. `/ /` doesn't match a `//` terminator.
+    fn at_statement_terminator(&self) -> bool {
+        let start = self.tokens[self.index.min(self.tokens.len())..]
+            .iter()
+            .position(|t| !matches!(t, Token::Whitespace(_)))
+            .map_or(self.tokens.len(), |offset| self.index + offset);
+        !self.statement_terminator.is_empty()
+            && self
+                .tokens
+                .get(start..start + self.statement_terminator.len())
+                == Some(&self.statement_terminator[..])
+    }
+
+    /// Consume the statement terminator if it comes next
+    fn consume_statement_terminator(&mut self) -> bool {
+        if self.at_statement_terminator() {
+            for _ in 0..self.statement_terminator.len() {
+                self.next_token();
+            }
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Return up to `ERROR_CONTEXT_RADIUS` non-whitespace tokens on either
+    /// side of the current position, for use in error messages.
+    fn error_context(&self) -> Vec<Token> {
+        let index = self.index.min(self.tokens.len());
+        let is_significant = |t: &&Token| !matches!(t, Token::Whitespace(_));
+        let mut context: Vec<Token> = self.tokens[..index]
+            .iter()
+            .rev()
+            .filter(is_significant)
+            .take(ERROR_CONTEXT_RADIUS)
+            .cloned()
+            .collect();
+        context.reverse();
+        context.extend(
+            self.tokens[index..]
+                .iter()
+                .filter(is_significant)
+                .take(ERROR_CONTEXT_RADIUS)
+                .cloned(),
+        );
+        context
+    }
+
     /// Parse a single top-level statement (such as SELECT, INSERT, CREATE, etc.),
     /// stopping before the statement separator, if any.
     pub fn parse_statement(&mut self) -> Result<Statement, ParserError> {
         match self.next_token() {
             Token::Word(w) => match w.keyword {
                 Keyword::EXPLAIN => Ok(self.parse_explain()?),
+                Keyword::DESCRIBE | Keyword::DESC if dialect_of!(self is MySqlDialect | GenericDialect) => {
+                    Ok(Statement::Describe {
+                        table_name: self.parse_object_name()?,
+                    })
+                }
                 Keyword::ANALYZE => Ok(self.parse_analyze()?),
+                Keyword::VACUUM if dialect_of!(self is PostgreSqlDialect | GenericDialect) => {
+                    Ok(self.parse_vacuum()?)
+                }
                 Keyword::SELECT | Keyword::WITH | Keyword::VALUES => {
                     self.prev_token();
                     Ok(Statement::Query(Box::new(self.parse_query()?)))
@@ -171,9 +384,10 @@ impl<'a> Parser<'a> {
                 Keyword::DEALLOCATE => Ok(self.parse_deallocate()?),
                 Keyword::EXECUTE => Ok(self.parse_execute()?),
                 Keyword::PREPARE => Ok(self.parse_prepare()?),
-                Keyword::REPLACE if dialect_of!(self is SQLiteDialect ) => {
-                    self.prev_token();
-                    Ok(self.parse_insert()?)
+                Keyword::GRANT => Ok(self.parse_grant()?),
+                Keyword::REVOKE => Ok(self.parse_revoke()?),
+                Keyword::REPLACE if dialect_of!(self is SQLiteDialect | MySqlDialect | GenericDialect) => {
+                    Ok(self.parse_insert_or_replace(true)?)
                 }
                 _ => self.expected("an SQL statement", Token::Word(w)),
             },
@@ -228,14 +442,19 @@ impl<'a> Parser<'a> {
     }
 
     pub fn parse_analyze(&mut self) -> Result<Statement, ParserError> {
-        self.expect_keyword(Keyword::TABLE)?;
+        let has_table_keyword = self.parse_keyword(Keyword::TABLE);
         let table_name = self.parse_object_name()?;
         let mut for_columns = false;
         let mut cache_metadata = false;
         let mut noscan = false;
         let mut partitions = None;
         let mut compute_statistics = false;
-        let mut columns = vec![];
+        // PostgreSQL names the columns to analyze in parentheses after the table
+        let mut columns = if has_table_keyword {
+            vec![]
+        } else {
+            self.parse_parenthesized_column_list(Optional)?
+        };
         loop {
             match self.parse_one_of_keywords(&[
                 Keyword::PARTITION,
@@ -273,6 +492,7 @@ impl<'a> Parser<'a> {
         }
 
         Ok(Statement::Analyze {
+            has_table_keyword,
             table_name,
             for_columns,
             columns,
@@ -283,6 +503,39 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse a PostgreSQL `VACUUM` statement (`VACUUM` has already been consumed)
+    pub fn parse_vacuum(&mut self) -> Result<Statement, ParserError> {
+        let mut options = vec![];
+        loop {
+            let option = match self.parse_one_of_keywords(&[
+                Keyword::FULL,
+                Keyword::FREEZE,
+                Keyword::VERBOSE,
+                Keyword::ANALYZE,
+            ]) {
+                Some(Keyword::FULL) => VacuumOption::Full,
+                Some(Keyword::FREEZE) => VacuumOption::Freeze,
+                Some(Keyword::VERBOSE) => VacuumOption::Verbose,
+                Some(Keyword::ANALYZE) => VacuumOption::Analyze,
+                _ => break,
+            };
+            options.push(option);
+        }
+        let (table_name, columns) = match self.peek_token() {
+            Token::Word(_) => {
+                let table_name = self.parse_object_name()?;
+                let columns = self.parse_parenthesized_column_list(Optional)?;
+                (Some(table_name), columns)
+            }
+            _ => (None, vec![]),
+        };
+        Ok(Statement::Vacuum {
+            options,
+            table_name,
+            columns,
+        })
+    }
+
     /// Parse a new expression
     pub fn parse_expr(&mut self) -> Result<Expr, ParserError> {
         self.parse_subexpr(0)
@@ -360,17 +613,41 @@ impl<'a> Parser<'a> {
                     Ok(Expr::Value(self.parse_value()?))
                 }
                 Keyword::CASE => self.parse_case_expr(),
-                Keyword::CAST => self.parse_cast_expr(),
-                Keyword::TRY_CAST => self.parse_try_cast_expr(),
+                Keyword::CAST => self.parse_cast_expr(CastKind::Cast),
+                Keyword::TRY_CAST => self.parse_cast_expr(CastKind::TryCast),
+                Keyword::SAFE_CAST if dialect_of!(self is BigQueryDialect | GenericDialect) => {
+                    self.parse_cast_expr(CastKind::SafeCast)
+                }
+                Keyword::ARRAY if self.peek_token() == Token::LBracket => {
+                    self.expect_token(&Token::LBracket)?;
+                    self.parse_array_expr()
+                }
                 Keyword::EXISTS => self.parse_exists_expr(),
                 Keyword::EXTRACT => self.parse_extract_expr(),
                 Keyword::SUBSTRING => self.parse_substring_expr(),
                 Keyword::TRIM => self.parse_trim_expr(),
                 Keyword::INTERVAL => self.parse_literal_interval(),
                 Keyword::LISTAGG => self.parse_listagg_expr(),
+                Keyword::GROUP_CONCAT if dialect_of!(self is MySqlDialect | GenericDialect) => {
+                    self.parse_group_concat_expr()
+                }
+                Keyword::CURRENT_DATE
+                | Keyword::CURRENT_TIME
+                | Keyword::CURRENT_TIMESTAMP
+                | Keyword::CURRENT_USER
+                    if self.peek_token() != Token::LParen =>
+                {
+                    Ok(Expr::NiladicFunction { name: w.to_ident() })
+                }
+                Keyword::MATCH if dialect_of!(self is MySqlDialect | GenericDialect) => {
+                    self.parse_match_against()
+                }
+                // Binary logical operators never start an expression, so
+                // don't mistake them for identifiers
+                Keyword::AND | Keyword::OR => self.expected("an expression", Token::Word(w)),
                 Keyword::NOT => Ok(Expr::UnaryOp {
                     op: UnaryOperator::Not,
-                    expr: Box::new(self.parse_subexpr(Self::UNARY_NOT_PREC)?),
+                    expr: Box::new(self.parse_subexpr(UNARY_NOT_PREC)?),
                 }),
                 // Here `w` is a word, check if it's a part of a multi-part
                 // identifier, a function call, or a simple identifier:
@@ -393,13 +670,15 @@ impl<'a> Parser<'a> {
                         }
                         if ends_with_wildcard {
                             Ok(Expr::QualifiedWildcard(id_parts))
-                        } else if self.consume_token(&Token::LParen) {
-                            self.prev_token();
+                        } else if self.peek_token() == Token::LParen {
                             self.parse_function(ObjectName(id_parts))
                         } else {
                             Ok(Expr::CompoundIdentifier(id_parts))
                         }
                     }
+                    _ if self.is_reserved_word(&w) => {
+                        self.expected("an expression", Token::Word(w))
+                    }
                     _ => Ok(Expr::Identifier(w.to_ident())),
                 },
             }, // End of Token::Word
@@ -412,7 +691,7 @@ impl<'a> Parser<'a> {
                 };
                 Ok(Expr::UnaryOp {
                     op,
-                    expr: Box::new(self.parse_subexpr(Self::PLUS_MINUS_PREC)?),
+                    expr: Box::new(self.parse_subexpr(PLUS_MINUS_PREC)?),
                 })
             }
             tok @ Token::DoubleExclamationMark
@@ -432,7 +711,7 @@ impl<'a> Parser<'a> {
                 };
                 Ok(Expr::UnaryOp {
                     op,
-                    expr: Box::new(self.parse_subexpr(Self::PLUS_MINUS_PREC)?),
+                    expr: Box::new(self.parse_subexpr(PLUS_MINUS_PREC)?),
                 })
             }
             Token::Number(_, _)
@@ -442,18 +721,21 @@ impl<'a> Parser<'a> {
                 self.prev_token();
                 Ok(Expr::Value(self.parse_value()?))
             }
+            Token::Placeholder(s) => Ok(Expr::Parameter(s)),
+            // `?` is also an operator in PostgreSQL, but stands for a
+            // placeholder wherever an operand is expected
+            Token::Question => Ok(Expr::Parameter("?".to_string())),
+            // A named or numbered placeholder such as `:name` or `:1`, with
+            // nothing in between the colon and its name
+            Token::Colon => match self.next_token_no_skip().cloned() {
+                Some(Token::Word(w)) => Ok(Expr::Parameter(format!(":{}", w))),
+                Some(Token::Number(n, false)) => Ok(Expr::Parameter(format!(":{}", n))),
+                unexpected => {
+                    self.expected("a parameter name after :", unexpected.unwrap_or(Token::EOF))
+                }
+            },
 
-            Token::LParen => {
-                let expr =
-                    if self.parse_keyword(Keyword::SELECT) || self.parse_keyword(Keyword::WITH) {
-                        self.prev_token();
-                        Expr::Subquery(Box::new(self.parse_query()?))
-                    } else {
-                        Expr::Nested(Box::new(self.parse_expr()?))
-                    };
-                self.expect_token(&Token::RParen)?;
-                Ok(expr)
-            }
+            Token::LParen => self.parse_grouped_expr(),
             unexpected => self.expected("an expression:", unexpected),
         }?;
 
@@ -467,6 +749,40 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse the elements of an array literal `ARRAY[1, 2]`, assuming
+    /// `ARRAY[` was already consumed
+    pub fn parse_array_expr(&mut self) -> Result<Expr, ParserError> {
+        if self.consume_token(&Token::RBracket) {
+            return Ok(Expr::Array(vec![]));
+        }
+        let elems = self.parse_comma_separated(Parser::parse_expr)?;
+        self.expect_token(&Token::RBracket)?;
+        Ok(Expr::Array(elems))
+    }
+
+    /// Parse a parenthesized expression, assuming the `(` was already consumed:
+    /// a subquery if it starts with `SELECT`, `WITH` or `VALUES`, otherwise a
+    /// single grouped expression `(a)` or a row constructor `(a, b)`
+    pub fn parse_grouped_expr(&mut self) -> Result<Expr, ParserError> {
+        let expr = match self.peek_token() {
+            Token::Word(w)
+                if [Keyword::SELECT, Keyword::WITH, Keyword::VALUES].contains(&w.keyword) =>
+            {
+                Expr::Subquery(Box::new(self.parse_query()?))
+            }
+            _ => {
+                let mut exprs = self.parse_comma_separated(Parser::parse_expr)?;
+                if exprs.len() == 1 {
+                    Expr::Nested(Box::new(exprs.remove(0)))
+                } else {
+                    Expr::Tuple(exprs)
+                }
+            }
+        };
+        self.expect_token(&Token::RParen)?;
+        Ok(expr)
+    }
+
     pub fn parse_function(&mut self, name: ObjectName) -> Result<Expr, ParserError> {
         self.expect_token(&Token::LParen)?;
 
@@ -475,7 +791,38 @@ impl<'a> Parser<'a> {
             .unwrap_or_default();
 
         let distinct = self.parse_all_or_distinct()?;
-        let args = self.parse_optional_args()?;
+        let (args, order_by) = if self.consume_token(&Token::RParen) {
+            (vec![], vec![])
+        } else {
+            let args = self.parse_comma_separated(Parser::parse_function_args)?;
+            let order_by = if self.parse_keywords(&[Keyword::ORDER, Keyword::BY]) {
+                self.parse_comma_separated(Parser::parse_order_by_expr)?
+            } else {
+                vec![]
+            };
+            self.expect_token(&Token::RParen)?;
+            (args, order_by)
+        };
+        let null_treatment = if self.parse_keywords(&[Keyword::IGNORE, Keyword::NULLS]) {
+            Some(NullTreatment::IgnoreNulls)
+        } else if self.parse_keywords(&[Keyword::RESPECT, Keyword::NULLS]) {
+            Some(NullTreatment::RespectNulls)
+        } else {
+            None
+        };
+        // `FILTER` is only a clause when followed by `(`, and may otherwise
+        // be a column alias
+        let filter = match (self.peek_token(), self.peek_nth_token(1)) {
+            (Token::Word(w), Token::LParen) if w.keyword == Keyword::FILTER => {
+                self.next_token();
+                self.next_token();
+                self.expect_keyword(Keyword::WHERE)?;
+                let filter = self.parse_expr()?;
+                self.expect_token(&Token::RParen)?;
+                Some(Box::new(filter))
+            }
+            _ => None,
+        };
         let over = if self.parse_keyword(Keyword::OVER) {
             // TBD: support window names (`OVER mywin`) in place of inline specification
             self.expect_token(&Token::LParen)?;
@@ -513,6 +860,9 @@ impl<'a> Parser<'a> {
             args,
             over,
             distinct,
+            null_treatment,
+            order_by,
+            filter,
         }))
     }
 
@@ -533,18 +883,57 @@ impl<'a> Parser<'a> {
         let (start_bound, end_bound) = if self.parse_keyword(Keyword::BETWEEN) {
             let start_bound = self.parse_window_frame_bound()?;
             self.expect_keyword(Keyword::AND)?;
-            let end_bound = Some(self.parse_window_frame_bound()?);
-            (start_bound, end_bound)
+            let end_bound = self.parse_window_frame_bound()?;
+            // A frame may not end before it starts, e.g. `CURRENT ROW AND 1 PRECEDING`
+            // (offsets of the same direction aren't compared, as that depends on the units)
+            let position = |bound: &WindowFrameBound| match bound {
+                WindowFrameBound::Preceding(None) => 0,
+                WindowFrameBound::Preceding(Some(_)) => 1,
+                WindowFrameBound::CurrentRow => 2,
+                WindowFrameBound::Following(Some(_)) => 3,
+                WindowFrameBound::Following(None) => 4,
+            };
+            if start_bound == WindowFrameBound::Following(None)
+                || end_bound == WindowFrameBound::Preceding(None)
+                || position(&start_bound) > position(&end_bound)
+            {
+                return parser_err!(format!(
+                    "Invalid window frame: BETWEEN {} AND {}",
+                    start_bound, end_bound
+                ));
+            }
+            (start_bound, Some(end_bound))
         } else {
             (self.parse_window_frame_bound()?, None)
         };
+        let exclude = if self.parse_keyword(Keyword::EXCLUDE) {
+            Some(self.parse_window_frame_exclude()?)
+        } else {
+            None
+        };
         Ok(WindowFrame {
             units,
             start_bound,
             end_bound,
+            exclude,
         })
     }
 
+    /// Parse `CURRENT ROW`, `GROUP`, `TIES` or `NO OTHERS` after `EXCLUDE`
+    pub fn parse_window_frame_exclude(&mut self) -> Result<WindowFrameExclude, ParserError> {
+        if self.parse_keywords(&[Keyword::CURRENT, Keyword::ROW]) {
+            Ok(WindowFrameExclude::CurrentRow)
+        } else if self.parse_keyword(Keyword::GROUP) {
+            Ok(WindowFrameExclude::Group)
+        } else if self.parse_keyword(Keyword::TIES) {
+            Ok(WindowFrameExclude::Ties)
+        } else if self.parse_keywords(&[Keyword::NO, Keyword::OTHERS]) {
+            Ok(WindowFrameExclude::NoOthers)
+        } else {
+            self.expected("CURRENT ROW, GROUP, TIES or NO OTHERS", self.peek_token())
+        }
+    }
+
     /// Parse `CURRENT ROW` or `{ <positive number> | UNBOUNDED } { PRECEDING | FOLLOWING }`
     pub fn parse_window_frame_bound(&mut self) -> Result<WindowFrameBound, ParserError> {
         if self.parse_keywords(&[Keyword::CURRENT, Keyword::ROW]) {
@@ -555,12 +944,10 @@ impl<'a> Parser<'a> {
             } else {
                 Some(self.parse_literal_uint()?)
             };
-            if self.parse_keyword(Keyword::PRECEDING) {
-                Ok(WindowFrameBound::Preceding(rows))
-            } else if self.parse_keyword(Keyword::FOLLOWING) {
-                Ok(WindowFrameBound::Following(rows))
-            } else {
-                self.expected("PRECEDING or FOLLOWING", self.peek_token())
+            match self.parse_one_of_keywords(&[Keyword::PRECEDING, Keyword::FOLLOWING]) {
+                Some(Keyword::PRECEDING) => Ok(WindowFrameBound::Preceding(rows)),
+                Some(Keyword::FOLLOWING) => Ok(WindowFrameBound::Following(rows)),
+                _ => self.expected("PRECEDING or FOLLOWING", self.peek_token()),
             }
         }
     }
@@ -595,33 +982,73 @@ impl<'a> Parser<'a> {
         })
     }
 
-    /// Parse a SQL CAST function e.g. `CAST(expr AS FLOAT)` or `cast(expr, FLOAT)`
-    pub fn parse_cast_expr(&mut self) -> Result<Expr, ParserError> {
+    /// Parse a SQL CAST function e.g. `CAST(expr AS FLOAT)` or `cast(expr, FLOAT)`,
+    /// or one of its variants such as `TRY_CAST(expr AS FLOAT)`
+    pub fn parse_cast_expr(&mut self, kind: CastKind) -> Result<Expr, ParserError> {
         self.expect_token(&Token::LParen)?;
         let expr = self.parse_expr()?;
         if !self.consume_token(&Token::Comma) {
             self.expect_keyword(Keyword::AS)?;
         }
         let data_type = self.parse_data_type()?;
+        let format = self.parse_cast_format()?;
         self.expect_token(&Token::RParen)?;
         Ok(Expr::Cast {
+            kind,
             expr: Box::new(expr),
             data_type,
+            format,
         })
     }
 
-    /// Parse a SQL TRY_CAST function e.g. `TRY_CAST(expr AS FLOAT)` or `cast(expr, FLOAT)`
-    pub fn parse_try_cast_expr(&mut self) -> Result<Expr, ParserError> {
-        self.expect_token(&Token::LParen)?;
-        let expr = self.parse_expr()?;
-        if !self.consume_token(&Token::Comma) {
-            self.expect_keyword(Keyword::AS)?;
+    /// Parse the optional `FORMAT <string>` suffix of a CAST, e.g.
+    /// `CAST(d AS STRING FORMAT 'YYYY-MM-DD')`, as supported by BigQuery
+    pub fn parse_cast_format(&mut self) -> Result<Option<Box<Expr>>, ParserError> {
+        if dialect_of!(self is BigQueryDialect | GenericDialect)
+            && self.parse_keyword(Keyword::FORMAT)
+        {
+            Ok(Some(Box::new(self.parse_expr()?)))
+        } else {
+            Ok(None)
         }
-        let data_type = self.parse_data_type()?;
+    }
+
+    /// Parse a MySQL full-text search, e.g. `MATCH (a, b) AGAINST ('x' IN BOOLEAN MODE)`,
+    /// assuming the `MATCH` keyword was already consumed
+    pub fn parse_match_against(&mut self) -> Result<Expr, ParserError> {
+        let columns = self.parse_parenthesized_column_list(Mandatory)?;
+        self.expect_keyword(Keyword::AGAINST)?;
+        self.expect_token(&Token::LParen)?;
+        let match_value = self.parse_value()?;
+        let modifier = if self.parse_keywords(&[
+            Keyword::IN,
+            Keyword::NATURAL,
+            Keyword::LANGUAGE,
+            Keyword::MODE,
+            Keyword::WITH,
+            Keyword::QUERY,
+            Keyword::EXPANSION,
+        ]) {
+            Some(SearchModifier::InNaturalLanguageModeWithQueryExpansion)
+        } else if self.parse_keywords(&[
+            Keyword::IN,
+            Keyword::NATURAL,
+            Keyword::LANGUAGE,
+            Keyword::MODE,
+        ]) {
+            Some(SearchModifier::InNaturalLanguageMode)
+        } else if self.parse_keywords(&[Keyword::IN, Keyword::BOOLEAN, Keyword::MODE]) {
+            Some(SearchModifier::InBooleanMode)
+        } else if self.parse_keywords(&[Keyword::WITH, Keyword::QUERY, Keyword::EXPANSION]) {
+            Some(SearchModifier::WithQueryExpansion)
+        } else {
+            None
+        };
         self.expect_token(&Token::RParen)?;
-        Ok(Expr::TryCast {
-            expr: Box::new(expr),
-            data_type,
+        Ok(Expr::MatchAgainst {
+            columns,
+            match_value,
+            modifier,
         })
     }
 
@@ -752,6 +1179,33 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    /// Parse a MySQL GROUP_CONCAT expression, e.g. `GROUP_CONCAT(a ORDER BY b SEPARATOR ';')`.
+    pub fn parse_group_concat_expr(&mut self) -> Result<Expr, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let distinct = self.parse_all_or_distinct()?;
+        let exprs = self.parse_comma_separated(Parser::parse_expr)?;
+        let order_by = if self.parse_keywords(&[Keyword::ORDER, Keyword::BY]) {
+            self.parse_comma_separated(Parser::parse_order_by_expr)?
+        } else {
+            vec![]
+        };
+        let separator = if self.parse_keyword(Keyword::SEPARATOR) {
+            match self.next_token() {
+                Token::SingleQuotedString(s) => Some(s),
+                unexpected => return self.expected("a string literal after SEPARATOR", unexpected),
+            }
+        } else {
+            None
+        };
+        self.expect_token(&Token::RParen)?;
+        Ok(Expr::GroupConcat(GroupConcat {
+            distinct,
+            exprs,
+            order_by,
+            separator,
+        }))
+    }
+
     // This function parses date/time fields for both the EXTRACT function-like
     // operator and interval qualifiers. EXTRACT supports a wider set of
     // date/time fields than interval qualifiers, so this function may need to
@@ -850,10 +1304,9 @@ impl<'a> Parser<'a> {
         }))
     }
 
-    /// Parse an operator following an expression
-    pub fn parse_infix(&mut self, expr: Expr, precedence: u8) -> Result<Expr, ParserError> {
-        let tok = self.next_token();
-        let regular_binary_operator = match &tok {
+    /// The binary operator `tok` stands for, if any
+    fn binary_operator(&self, tok: &Token) -> Option<BinaryOperator> {
+        match tok {
             Token::Spaceship => Some(BinaryOperator::Spaceship),
             Token::DoubleEq => Some(BinaryOperator::Eq),
             Token::Eq => Some(BinaryOperator::Eq),
@@ -884,24 +1337,32 @@ impl<'a> Parser<'a> {
             Token::TildeAsterisk => Some(BinaryOperator::PGRegexIMatch),
             Token::ExclamationMarkTilde => Some(BinaryOperator::PGRegexNotMatch),
             Token::ExclamationMarkTildeAsterisk => Some(BinaryOperator::PGRegexNotIMatch),
+            Token::AtArrow => Some(BinaryOperator::PGContains),
+            Token::ArrowAt => Some(BinaryOperator::PGContainedBy),
+            Token::Overlap => Some(BinaryOperator::PGOverlap),
+            Token::Question if dialect_of!(self is PostgreSqlDialect | GenericDialect) => {
+                Some(BinaryOperator::PGExists)
+            }
+            Token::QuestionPipe => Some(BinaryOperator::PGExistsAny),
+            Token::QuestionAnd => Some(BinaryOperator::PGExistsAll),
+            Token::Arrow => Some(BinaryOperator::PGJsonGet),
+            Token::LongArrow => Some(BinaryOperator::PGJsonGetText),
+            Token::HashArrow => Some(BinaryOperator::PGJsonGetPath),
+            Token::HashLongArrow => Some(BinaryOperator::PGJsonGetPathText),
+            Token::CustomOperator(op) => Some(BinaryOperator::Custom(op.clone())),
             Token::Word(w) => match w.keyword {
                 Keyword::AND => Some(BinaryOperator::And),
                 Keyword::OR => Some(BinaryOperator::Or),
-                Keyword::LIKE => Some(BinaryOperator::Like),
-                Keyword::ILIKE => Some(BinaryOperator::ILike),
-                Keyword::NOT => {
-                    if self.parse_keyword(Keyword::LIKE) {
-                        Some(BinaryOperator::NotLike)
-                    } else if self.parse_keyword(Keyword::ILIKE) {
-                        Some(BinaryOperator::NotILike)
-                    } else {
-                        None
-                    }
-                }
                 _ => None,
             },
             _ => None,
-        };
+        }
+    }
+
+    /// Parse an operator following an expression
+    pub fn parse_infix(&mut self, expr: Expr, precedence: u8) -> Result<Expr, ParserError> {
+        let tok = self.next_token();
+        let regular_binary_operator = self.binary_operator(&tok);
 
         if let Some(op) = regular_binary_operator {
             Ok(Expr::BinaryOp {
@@ -920,15 +1381,32 @@ impl<'a> Parser<'a> {
                         self.expected("NULL or NOT NULL after IS", self.peek_token())
                     }
                 }
-                Keyword::NOT | Keyword::IN | Keyword::BETWEEN => {
+                Keyword::NOT
+                | Keyword::IN
+                | Keyword::BETWEEN
+                | Keyword::LIKE
+                | Keyword::ILIKE
+                | Keyword::SIMILAR => {
                     self.prev_token();
                     let negated = self.parse_keyword(Keyword::NOT);
                     if self.parse_keyword(Keyword::IN) {
                         self.parse_in(expr, negated)
                     } else if self.parse_keyword(Keyword::BETWEEN) {
                         self.parse_between(expr, negated)
+                    } else if self.parse_keyword(Keyword::LIKE) {
+                        self.parse_like(expr, negated, false)
+                    } else if self.parse_keyword(Keyword::ILIKE) {
+                        self.parse_like(expr, negated, true)
+                    } else if self.parse_keyword(Keyword::SIMILAR) {
+                        if !self.parse_keyword(Keyword::TO) {
+                            return self.expected("TO after SIMILAR", self.peek_token());
+                        }
+                        self.parse_similar_to(expr, negated)
                     } else {
-                        self.expected("IN or BETWEEN after NOT", self.peek_token())
+                        self.expected(
+                            "IN, BETWEEN, LIKE or SIMILAR TO after NOT",
+                            self.peek_token(),
+                        )
                     }
                 }
                 // Can only happen if `get_next_precedence` got out of sync with this function
@@ -943,7 +1421,16 @@ impl<'a> Parser<'a> {
                 expr: Box::new(expr),
             })
         } else if Token::LBracket == tok {
-            self.parse_map_access(expr)
+            // A string key after another subscript, as in `a[1]['x']`, is
+            // an index into its result rather than a map access
+            if dialect_of!(self is PostgreSqlDialect | GenericDialect)
+                && (!matches!(self.peek_token(), Token::SingleQuotedString(_))
+                    || !matches!(expr, Expr::Identifier(_) | Expr::CompoundIdentifier(_)))
+            {
+                self.parse_subscript_range(expr)
+            } else {
+                self.parse_map_access(expr)
+            }
         } else {
             // Can only happen if `get_next_precedence` got out of sync with this function
             parser_err!(format!("No infix parser for token {:?}", tok))
@@ -954,19 +1441,51 @@ impl<'a> Parser<'a> {
         let key = self.parse_literal_string()?;
         let tok = self.consume_token(&Token::RBracket);
         debug!("Tok: {}", tok);
-        match expr {
-            e @ Expr::Identifier(_) | e @ Expr::CompoundIdentifier(_) => Ok(Expr::MapAccess {
-                column: Box::new(e),
-                key,
-            }),
-            _ => Ok(expr),
-        }
+        Ok(Expr::MapAccess {
+            column: Box::new(expr),
+            key,
+        })
+    }
+
+    /// Parse an array subscript, assuming the `[` was already consumed: either
+    /// an index `a[1]` or a slice `a[1:3]`, where either bound may be omitted
+    pub fn parse_subscript_range(&mut self, obj: Expr) -> Result<Expr, ParserError> {
+        let lower = if self.consume_token(&Token::Colon) {
+            None
+        } else {
+            let index = self.parse_expr()?;
+            if !self.consume_token(&Token::Colon) {
+                self.expect_token(&Token::RBracket)?;
+                return Ok(Expr::ArrayIndex {
+                    obj: Box::new(obj),
+                    index: Box::new(index),
+                });
+            }
+            Some(Box::new(index))
+        };
+        let upper = if self.consume_token(&Token::RBracket) {
+            None
+        } else {
+            let upper = self.parse_expr()?;
+            self.expect_token(&Token::RBracket)?;
+            Some(Box::new(upper))
+        };
+        Ok(Expr::ArraySlice {
+            obj: Box::new(obj),
+            lower,
+            upper,
+        })
     }
 
     /// Parses the parens following the `[ NOT ] IN` operator
     pub fn parse_in(&mut self, expr: Expr, negated: bool) -> Result<Expr, ParserError> {
         self.expect_token(&Token::LParen)?;
-        let in_op = if self.parse_keyword(Keyword::SELECT) || self.parse_keyword(Keyword::WITH) {
+        // A query right after the `(` makes this `IN (subquery)`; anything
+        // else, including a parenthesized query, starts an expression list.
+        let in_op = if self
+            .parse_one_of_keywords(&[Keyword::SELECT, Keyword::WITH, Keyword::VALUES])
+            .is_some()
+        {
             self.prev_token();
             Expr::InSubquery {
                 expr: Box::new(expr),
@@ -984,13 +1503,69 @@ impl<'a> Parser<'a> {
         Ok(in_op)
     }
 
+    /// Parses `<pattern> [ ESCAPE '<char>' ]`, assuming the `LIKE` (or `ILIKE`)
+    /// keyword was already consumed
+    pub fn parse_like(
+        &mut self,
+        expr: Expr,
+        negated: bool,
+        case_insensitive: bool,
+    ) -> Result<Expr, ParserError> {
+        let pattern = Box::new(self.parse_subexpr(BETWEEN_PREC)?);
+        let escape_char = self.parse_optional_escape_char()?;
+        let expr = Box::new(expr);
+        Ok(if case_insensitive {
+            Expr::ILike {
+                negated,
+                expr,
+                pattern,
+                escape_char,
+            }
+        } else {
+            Expr::Like {
+                negated,
+                expr,
+                pattern,
+                escape_char,
+            }
+        })
+    }
+
+    /// Parses `<pattern> [ ESCAPE '<char>' ]`, assuming `SIMILAR TO` was
+    /// already consumed
+    pub fn parse_similar_to(&mut self, expr: Expr, negated: bool) -> Result<Expr, ParserError> {
+        let pattern = Box::new(self.parse_subexpr(BETWEEN_PREC)?);
+        let escape_char = self.parse_optional_escape_char()?;
+        Ok(Expr::SimilarTo {
+            negated,
+            expr: Box::new(expr),
+            pattern,
+            escape_char,
+        })
+    }
+
+    /// Parses the optional `ESCAPE '<char>'` following a `LIKE` or
+    /// `SIMILAR TO` pattern
+    fn parse_optional_escape_char(&mut self) -> Result<Option<char>, ParserError> {
+        if !self.parse_keyword(Keyword::ESCAPE) {
+            return Ok(None);
+        }
+        match self.next_token() {
+            Token::SingleQuotedString(s) if s.chars().count() == 1 => Ok(s.chars().next()),
+            Token::SingleQuotedString(s) => {
+                parser_err!(format!("ESCAPE must be a single character, found: '{}'", s))
+            }
+            unexpected => self.expected("a string after ESCAPE", unexpected),
+        }
+    }
+
     /// Parses `BETWEEN <low> AND <high>`, assuming the `BETWEEN` keyword was already consumed
     pub fn parse_between(&mut self, expr: Expr, negated: bool) -> Result<Expr, ParserError> {
         // Stop parsing subexpressions for <low> and <high> on tokens with
         // precedence lower than that of `BETWEEN`, such as `AND`, `IS`, etc.
-        let low = self.parse_subexpr(Self::BETWEEN_PREC)?;
+        let low = self.parse_subexpr(BETWEEN_PREC)?;
         self.expect_keyword(Keyword::AND)?;
-        let high = self.parse_subexpr(Self::BETWEEN_PREC)?;
+        let high = self.parse_subexpr(BETWEEN_PREC)?;
         Ok(Expr::Between {
             expr: Box::new(expr),
             negated,
@@ -1002,69 +1577,96 @@ impl<'a> Parser<'a> {
     /// Parse a postgresql casting style which is in the form of `expr::datatype`
     pub fn parse_pg_cast(&mut self, expr: Expr) -> Result<Expr, ParserError> {
         Ok(Expr::Cast {
+            kind: CastKind::Cast,
             expr: Box::new(expr),
             data_type: self.parse_data_type()?,
+            format: None,
         })
     }
 
-    const UNARY_NOT_PREC: u8 = 15;
-    const BETWEEN_PREC: u8 = 20;
-    const PLUS_MINUS_PREC: u8 = 30;
-
     /// Get the precedence of the next token
     pub fn get_next_precedence(&self) -> Result<u8, ParserError> {
+        // A custom terminator may consist of operator tokens, such as `//`
+        if self.at_statement_terminator() {
+            return Ok(0);
+        }
         let token = self.peek_token();
         debug!("get_next_precedence() {:?}", token);
+        if let Some(precedence) = self.dialect.precedence_of(&token) {
+            return Ok(precedence);
+        }
+        if let Some(precedence) = self.binary_operator(&token).and_then(|op| op.precedence()) {
+            return Ok(precedence);
+        }
         match token {
-            Token::Word(w) if w.keyword == Keyword::OR => Ok(5),
-            Token::Word(w) if w.keyword == Keyword::AND => Ok(10),
             Token::Word(w) if w.keyword == Keyword::NOT => match self.peek_nth_token(1) {
                 // The precedence of NOT varies depending on keyword that
                 // follows it. If it is followed by IN, BETWEEN, or LIKE,
                 // it takes on the precedence of those tokens. Otherwise it
                 // is not an infix operator, and therefore has zero
                 // precedence.
-                Token::Word(w) if w.keyword == Keyword::IN => Ok(Self::BETWEEN_PREC),
-                Token::Word(w) if w.keyword == Keyword::BETWEEN => Ok(Self::BETWEEN_PREC),
-                Token::Word(w) if w.keyword == Keyword::LIKE => Ok(Self::BETWEEN_PREC),
-                Token::Word(w) if w.keyword == Keyword::ILIKE => Ok(Self::BETWEEN_PREC),
+                Token::Word(w) if w.keyword == Keyword::IN => Ok(BETWEEN_PREC),
+                Token::Word(w) if w.keyword == Keyword::BETWEEN => Ok(BETWEEN_PREC),
+                Token::Word(w) if w.keyword == Keyword::LIKE => Ok(BETWEEN_PREC),
+                Token::Word(w) if w.keyword == Keyword::ILIKE => Ok(BETWEEN_PREC),
+                Token::Word(w) if w.keyword == Keyword::SIMILAR => Ok(BETWEEN_PREC),
+                _ => Ok(0),
+            },
+            Token::Word(w) if w.keyword == Keyword::IS => Ok(IS_PREC),
+            Token::Word(w) if w.keyword == Keyword::IN => Ok(BETWEEN_PREC),
+            Token::Word(w) if w.keyword == Keyword::BETWEEN => Ok(BETWEEN_PREC),
+            Token::Word(w) if w.keyword == Keyword::LIKE => Ok(BETWEEN_PREC),
+            Token::Word(w) if w.keyword == Keyword::ILIKE => Ok(BETWEEN_PREC),
+            // Without TO, `similar` may be an alias, as in `SELECT a similar`
+            Token::Word(w) if w.keyword == Keyword::SIMILAR => match self.peek_nth_token(1) {
+                Token::Word(w) if w.keyword == Keyword::TO => Ok(BETWEEN_PREC),
                 _ => Ok(0),
             },
-            Token::Word(w) if w.keyword == Keyword::IS => Ok(17),
-            Token::Word(w) if w.keyword == Keyword::IN => Ok(Self::BETWEEN_PREC),
-            Token::Word(w) if w.keyword == Keyword::BETWEEN => Ok(Self::BETWEEN_PREC),
-            Token::Word(w) if w.keyword == Keyword::LIKE => Ok(Self::BETWEEN_PREC),
-            Token::Word(w) if w.keyword == Keyword::ILIKE => Ok(Self::BETWEEN_PREC),
-            Token::Eq
-            | Token::Lt
-            | Token::LtEq
-            | Token::Neq
-            | Token::Gt
-            | Token::GtEq
-            | Token::DoubleEq
-            | Token::Tilde
-            | Token::TildeAsterisk
-            | Token::ExclamationMarkTilde
-            | Token::ExclamationMarkTildeAsterisk
-            | Token::Spaceship => Ok(20),
-            Token::Pipe => Ok(21),
-            Token::Caret | Token::Sharp | Token::ShiftRight | Token::ShiftLeft => Ok(22),
-            Token::Ampersand => Ok(23),
-            Token::Plus | Token::Minus => Ok(Self::PLUS_MINUS_PREC),
-            Token::Mult | Token::Div | Token::Mod | Token::StringConcat => Ok(40),
+            // Outside PostgreSQL these are no operators, see `binary_operator`,
+            // but keep their precedence, and fail in `parse_infix`. `?` is not
+            // listed, as it always stood for a placeholder in those dialects.
+            Token::Sharp | Token::ShiftRight | Token::ShiftLeft => Ok(22),
+            Token::CustomOperator(op) => Ok(self.dialect.custom_operator_precedence(&op)),
             Token::DoubleColon => Ok(50),
             Token::ExclamationMark => Ok(50),
-            Token::LBracket | Token::RBracket => Ok(10),
+            Token::LBracket => Ok(10),
             _ => Ok(0),
         }
     }
 
+    /// Consume the whitespace before the next token, returning the text of
+    /// the comments it contains (without the comment markers or surrounding
+    /// whitespace), e.g. to attach a `-- note` to the node that follows it
+    pub fn parse_comment(&mut self) -> Vec<String> {
+        let mut comments = vec![];
+        while let Some(Token::Whitespace(ws)) = self.tokens.get(self.index) {
+            match ws {
+                Whitespace::SingleLineComment { comment, .. }
+                | Whitespace::MultiLineComment(comment) => {
+                    comments.push(comment.trim().to_string())
+                }
+                Whitespace::Space | Whitespace::Newline | Whitespace::Tab => {}
+            }
+            self.index += 1;
+        }
+        comments
+    }
+
     /// Return the first non-whitespace token that has not yet been processed
     /// (or None if reached end-of-file)
     pub fn peek_token(&self) -> Token {
         self.peek_nth_token(0)
     }
 
+    /// Like [Parser::peek_token], but borrow the token instead of cloning
+    /// it, returning `None` at the end of input
+    pub fn peek_token_ref(&self) -> Option<&Token> {
+        self.tokens
+            .iter()
+            .skip(self.index)
+            .find(|token| !matches!(token, Token::Whitespace(_)))
+    }
+
     /// Return nth non-whitespace token that has not yet been processed
     pub fn peek_nth_token(&self, mut n: usize) -> Token {
         let mut index = self.index;
@@ -1123,8 +1725,8 @@ impl<'a> Parser<'a> {
     /// Look for an expected keyword and consume it if it exists
     #[must_use]
     pub fn parse_keyword(&mut self, expected: Keyword) -> bool {
-        match self.peek_token() {
-            Token::Word(w) if expected == w.keyword => {
+        match self.peek_token_ref() {
+            Some(Token::Word(w)) if expected == w.keyword => {
                 self.next_token();
                 true
             }
@@ -1150,18 +1752,12 @@ impl<'a> Parser<'a> {
     /// Look for one of the given keywords and return the one that matches.
     #[must_use]
     pub fn parse_one_of_keywords(&mut self, keywords: &[Keyword]) -> Option<Keyword> {
-        match self.peek_token() {
-            Token::Word(w) => {
-                keywords
-                    .iter()
-                    .find(|keyword| **keyword == w.keyword)
-                    .map(|keyword| {
-                        self.next_token();
-                        *keyword
-                    })
-            }
-            _ => None,
-        }
+        let keyword = match self.peek_token_ref() {
+            Some(Token::Word(w)) => keywords.iter().copied().find(|kw| *kw == w.keyword)?,
+            _ => return None,
+        };
+        self.next_token();
+        Some(keyword)
     }
 
     /// Bail out if the current token is not one of the expected keywords, or consume it if it is
@@ -1198,7 +1794,7 @@ impl<'a> Parser<'a> {
     /// Consume the next token if it matches the expected token, otherwise return false
     #[must_use]
     pub fn consume_token(&mut self, expected: &Token) -> bool {
-        if self.peek_token() == *expected {
+        if self.peek_token_ref().unwrap_or(&Token::EOF) == expected {
             self.next_token();
             true
         } else {
@@ -1216,7 +1812,20 @@ impl<'a> Parser<'a> {
     }
 
     /// Parse a comma-separated list of 1+ items accepted by `F`
-    pub fn parse_comma_separated<T, F>(&mut self, mut f: F) -> Result<Vec<T>, ParserError>
+    pub fn parse_comma_separated<T, F>(&mut self, f: F) -> Result<Vec<T>, ParserError>
+    where
+        F: FnMut(&mut Parser<'a>) -> Result<T, ParserError>,
+    {
+        self.parse_comma_separated_until(f, &[])
+    }
+
+    /// Same as [Parser::parse_comma_separated], but a trailing comma may also
+    /// be followed by one of `end_keywords`, e.g. `FROM` after a projection
+    fn parse_comma_separated_until<T, F>(
+        &mut self,
+        mut f: F,
+        end_keywords: &[Keyword],
+    ) -> Result<Vec<T>, ParserError>
     where
         F: FnMut(&mut Parser<'a>) -> Result<T, ParserError>,
     {
@@ -1225,11 +1834,35 @@ impl<'a> Parser<'a> {
             values.push(f(self)?);
             if !self.consume_token(&Token::Comma) {
                 break;
+            } else if self.is_list_end(end_keywords) {
+                if self.dialect.supports_trailing_comma() {
+                    // drop the trailing comma
+                    break;
+                }
+                // Otherwise a keyword like FROM would be parsed as an
+                // identifier, silently accepting the trailing comma.
+                if let Token::Word(_) = self.peek_token() {
+                    return self.expected("a list item after ','", self.peek_token());
+                }
             }
         }
         Ok(values)
     }
 
+    /// Check whether the next token ends a comma-separated list, i.e. it
+    /// can't start another element of the list. Other words are taken as
+    /// list items, as they may be non-reserved identifiers.
+    fn is_list_end(&self, end_keywords: &[Keyword]) -> bool {
+        if self.at_statement_terminator() {
+            return true;
+        }
+        match self.peek_token() {
+            Token::Word(w) => end_keywords.contains(&w.keyword),
+            Token::RParen | Token::RBracket | Token::SemiColon | Token::EOF => true,
+            _ => false,
+        }
+    }
+
     /// Run a parser method `f`, reverting back to the current position
     /// if unsuccessful.
     #[must_use]
@@ -1238,10 +1871,13 @@ impl<'a> Parser<'a> {
         F: FnMut(&mut Parser) -> Result<T, ParserError>,
     {
         let index = self.index;
+        let warnings = self.warnings.len();
         if let Ok(t) = f(self) {
             Some(t)
         } else {
+            // the input will be parsed again, recording its warnings again
             self.index = index;
+            self.warnings.truncate(warnings);
             None
         }
     }
@@ -1271,9 +1907,13 @@ impl<'a> Parser<'a> {
             self.parse_create_view(or_replace)
         } else if self.parse_keyword(Keyword::EXTERNAL) {
             self.parse_create_external_table(or_replace)
+        } else if dialect_of!(self is PostgreSqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::FUNCTION)
+        {
+            self.parse_create_function(or_replace)
         } else if or_replace {
             self.expected(
-                "[EXTERNAL] TABLE or [MATERIALIZED] VIEW after CREATE OR REPLACE",
+                "[EXTERNAL] TABLE, [MATERIALIZED] VIEW or FUNCTION after CREATE OR REPLACE",
                 self.peek_token(),
             )
         } else if self.parse_keyword(Keyword::INDEX) {
@@ -1284,6 +1924,8 @@ impl<'a> Parser<'a> {
             self.parse_create_virtual_table()
         } else if self.parse_keyword(Keyword::SCHEMA) {
             self.parse_create_schema()
+        } else if self.parse_keyword(Keyword::SEQUENCE) {
+            self.parse_create_sequence(temporary)
         } else {
             self.expected("an object type after CREATE", self.peek_token())
         }
@@ -1309,6 +1951,113 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse `CREATE SEQUENCE`, assuming `CREATE [TEMPORARY] SEQUENCE` was
+    /// already consumed
+    pub fn parse_create_sequence(&mut self, temporary: bool) -> Result<Statement, ParserError> {
+        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parse_object_name()?;
+        let mut options = vec![];
+        loop {
+            let option = if self.parse_keyword(Keyword::INCREMENT) {
+                let by = self.parse_keyword(Keyword::BY);
+                SequenceOptions::IncrementBy(self.parse_expr()?, by)
+            } else if self.parse_keyword(Keyword::MINVALUE) {
+                SequenceOptions::MinValue(Some(self.parse_expr()?))
+            } else if self.parse_keywords(&[Keyword::NO, Keyword::MINVALUE]) {
+                SequenceOptions::MinValue(None)
+            } else if self.parse_keyword(Keyword::MAXVALUE) {
+                SequenceOptions::MaxValue(Some(self.parse_expr()?))
+            } else if self.parse_keywords(&[Keyword::NO, Keyword::MAXVALUE]) {
+                SequenceOptions::MaxValue(None)
+            } else if self.parse_keyword(Keyword::START) {
+                let with = self.parse_keyword(Keyword::WITH);
+                SequenceOptions::StartWith(self.parse_expr()?, with)
+            } else if self.parse_keyword(Keyword::CACHE) {
+                SequenceOptions::Cache(self.parse_expr()?)
+            } else if self.parse_keyword(Keyword::CYCLE) {
+                SequenceOptions::Cycle(true)
+            } else if self.parse_keywords(&[Keyword::NO, Keyword::CYCLE]) {
+                SequenceOptions::Cycle(false)
+            } else {
+                break;
+            };
+            options.push(option);
+        }
+        Ok(Statement::CreateSequence {
+            temporary,
+            if_not_exists,
+            name,
+            options,
+        })
+    }
+
+    /// Parse a PostgreSQL `CREATE FUNCTION`, assuming `CREATE [OR REPLACE] FUNCTION`
+    /// was already consumed
+    pub fn parse_create_function(&mut self, or_replace: bool) -> Result<Statement, ParserError> {
+        let name = self.parse_object_name()?;
+        self.expect_token(&Token::LParen)?;
+        let args = if self.consume_token(&Token::RParen) {
+            vec![]
+        } else {
+            let args = self.parse_comma_separated(Parser::parse_create_function_arg)?;
+            self.expect_token(&Token::RParen)?;
+            args
+        };
+        let return_type = if self.parse_keyword(Keyword::RETURNS) {
+            Some(self.parse_data_type()?)
+        } else {
+            None
+        };
+
+        // The body and the language may be given in either order
+        let mut body = None;
+        let mut language = None;
+        loop {
+            if body.is_none() && self.parse_keyword(Keyword::AS) {
+                body = Some(match self.next_token() {
+                    Token::SingleQuotedString(s) => FunctionDefinition::SingleQuoted(s),
+                    Token::DollarQuotedString(s) => FunctionDefinition::DollarQuoted(s),
+                    unexpected => return self.expected("a function body string", unexpected),
+                });
+            } else if language.is_none() && self.parse_keyword(Keyword::LANGUAGE) {
+                language = Some(self.parse_identifier()?);
+            } else {
+                break;
+            }
+        }
+        let body = match body {
+            Some(body) => body,
+            None => return self.expected("AS <function body>", self.peek_token()),
+        };
+
+        Ok(Statement::CreateFunction {
+            or_replace,
+            name,
+            args,
+            return_type,
+            body,
+            language,
+        })
+    }
+
+    /// Parse `[ argname ] argtype` in the signature of `CREATE FUNCTION`
+    fn parse_create_function_arg(&mut self) -> Result<CreateFunctionArg, ParserError> {
+        // An unnamed argument's type may itself look like an identifier,
+        // so try parsing a name followed by a type first
+        if let Some((name, data_type)) =
+            self.maybe_parse(|parser| Ok((parser.parse_identifier()?, parser.parse_data_type()?)))
+        {
+            return Ok(CreateFunctionArg {
+                name: Some(name),
+                data_type,
+            });
+        }
+        Ok(CreateFunctionArg {
+            name: None,
+            data_type: self.parse_data_type()?,
+        })
+    }
+
     pub fn parse_create_schema(&mut self) -> Result<Statement, ParserError> {
         let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
         let schema_name = self.parse_object_name()?;
@@ -1379,6 +2128,12 @@ impl<'a> Parser<'a> {
             query: None,
             without_rowid: false,
             like: None,
+            inherits: vec![],
+            partition_by: None,
+            on_commit: None,
+            tablespace: None,
+            engine: None,
+            default_charset: None,
         })
     }
 
@@ -1408,7 +2163,17 @@ impl<'a> Parser<'a> {
         let with_options = self.parse_options(Keyword::WITH)?;
         self.expect_keyword(Keyword::AS)?;
         let query = Box::new(self.parse_query()?);
-        // Optional `WITH [ CASCADED | LOCAL ] CHECK OPTION` is widely supported here.
+        let with_check_option = if self.parse_keyword(Keyword::WITH) {
+            let check_option =
+                match self.parse_one_of_keywords(&[Keyword::CASCADED, Keyword::LOCAL]) {
+                    Some(Keyword::LOCAL) => CheckOption::Local,
+                    _ => CheckOption::Cascaded,
+                };
+            self.expect_keywords(&[Keyword::CHECK, Keyword::OPTION])?;
+            Some(check_option)
+        } else {
+            None
+        };
         Ok(Statement::CreateView {
             name,
             columns,
@@ -1416,6 +2181,7 @@ impl<'a> Parser<'a> {
             materialized,
             or_replace,
             with_options,
+            with_check_option,
         })
     }
 
@@ -1435,38 +2201,92 @@ impl<'a> Parser<'a> {
         // specifying multiple objects to delete in a single statement
         let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
         let names = self.parse_comma_separated(Parser::parse_object_name)?;
-        let cascade = self.parse_keyword(Keyword::CASCADE);
-        let restrict = self.parse_keyword(Keyword::RESTRICT);
+        let behavior = self.parse_optional_drop_behavior("DROP")?;
         let purge = self.parse_keyword(Keyword::PURGE);
-        if cascade && restrict {
-            return parser_err!("Cannot specify both CASCADE and RESTRICT in DROP");
-        }
         Ok(Statement::Drop {
             object_type,
             if_exists,
             names,
-            cascade,
+            behavior,
             purge,
         })
     }
 
+    /// Parse an optional trailing `CASCADE` or `RESTRICT`, rejecting both
+    /// being given in the same `statement`
+    pub fn parse_optional_drop_behavior(
+        &mut self,
+        statement: &str,
+    ) -> Result<Option<DropBehavior>, ParserError> {
+        let keywords = [Keyword::CASCADE, Keyword::RESTRICT];
+        let behavior = |keyword| match keyword {
+            Some(Keyword::CASCADE) => Some(DropBehavior::Cascade),
+            Some(Keyword::RESTRICT) => Some(DropBehavior::Restrict),
+            _ => None,
+        };
+        let first = match behavior(self.parse_one_of_keywords(&keywords)) {
+            Some(first) => first,
+            None => return Ok(None),
+        };
+        match behavior(self.parse_one_of_keywords(&keywords)) {
+            Some(second) if second == first => parser_err!(format!(
+                "Cannot specify {} more than once in {}",
+                first, statement
+            )),
+            Some(_) => parser_err!(format!(
+                "Cannot specify both CASCADE and RESTRICT in {}",
+                statement
+            )),
+            None => Ok(Some(first)),
+        }
+    }
+
     pub fn parse_create_index(&mut self, unique: bool) -> Result<Statement, ParserError> {
         let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
         let index_name = self.parse_object_name()?;
         self.expect_keyword(Keyword::ON)?;
         let table_name = self.parse_object_name()?;
+        let using = if self.parse_keyword(Keyword::USING) {
+            Some(self.parse_identifier()?)
+        } else {
+            None
+        };
         self.expect_token(&Token::LParen)?;
         let columns = self.parse_comma_separated(Parser::parse_order_by_expr)?;
         self.expect_token(&Token::RParen)?;
+        let include = if self.parse_keyword(Keyword::INCLUDE) {
+            self.parse_parenthesized_column_list(Mandatory)?
+        } else {
+            vec![]
+        };
+        let tablespace = self.parse_tablespace()?;
+        let predicate = if self.parse_keyword(Keyword::WHERE) {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
         Ok(Statement::CreateIndex {
             name: index_name,
             table_name,
+            using,
             columns,
             unique,
             if_not_exists,
+            include,
+            tablespace,
+            predicate,
         })
     }
 
+    /// Parse an optional `TABLESPACE <name>` storage clause
+    pub fn parse_tablespace(&mut self) -> Result<Option<Ident>, ParserError> {
+        if self.parse_keyword(Keyword::TABLESPACE) {
+            Ok(Some(self.parse_identifier()?))
+        } else {
+            Ok(None)
+        }
+    }
+
     //TODO: Implement parsing for Skewed and Clustered
     pub fn parse_hive_distribution(&mut self) -> Result<HiveDistributionStyle, ParserError> {
         if self.parse_keywords(&[Keyword::PARTITIONED, Keyword::BY]) {
@@ -1531,7 +2351,15 @@ impl<'a> Parser<'a> {
         let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
         let table_name = self.parse_object_name()?;
         let like = if self.parse_keyword(Keyword::LIKE) || self.parse_keyword(Keyword::ILIKE) {
-            self.parse_object_name().ok()
+            self.parse_object_name().ok().map(|name| TableLike {
+                name,
+                parenthesized: false,
+                options: vec![],
+            })
+        } else if self.peek_token() == Token::LParen
+            && matches!(self.peek_nth_token(1), Token::Word(w) if w.keyword == Keyword::LIKE)
+        {
+            Some(self.parse_parenthesized_table_like()?)
         } else {
             None
         };
@@ -1541,11 +2369,42 @@ impl<'a> Parser<'a> {
         // SQLite supports `WITHOUT ROWID` at the end of `CREATE TABLE`
         let without_rowid = self.parse_keywords(&[Keyword::WITHOUT, Keyword::ROWID]);
 
+        let inherits = if dialect_of!(self is PostgreSqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::INHERITS)
+        {
+            self.expect_token(&Token::LParen)?;
+            let parents = self.parse_comma_separated(Parser::parse_object_name)?;
+            self.expect_token(&Token::RParen)?;
+            parents
+        } else {
+            vec![]
+        };
+
+        let partition_by = if self.parse_keywords(&[Keyword::PARTITION, Keyword::BY]) {
+            Some(self.parse_partition_spec()?)
+        } else {
+            None
+        };
+
         let hive_distribution = self.parse_hive_distribution()?;
         let hive_formats = self.parse_hive_formats()?;
         // PostgreSQL supports `WITH ( options )`, before `AS`
         let with_options = self.parse_options(Keyword::WITH)?;
         let table_properties = self.parse_options(Keyword::TBLPROPERTIES)?;
+        let on_commit = if self.parse_keywords(&[Keyword::ON, Keyword::COMMIT]) {
+            if !temporary {
+                return parser_err!("ON COMMIT can only be used on temporary tables");
+            }
+            Some(self.parse_on_commit()?)
+        } else {
+            None
+        };
+        let tablespace = self.parse_tablespace()?;
+        let (engine, default_charset) = if dialect_of!(self is MySqlDialect | GenericDialect) {
+            self.parse_mysql_table_options()?
+        } else {
+            (None, None)
+        };
         // Parse optional `AS ( query )`
         let query = if self.parse_keyword(Keyword::AS) {
             Some(Box::new(self.parse_query()?))
@@ -1570,9 +2429,102 @@ impl<'a> Parser<'a> {
             query,
             without_rowid,
             like,
+            inherits,
+            partition_by,
+            on_commit,
+            tablespace,
+            engine,
+            default_charset,
+        })
+    }
+
+    /// Parse the MySQL table options `ENGINE [=] <name>` and
+    /// `[DEFAULT] { CHARSET | CHARACTER SET } [=] <name>`, in either order
+    fn parse_mysql_table_options(&mut self) -> Result<(Option<Ident>, Option<Ident>), ParserError> {
+        let mut engine = None;
+        let mut default_charset = None;
+        loop {
+            if self.parse_keyword(Keyword::ENGINE) {
+                if engine.is_some() {
+                    return parser_err!("Cannot specify ENGINE more than once in CREATE TABLE");
+                }
+                let _ = self.consume_token(&Token::Eq);
+                engine = Some(self.parse_identifier()?);
+            } else if self.parse_keywords(&[Keyword::DEFAULT, Keyword::CHARSET])
+                || self.parse_keywords(&[Keyword::DEFAULT, Keyword::CHARACTER, Keyword::SET])
+                || self.parse_keyword(Keyword::CHARSET)
+                || self.parse_keywords(&[Keyword::CHARACTER, Keyword::SET])
+            {
+                if default_charset.is_some() {
+                    return parser_err!("Cannot specify CHARSET more than once in CREATE TABLE");
+                }
+                let _ = self.consume_token(&Token::Eq);
+                default_charset = Some(self.parse_identifier()?);
+            } else {
+                return Ok((engine, default_charset));
+            }
+        }
+    }
+
+    /// Parse `(LIKE <source> [ { INCLUDING | EXCLUDING } <property> ... ])`
+    /// given in place of the column list of `CREATE TABLE`
+    fn parse_parenthesized_table_like(&mut self) -> Result<TableLike, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        self.expect_keyword(Keyword::LIKE)?;
+        let name = self.parse_object_name()?;
+        let mut options = vec![];
+        while let Some(keyword) =
+            self.parse_one_of_keywords(&[Keyword::INCLUDING, Keyword::EXCLUDING])
+        {
+            let property = if self.parse_keyword(Keyword::ALL) {
+                Ident::new("ALL")
+            } else {
+                self.parse_identifier()?
+            };
+            options.push(TableLikeOption {
+                including: keyword == Keyword::INCLUDING,
+                property,
+            });
+        }
+        self.expect_token(&Token::RParen)?;
+        Ok(TableLike {
+            name,
+            parenthesized: true,
+            options,
         })
     }
 
+    /// Parse the action after `ON COMMIT`
+    fn parse_on_commit(&mut self) -> Result<OnCommit, ParserError> {
+        if self.parse_keywords(&[Keyword::PRESERVE, Keyword::ROWS]) {
+            Ok(OnCommit::PreserveRows)
+        } else if self.parse_keywords(&[Keyword::DELETE, Keyword::ROWS]) {
+            Ok(OnCommit::DeleteRows)
+        } else if self.parse_keyword(Keyword::DROP) {
+            Ok(OnCommit::Drop)
+        } else {
+            self.expected(
+                "PRESERVE ROWS, DELETE ROWS or DROP after ON COMMIT",
+                self.peek_token(),
+            )
+        }
+    }
+
+    /// Parse the partitioning strategy and key after `PARTITION BY`
+    pub fn parse_partition_spec(&mut self) -> Result<PartitionSpec, ParserError> {
+        let strategy =
+            match self.expect_one_of_keywords(&[Keyword::RANGE, Keyword::LIST, Keyword::HASH])? {
+                Keyword::RANGE => PartitionStrategy::Range,
+                Keyword::LIST => PartitionStrategy::List,
+                Keyword::HASH => PartitionStrategy::Hash,
+                _ => unreachable!(),
+            };
+        self.expect_token(&Token::LParen)?;
+        let columns = self.parse_comma_separated(Parser::parse_expr)?;
+        self.expect_token(&Token::RParen)?;
+        Ok(PartitionSpec { strategy, columns })
+    }
+
     fn parse_columns(&mut self) -> Result<(Vec<ColumnDef>, Vec<TableConstraint>), ParserError> {
         let mut columns = vec![];
         let mut constraints = vec![];
@@ -1581,12 +2533,15 @@ impl<'a> Parser<'a> {
         }
 
         loop {
+            // Collected first, as backtracking over a token also skips the whitespace before it
+            let leading_comments = self.parse_comment();
             if let Some(constraint) = self.parse_optional_table_constraint()? {
                 constraints.push(constraint);
-            } else if let Token::Word(_) = self.peek_token() {
-                columns.push(self.parse_column_def()?);
-            } else if let Token::BackQuotedString(_) = self.peek_token() {
-                columns.push(self.parse_column_def()?);
+            } else if let Token::Word(_) | Token::BackQuotedString(_) = self.peek_token() {
+                columns.push(ColumnDef {
+                    leading_comments,
+                    ..self.parse_column_def()?
+                });
             } else {
                 return self.expected("column name or constraint definition", self.peek_token());
             }
@@ -1612,8 +2567,8 @@ impl<'a> Parser<'a> {
         };
         let mut options = vec![];
         loop {
-            if self.parse_keyword(Keyword::CONSTRAINT) {
-                let name = Some(self.parse_identifier()?);
+            if let Some(name) = self.parse_optional_constraint_name()? {
+                let name = Some(name);
                 if let Some(option) = self.parse_optional_column_option()? {
                     options.push(ColumnOptionDef { name, option });
                 } else {
@@ -1632,6 +2587,7 @@ impl<'a> Parser<'a> {
             name,
             data_type,
             collation,
+            leading_comments: vec![],
             options,
         })
     }
@@ -1652,24 +2608,14 @@ impl<'a> Parser<'a> {
             // PostgreSQL allows omitting the column list and
             // uses the primary key column of the foreign table by default
             let referred_columns = self.parse_parenthesized_column_list(Optional)?;
-            let mut on_delete = None;
-            let mut on_update = None;
-            loop {
-                if on_delete.is_none() && self.parse_keywords(&[Keyword::ON, Keyword::DELETE]) {
-                    on_delete = Some(self.parse_referential_action()?);
-                } else if on_update.is_none()
-                    && self.parse_keywords(&[Keyword::ON, Keyword::UPDATE])
-                {
-                    on_update = Some(self.parse_referential_action()?);
-                } else {
-                    break;
-                }
-            }
+            let (on_delete, on_update) = self.parse_referential_triggers()?;
+            let characteristics = self.parse_constraint_characteristics()?;
             Ok(Some(ColumnOption::ForeignKey {
                 foreign_table,
                 referred_columns,
                 on_delete,
                 on_update,
+                characteristics,
             }))
         } else if self.parse_keyword(Keyword::CHECK) {
             self.expect_token(&Token::LParen)?;
@@ -1690,11 +2636,37 @@ impl<'a> Parser<'a> {
             Ok(Some(ColumnOption::DialectSpecific(vec![
                 Token::make_keyword("AUTOINCREMENT"),
             ])))
+        } else if dialect_of!(self is MySqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::COMMENT)
+        {
+            match self.next_token() {
+                Token::SingleQuotedString(comment) => Ok(Some(ColumnOption::Comment(comment))),
+                unexpected => self.expected("a string after COMMENT", unexpected),
+            }
         } else {
             Ok(None)
         }
     }
 
+    /// Parse the `ON DELETE` and `ON UPDATE` clauses of a foreign key, which
+    /// may appear in either order, each at most once
+    fn parse_referential_triggers(
+        &mut self,
+    ) -> Result<(Option<ReferentialAction>, Option<ReferentialAction>), ParserError> {
+        let mut on_delete = None;
+        let mut on_update = None;
+        loop {
+            if on_delete.is_none() && self.parse_keywords(&[Keyword::ON, Keyword::DELETE]) {
+                on_delete = Some(self.parse_referential_action()?);
+            } else if on_update.is_none() && self.parse_keywords(&[Keyword::ON, Keyword::UPDATE]) {
+                on_update = Some(self.parse_referential_action()?);
+            } else {
+                break;
+            }
+        }
+        Ok((on_delete, on_update))
+    }
+
     pub fn parse_referential_action(&mut self) -> Result<ReferentialAction, ParserError> {
         if self.parse_keyword(Keyword::RESTRICT) {
             Ok(ReferentialAction::Restrict)
@@ -1714,14 +2686,20 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse the `CONSTRAINT <name>` that may prefix any column or table
+    /// constraint, returning the name if present
+    pub fn parse_optional_constraint_name(&mut self) -> Result<Option<Ident>, ParserError> {
+        if self.parse_keyword(Keyword::CONSTRAINT) {
+            Ok(Some(self.parse_identifier_strict()?))
+        } else {
+            Ok(None)
+        }
+    }
+
     pub fn parse_optional_table_constraint(
         &mut self,
     ) -> Result<Option<TableConstraint>, ParserError> {
-        let name = if self.parse_keyword(Keyword::CONSTRAINT) {
-            Some(self.parse_identifier()?)
-        } else {
-            None
-        };
+        let name = self.parse_optional_constraint_name()?;
         match self.next_token() {
             Token::Word(w) if w.keyword == Keyword::PRIMARY || w.keyword == Keyword::UNIQUE => {
                 let is_primary = w.keyword == Keyword::PRIMARY;
@@ -1729,10 +2707,12 @@ impl<'a> Parser<'a> {
                     self.expect_keyword(Keyword::KEY)?;
                 }
                 let columns = self.parse_parenthesized_column_list(Mandatory)?;
+                let characteristics = self.parse_constraint_characteristics()?;
                 Ok(Some(TableConstraint::Unique {
                     name,
                     columns,
                     is_primary,
+                    characteristics,
                 }))
             }
             Token::Word(w) if w.keyword == Keyword::FOREIGN => {
@@ -1741,11 +2721,16 @@ impl<'a> Parser<'a> {
                 self.expect_keyword(Keyword::REFERENCES)?;
                 let foreign_table = self.parse_object_name()?;
                 let referred_columns = self.parse_parenthesized_column_list(Mandatory)?;
+                let (on_delete, on_update) = self.parse_referential_triggers()?;
+                let characteristics = self.parse_constraint_characteristics()?;
                 Ok(Some(TableConstraint::ForeignKey {
                     name,
                     columns,
                     foreign_table,
                     referred_columns,
+                    on_delete,
+                    on_update,
+                    characteristics,
                 }))
             }
             Token::Word(w) if w.keyword == Keyword::CHECK => {
@@ -1765,6 +2750,43 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse `[ NOT ] DEFERRABLE` and `INITIALLY { DEFERRED | IMMEDIATE }`,
+    /// each at most once and in either order, after a constraint
+    pub fn parse_constraint_characteristics(
+        &mut self,
+    ) -> Result<Option<ConstraintCharacteristics>, ParserError> {
+        let mut characteristics = ConstraintCharacteristics {
+            deferrable: None,
+            initially: None,
+        };
+        loop {
+            if characteristics.deferrable.is_none()
+                && self.parse_keywords(&[Keyword::NOT, Keyword::DEFERRABLE])
+            {
+                characteristics.deferrable = Some(false);
+            } else if characteristics.deferrable.is_none()
+                && self.parse_keyword(Keyword::DEFERRABLE)
+            {
+                characteristics.deferrable = Some(true);
+            } else if characteristics.initially.is_none() && self.parse_keyword(Keyword::INITIALLY)
+            {
+                characteristics.initially =
+                    match self.parse_one_of_keywords(&[Keyword::DEFERRED, Keyword::IMMEDIATE]) {
+                        Some(Keyword::DEFERRED) => Some(DeferrableInitial::Deferred),
+                        Some(Keyword::IMMEDIATE) => Some(DeferrableInitial::Immediate),
+                        _ => return self.expected("DEFERRED or IMMEDIATE", self.peek_token()),
+                    };
+            } else {
+                break;
+            }
+        }
+        if characteristics.deferrable.is_none() && characteristics.initially.is_none() {
+            Ok(None)
+        } else {
+            Ok(Some(characteristics))
+        }
+    }
+
     pub fn parse_options(&mut self, keyword: Keyword) -> Result<Vec<SqlOption>, ParserError> {
         if self.parse_keyword(keyword) {
             self.expect_token(&Token::LParen)?;
@@ -1784,10 +2806,51 @@ impl<'a> Parser<'a> {
     }
 
     pub fn parse_alter(&mut self) -> Result<Statement, ParserError> {
-        self.expect_keyword(Keyword::TABLE)?;
+        let object_type = match self.parse_one_of_keywords(&[
+            Keyword::TABLE,
+            Keyword::VIEW,
+            Keyword::SEQUENCE,
+            Keyword::INDEX,
+        ]) {
+            Some(Keyword::TABLE) => ObjectType::Table,
+            Some(Keyword::VIEW) => ObjectType::View,
+            Some(Keyword::SEQUENCE) => ObjectType::Sequence,
+            Some(Keyword::INDEX) => ObjectType::Index,
+            _ => {
+                return self.expected(
+                    "TABLE, VIEW, SEQUENCE or INDEX after ALTER",
+                    self.peek_token(),
+                )
+            }
+        };
+        if object_type != ObjectType::Table {
+            let name = self.parse_object_name()?;
+            return match self.parse_alter_rename_or_set_schema()? {
+                Some(operation) => Ok(Statement::AlterObject {
+                    object_type,
+                    name,
+                    operation,
+                }),
+                None => self.expected(
+                    &format!("RENAME TO or SET SCHEMA after ALTER {}", object_type),
+                    self.peek_token(),
+                ),
+            };
+        }
         let _ = self.parse_keyword(Keyword::ONLY);
         let table_name = self.parse_object_name()?;
-        let operation = if self.parse_keyword(Keyword::ADD) {
+        let operations = self.parse_comma_separated(Parser::parse_alter_table_operation)?;
+        Ok(Statement::AlterTable {
+            name: table_name,
+            operations,
+        })
+    }
+
+    /// Parse one of the comma-separated operations of an `ALTER TABLE`
+    pub fn parse_alter_table_operation(&mut self) -> Result<AlterTableOperation, ParserError> {
+        let operation = if let Some(operation) = self.parse_alter_rename_or_set_schema()? {
+            operation
+        } else if self.parse_keyword(Keyword::ADD) {
             if let Some(constraint) = self.parse_optional_table_constraint()? {
                 AlterTableOperation::AddConstraint(constraint)
             } else {
@@ -1808,18 +2871,13 @@ impl<'a> Parser<'a> {
                 }
             }
         } else if self.parse_keyword(Keyword::RENAME) {
-            if self.parse_keyword(Keyword::TO) {
-                let table_name = self.parse_object_name()?;
-                AlterTableOperation::RenameTable { table_name }
-            } else {
-                let _ = self.parse_keyword(Keyword::COLUMN);
-                let old_column_name = self.parse_identifier()?;
-                self.expect_keyword(Keyword::TO)?;
-                let new_column_name = self.parse_identifier()?;
-                AlterTableOperation::RenameColumn {
-                    old_column_name,
-                    new_column_name,
-                }
+            let _ = self.parse_keyword(Keyword::COLUMN);
+            let old_column_name = self.parse_identifier()?;
+            self.expect_keyword(Keyword::TO)?;
+            let new_column_name = self.parse_identifier()?;
+            AlterTableOperation::RenameColumn {
+                old_column_name,
+                new_column_name,
             }
         } else if self.parse_keyword(Keyword::DROP) {
             if self.parse_keywords(&[Keyword::IF, Keyword::EXISTS, Keyword::PARTITION]) {
@@ -1862,32 +2920,144 @@ impl<'a> Parser<'a> {
                 old_partitions: before,
                 new_partitions: renames,
             }
+        } else if self.parse_keywords(&[Keyword::ALTER, Keyword::CONSTRAINT]) {
+            let name = self.parse_identifier()?;
+            match self.parse_constraint_characteristics()? {
+                Some(characteristics) => AlterTableOperation::AlterConstraint {
+                    name,
+                    characteristics,
+                },
+                None => {
+                    return self.expected(
+                        "DEFERRABLE, NOT DEFERRABLE or INITIALLY after ALTER CONSTRAINT <name>",
+                        self.peek_token(),
+                    )
+                }
+            }
+        } else if self.parse_keywords(&[Keyword::VALIDATE, Keyword::CONSTRAINT]) {
+            let name = self.parse_identifier()?;
+            AlterTableOperation::ValidateConstraint { name }
+        } else if self.parse_keyword(Keyword::ALTER) {
+            let _ = self.parse_keyword(Keyword::COLUMN);
+            let column_name = self.parse_identifier()?;
+            let op = if self.parse_keywords(&[Keyword::SET, Keyword::NOT, Keyword::NULL]) {
+                AlterColumnOperation::SetNotNull
+            } else if self.parse_keywords(&[Keyword::DROP, Keyword::NOT, Keyword::NULL]) {
+                AlterColumnOperation::DropNotNull
+            } else if self.parse_keywords(&[Keyword::SET, Keyword::DEFAULT]) {
+                AlterColumnOperation::SetDefault {
+                    value: self.parse_expr()?,
+                }
+            } else if self.parse_keywords(&[Keyword::DROP, Keyword::DEFAULT]) {
+                AlterColumnOperation::DropDefault
+            } else if self.parse_keywords(&[Keyword::SET, Keyword::DATA, Keyword::TYPE])
+                || self.parse_keyword(Keyword::TYPE)
+            {
+                AlterColumnOperation::SetDataType {
+                    data_type: self.parse_data_type()?,
+                }
+            } else {
+                return self.expected(
+                    "SET/DROP NOT NULL, SET DEFAULT, SET DATA TYPE after ALTER COLUMN",
+                    self.peek_token(),
+                );
+            };
+            AlterTableOperation::AlterColumn { column_name, op }
         } else {
             return self.expected(
-                "ADD, RENAME, PARTITION or DROP after ALTER TABLE",
+                "ADD, RENAME, PARTITION, ALTER, DROP, VALIDATE or SET SCHEMA after ALTER TABLE",
                 self.peek_token(),
             );
         };
-        Ok(Statement::AlterTable {
-            name: table_name,
-            operation,
-        })
+        Ok(operation)
+    }
+
+    /// Parse the `RENAME TO <name>` or `SET SCHEMA <schema>` operations
+    /// shared by `ALTER TABLE` and the other `ALTER <object>` statements
+    fn parse_alter_rename_or_set_schema(
+        &mut self,
+    ) -> Result<Option<AlterTableOperation>, ParserError> {
+        if self.parse_keywords(&[Keyword::RENAME, Keyword::TO]) {
+            let table_name = self.parse_object_name()?;
+            Ok(Some(AlterTableOperation::RenameTable { table_name }))
+        } else if self.parse_keywords(&[Keyword::SET, Keyword::SCHEMA]) {
+            let new_schema = self.parse_object_name()?;
+            Ok(Some(AlterTableOperation::SetSchema { new_schema }))
+        } else {
+            Ok(None)
+        }
     }
 
     /// Parse a copy statement
     pub fn parse_copy(&mut self) -> Result<Statement, ParserError> {
         let table_name = self.parse_object_name()?;
         let columns = self.parse_parenthesized_column_list(Optional)?;
-        self.expect_keywords(&[Keyword::FROM, Keyword::STDIN])?;
-        self.expect_token(&Token::SemiColon)?;
-        let values = self.parse_tsv();
+        let to = match self.parse_one_of_keywords(&[Keyword::FROM, Keyword::TO]) {
+            Some(Keyword::FROM) => false,
+            Some(Keyword::TO) => true,
+            _ => self.expected("FROM or TO", self.peek_token())?,
+        };
+        let target = if self.parse_keyword(Keyword::STDIN) {
+            CopyTarget::Stdin
+        } else if self.parse_keyword(Keyword::STDOUT) {
+            CopyTarget::Stdout
+        } else {
+            match self.next_token() {
+                Token::SingleQuotedString(filename) => CopyTarget::File { filename },
+                unexpected => self.expected("STDIN, STDOUT or a file name", unexpected)?,
+            }
+        };
+        // The WITH keyword before the options list is optional
+        let with = self.parse_keyword(Keyword::WITH);
+        let options = if with || self.peek_token() == Token::LParen {
+            self.expect_token(&Token::LParen)?;
+            let options = self.parse_comma_separated(Parser::parse_copy_option)?;
+            self.expect_token(&Token::RParen)?;
+            options
+        } else {
+            vec![]
+        };
+        // The data to load follows the statement directly when copying from STDIN
+        let values = if !to && target == CopyTarget::Stdin && self.consume_token(&Token::SemiColon)
+        {
+            self.parse_tsv()
+        } else {
+            vec![]
+        };
         Ok(Statement::Copy {
             table_name,
             columns,
+            to,
+            target,
+            options,
             values,
         })
     }
 
+    /// Parse a `COPY` option: a name, optionally followed by its value
+    fn parse_copy_option(&mut self) -> Result<CopyOption, ParserError> {
+        let name = self.parse_identifier()?;
+        let value = match self.peek_token() {
+            Token::Comma | Token::RParen => None,
+            // these options take exactly one character
+            _ if ["DELIMITER", "QUOTE", "ESCAPE"]
+                .iter()
+                .any(|opt| name.value.eq_ignore_ascii_case(opt)) =>
+            {
+                let s = self.parse_literal_string()?;
+                if s.chars().count() != 1 {
+                    return parser_err!(format!(
+                        "COPY option {} must be a single character, found: '{}'",
+                        name, s
+                    ));
+                }
+                Some(Expr::Value(Value::SingleQuotedString(s)))
+            }
+            _ => Some(self.parse_expr()?),
+        };
+        Ok(CopyOption { name, value })
+    }
+
     /// Parse a tab separated values in
     /// COPY payload
     fn parse_tsv(&mut self) -> Vec<Option<String>> {
@@ -1942,10 +3112,22 @@ impl<'a> Parser<'a> {
             // The call to n.parse() returns a bigdecimal when the
             // bigdecimal feature is enabled, and is otherwise a no-op
             // (i.e., it returns the input string).
-            Token::Number(ref n, l) => match n.parse() {
-                Ok(n) => Ok(Value::Number(n, l)),
-                Err(e) => parser_err!(format!("Could not parse '{}' as number: {}", n, e)),
-            },
+            Token::Number(ref n, l) => {
+                if n.starts_with('.') || n.ends_with('.') {
+                    if !self.dialect.supports_numeric_dot_shorthand() {
+                        return parser_err!(format!(
+                            "Numeric literal '{}' needs digits on both sides of the decimal point",
+                            n
+                        ));
+                    }
+                    self.warnings
+                        .push(format!("Nonstandard numeric literal '{}'", n));
+                }
+                match n.parse() {
+                    Ok(n) => Ok(Value::Number(n, l)),
+                    Err(e) => parser_err!(format!("Could not parse '{}' as number: {}", n, e)),
+                }
+            }
             Token::SingleQuotedString(ref s) => Ok(Value::SingleQuotedString(s.to_string())),
             Token::NationalStringLiteral(ref s) => Ok(Value::NationalStringLiteral(s.to_string())),
             Token::HexStringLiteral(ref s) => Ok(Value::HexStringLiteral(s.to_string())),
@@ -1984,10 +3166,30 @@ impl<'a> Parser<'a> {
 
     /// Parse a SQL datatype (in the context of a CREATE TABLE statement for example)
     pub fn parse_data_type(&mut self) -> Result<DataType, ParserError> {
+        let mut data_type = self.parse_element_data_type()?;
+        loop {
+            // PostgreSQL array types, e.g. `INT[]` or `TEXT[][]`
+            if self.peek_token() == Token::LBracket && self.peek_nth_token(1) == Token::RBracket {
+                self.next_token();
+                self.next_token();
+                data_type = DataType::Array(Box::new(data_type));
+            // ANSI collection types, e.g. `INT ARRAY` or `INT MULTISET`
+            } else if self.parse_keyword(Keyword::ARRAY) {
+                data_type = DataType::Array(Box::new(data_type));
+            } else if self.parse_keyword(Keyword::MULTISET) {
+                data_type = DataType::Multiset(Box::new(data_type));
+            } else {
+                return Ok(data_type);
+            }
+        }
+    }
+
+    /// Parse a datatype other than an array type
+    fn parse_element_data_type(&mut self) -> Result<DataType, ParserError> {
         match self.next_token() {
             Token::Word(w) => match w.keyword {
-                Keyword::BOOLEAN => Ok(DataType::Boolean),
-                Keyword::FLOAT => Ok(DataType::Float(self.parse_optional_precision()?)),
+                Keyword::BOOL | Keyword::BOOLEAN => Ok(DataType::Boolean),
+                Keyword::FLOAT => Ok(DataType::Float(self.parse_optional_type_length("FLOAT")?)),
                 Keyword::REAL => Ok(DataType::Real),
                 Keyword::DOUBLE => {
                     let _ = self.parse_keyword(Keyword::PRECISION);
@@ -1997,49 +3199,96 @@ impl<'a> Parser<'a> {
                 Keyword::SMALLINT => Ok(DataType::SmallInt),
                 Keyword::INT | Keyword::INTEGER => Ok(DataType::Int),
                 Keyword::BIGINT => Ok(DataType::BigInt),
-                Keyword::VARCHAR => Ok(DataType::Varchar(self.parse_optional_precision()?)),
+                Keyword::VARCHAR => Ok(DataType::Varchar(self.parse_varchar_length()?)),
                 Keyword::CHAR | Keyword::CHARACTER => {
                     if self.parse_keyword(Keyword::VARYING) {
-                        Ok(DataType::Varchar(self.parse_optional_precision()?))
+                        Ok(DataType::Varchar(self.parse_varchar_length()?))
                     } else {
-                        Ok(DataType::Char(self.parse_optional_precision()?))
+                        Ok(DataType::Char(self.parse_optional_type_length("CHAR")?))
                     }
                 }
                 Keyword::UUID => Ok(DataType::Uuid),
                 Keyword::DATE => Ok(DataType::Date),
-                Keyword::TIMESTAMP => {
-                    // TBD: we throw away "with/without timezone" information
-                    if self.parse_keyword(Keyword::WITH) || self.parse_keyword(Keyword::WITHOUT) {
-                        self.expect_keywords(&[Keyword::TIME, Keyword::ZONE])?;
-                    }
-                    Ok(DataType::Timestamp)
-                }
-                Keyword::TIME => {
-                    // TBD: we throw away "with/without timezone" information
-                    if self.parse_keyword(Keyword::WITH) || self.parse_keyword(Keyword::WITHOUT) {
-                        self.expect_keywords(&[Keyword::TIME, Keyword::ZONE])?;
+                Keyword::TIMESTAMP => Ok(DataType::Timestamp {
+                    tz: self.parse_time_zone_qualifier()?,
+                }),
+                Keyword::TIME => Ok(DataType::Time {
+                    tz: self.parse_time_zone_qualifier()?,
+                }),
+                Keyword::TIMESTAMPTZ | Keyword::TIMETZ if dialect_of!(self is PostgreSqlDialect | GenericDialect) => {
+                    if w.keyword == Keyword::TIMESTAMPTZ {
+                        Ok(DataType::Timestamp { tz: true })
+                    } else {
+                        Ok(DataType::Time { tz: true })
                     }
-                    Ok(DataType::Time)
                 }
                 // Interval types can be followed by a complicated interval
                 // qualifier that we don't currently support. See
                 // parse_interval_literal for a taste.
                 Keyword::INTERVAL => Ok(DataType::Interval),
                 Keyword::REGCLASS => Ok(DataType::Regclass),
-                Keyword::STRING => Ok(DataType::String),
-                Keyword::TEXT => {
-                    if self.consume_token(&Token::LBracket) {
-                        // Note: this is postgresql-specific
-                        self.expect_token(&Token::RBracket)?;
-                        Ok(DataType::Array(Box::new(DataType::Text)))
-                    } else {
-                        Ok(DataType::Text)
-                    }
+                Keyword::STRING if dialect_of!(self is HiveDialect | BigQueryDialect | SnowflakeDialect | GenericDialect) => {
+                    Ok(DataType::String)
                 }
+                Keyword::TEXT => Ok(DataType::Text),
                 Keyword::BYTEA => Ok(DataType::Bytea),
+                Keyword::JSON
+                | Keyword::JSONB
+                | Keyword::INET
+                | Keyword::CIDR
+                | Keyword::MACADDR
+                | Keyword::MONEY
+                | Keyword::SMALLSERIAL
+                | Keyword::SERIAL
+                | Keyword::BIGSERIAL
+                | Keyword::INT2
+                | Keyword::INT4
+                | Keyword::INT8
+                | Keyword::FLOAT4
+                | Keyword::FLOAT8
+                    if dialect_of!(self is PostgreSqlDialect | GenericDialect) =>
+                {
+                    Ok(match w.keyword {
+                        Keyword::JSON => DataType::Json,
+                        Keyword::JSONB => DataType::Jsonb,
+                        Keyword::INET => DataType::Inet,
+                        Keyword::CIDR => DataType::Cidr,
+                        Keyword::MACADDR => DataType::Macaddr,
+                        Keyword::MONEY => DataType::Money,
+                        Keyword::SMALLSERIAL => DataType::SmallSerial,
+                        Keyword::SERIAL => DataType::Serial,
+                        Keyword::BIGSERIAL => DataType::BigSerial,
+                        Keyword::INT2 => DataType::SmallInt,
+                        Keyword::INT4 => DataType::Int,
+                        Keyword::INT8 => DataType::BigInt,
+                        Keyword::FLOAT4 => DataType::Real,
+                        Keyword::FLOAT8 => DataType::Double,
+                        _ => unreachable!(),
+                    })
+                }
                 Keyword::NUMERIC | Keyword::DECIMAL | Keyword::DEC => {
-                    let (precision, scale) = self.parse_optional_precision_scale()?;
-                    Ok(DataType::Decimal(precision, scale))
+                    // Report the spelling used in the input, not the canonical one
+                    let type_name = w.value.to_uppercase();
+                    self.check_positive_type_length(&type_name)?;
+                    match self.parse_optional_precision_scale()? {
+                        (Some(0), _) => parser_err!(format!(
+                            "Precision of type {} must be at least 1",
+                            type_name
+                        )),
+                        (Some(precision), Some(scale)) if scale > precision => {
+                            parser_err!(format!(
+                                "Scale of type {} must not exceed its precision, found {}({}, {})",
+                                type_name, type_name, precision, scale
+                            ))
+                        }
+                        (precision, scale) => Ok(DataType::Decimal(precision, scale)),
+                    }
+                }
+                Keyword::ENUM if dialect_of!(self is MySqlDialect | GenericDialect) => {
+                    Ok(DataType::Enum(self.parse_string_values()?))
+                }
+                Keyword::SET if dialect_of!(self is MySqlDialect | GenericDialect) => {
+                    Ok(DataType::Set(self.parse_string_values()?))
                 }
                 _ => {
                     self.prev_token();
@@ -2051,21 +3300,103 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse the optional `WITH TIME ZONE` or `WITHOUT TIME ZONE` after
+    /// `TIME`/`TIMESTAMP`, returning whether the type has a time zone
+    fn parse_time_zone_qualifier(&mut self) -> Result<bool, ParserError> {
+        let tz = match self.parse_one_of_keywords(&[Keyword::WITH, Keyword::WITHOUT]) {
+            Some(keyword) => {
+                self.expect_keywords(&[Keyword::TIME, Keyword::ZONE])?;
+                keyword == Keyword::WITH
+            }
+            None => false,
+        };
+        Ok(tz)
+    }
+
+    /// Parse the optional `(n)` length of a type such as `CHAR(n)`, rejecting
+    /// lengths that are zero or negative
+    fn parse_optional_type_length(&mut self, type_name: &str) -> Result<Option<u64>, ParserError> {
+        self.check_positive_type_length(type_name)?;
+        match self.parse_optional_precision()? {
+            Some(0) => parser_err!(format!("Length of type {} must be at least 1", type_name)),
+            length => Ok(length),
+        }
+    }
+
+    /// Parse the length of `VARCHAR`, which MySQL requires to be present
+    fn parse_varchar_length(&mut self) -> Result<Option<u64>, ParserError> {
+        let length = self.parse_optional_type_length("VARCHAR")?;
+        if length.is_none() && dialect_of!(self is MySqlDialect) {
+            return parser_err!("Type VARCHAR requires a length");
+        }
+        Ok(length)
+    }
+
+    /// Report `(-n)` as a negative length rather than a missing number
+    fn check_positive_type_length(&mut self, type_name: &str) -> Result<(), ParserError> {
+        if self.peek_token() == Token::LParen && self.peek_nth_token(1) == Token::Minus {
+            return parser_err!(format!(
+                "Type {} does not accept a negative length or precision",
+                type_name
+            ));
+        }
+        Ok(())
+    }
+
+    /// Parse a parenthesized, comma-separated list of string literals,
+    /// e.g. `('a', 'b')`
+    pub fn parse_string_values(&mut self) -> Result<Vec<String>, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let values = self.parse_comma_separated(|parser| match parser.next_token() {
+            Token::SingleQuotedString(s) => Ok(s),
+            unexpected => parser.expected("a string literal", unexpected),
+        })?;
+        self.expect_token(&Token::RParen)?;
+        Ok(values)
+    }
+
     /// Parse `AS identifier` (or simply `identifier` if it's not a reserved keyword)
     /// Some examples with aliases: `SELECT 1 foo`, `SELECT COUNT(*) AS cnt`,
     /// `SELECT ... FROM t1 foo, t2 bar`, `SELECT ... FROM (...) AS bar`
     pub fn parse_optional_alias(
         &mut self,
         reserved_kwds: &[Keyword],
+    ) -> Result<Option<Ident>, ParserError> {
+        self.parse_alias(Optional, reserved_kwds)
+    }
+
+    /// Parse an optional alias, where `as_keyword` says whether the alias
+    /// may be written without a preceding `AS`. A bare alias may not be one
+    /// of `reserved_kwds`, since those can start the construct following it.
+    ///
+    /// Where aliases appear, and the rules that apply:
+    ///
+    /// | Context                           | `AS`     | Bare alias may not be     |
+    /// |-----------------------------------|----------|---------------------------|
+    /// | SELECT item (`SELECT 1 foo`)      | optional | RESERVED_FOR_COLUMN_ALIAS |
+    /// | table factor (`FROM t1 foo`)      | optional | RESERVED_FOR_TABLE_ALIAS  |
+    /// | MySQL `INSERT ... VALUES ... AS v`| required | -                         |
+    /// | BigQuery `REPLACE (expr AS col)`  | required | -                         |
+    /// | CTE name (`WITH foo AS (...)`)    | required | (precedes `AS`, see `parse_cte`) |
+    pub fn parse_alias(
+        &mut self,
+        as_keyword: IsOptional,
+        reserved_kwds: &[Keyword],
     ) -> Result<Option<Ident>, ParserError> {
         let after_as = self.parse_keyword(Keyword::AS);
+        if !after_as && as_keyword == Mandatory {
+            return Ok(None);
+        }
         match self.next_token() {
             // Accept any identifier after `AS` (though many dialects have restrictions on
             // keywords that may appear here). If there's no `AS`: don't parse keywords,
             // which may start a construct allowed in this position, to be parsed as aliases.
             // (For example, in `FROM t1 JOIN` the `JOIN` will always be parsed as a keyword,
             // not an alias.)
-            Token::Word(w) if after_as || !reserved_kwds.contains(&w.keyword) => {
+            Token::Word(w)
+                if (after_as || !reserved_kwds.contains(&w.keyword))
+                    && !self.is_reserved_word(&w) =>
+            {
                 Ok(Some(w.to_ident()))
             }
             // MSSQL supports single-quoted strings as aliases for columns
@@ -2134,16 +3465,39 @@ impl<'a> Parser<'a> {
         Ok(idents)
     }
 
+    /// Check if `word` is unquoted and reserved by the current dialect, so
+    /// it can't be used as an identifier.
+    fn is_reserved_word(&self, word: &Word) -> bool {
+        word.quote_style.is_none() && self.dialect.is_reserved_keyword(&word.value)
+    }
+
     /// Parse a simple one-word identifier (possibly quoted, possibly a keyword)
     pub fn parse_identifier(&mut self) -> Result<Ident, ParserError> {
         match self.next_token() {
-            Token::Word(w) => Ok(w.to_ident()),
+            Token::Word(w) if !self.is_reserved_word(&w) => Ok(w.to_ident()),
             Token::SingleQuotedString(s) => Ok(Ident::with_quote('\'', s)),
             Token::BackQuotedString(s) => Ok(Ident::with_quote('`', s)),
             unexpected => self.expected("identifier", unexpected),
         }
     }
 
+    /// Parse a simple one-word identifier like [Parser::parse_identifier], but
+    /// reject unquoted keywords that introduce clauses (those reserved as
+    /// aliases) or that the dialect reserves, for positions where only a
+    /// true name is valid, such as a constraint name
+    pub fn parse_identifier_strict(&mut self) -> Result<Ident, ParserError> {
+        match self.peek_token() {
+            Token::Word(w)
+                if w.quote_style.is_none()
+                    && (keywords::RESERVED_FOR_TABLE_ALIAS.contains(&w.keyword)
+                        || keywords::RESERVED_FOR_COLUMN_ALIAS.contains(&w.keyword)) =>
+            {
+                self.expected("identifier", Token::Word(w))
+            }
+            _ => self.parse_identifier(),
+        }
+    }
+
     /// Parse a parenthesized comma-separated list of unqualified, possibly quoted identifiers
     pub fn parse_parenthesized_column_list(
         &mut self,
@@ -2190,15 +3544,23 @@ impl<'a> Parser<'a> {
     pub fn parse_delete(&mut self) -> Result<Statement, ParserError> {
         self.expect_keyword(Keyword::FROM)?;
         let table_name = self.parse_object_name()?;
+        let using = if self.parse_keyword(Keyword::USING) {
+            self.parse_comma_separated(Parser::parse_table_and_joins)?
+        } else {
+            vec![]
+        };
         let selection = if self.parse_keyword(Keyword::WHERE) {
             Some(self.parse_expr()?)
         } else {
             None
         };
+        let returning = self.parse_returning()?;
 
         Ok(Statement::Delete {
             table_name,
+            using,
             selection,
+            returning,
         })
     }
 
@@ -2229,14 +3591,41 @@ impl<'a> Parser<'a> {
             None
         };
 
-        if !self.parse_keyword(Keyword::INSERT) {
+        // A data-modifying statement may only follow a WITH clause, as a
+        // bare one is not a query, e.g. in `FROM (DELETE FROM t)`
+        let dml_keyword = if with.is_some() {
+            self.parse_one_of_keywords(&[Keyword::INSERT, Keyword::UPDATE, Keyword::DELETE])
+        } else {
+            None
+        };
+        let dml = match dml_keyword {
+            Some(Keyword::INSERT) => Some(SetExpr::Insert(self.parse_insert()?)),
+            Some(Keyword::UPDATE) => Some(SetExpr::Update(self.parse_update()?)),
+            Some(Keyword::DELETE) => Some(SetExpr::Delete(self.parse_delete()?)),
+            _ => None,
+        };
+
+        if let Some(body) = dml {
+            Ok(Query {
+                with,
+                body,
+                limit: None,
+                order_by: vec![],
+                offset: None,
+                fetch: None,
+            })
+        } else {
             let body = self.parse_query_body(0)?;
 
             let order_by = if self.parse_keywords(&[Keyword::ORDER, Keyword::BY]) {
-                self.parse_comma_separated(Parser::parse_order_by_expr)?
+                self.parse_comma_separated_until(
+                    Parser::parse_order_by_expr,
+                    &[Keyword::LIMIT, Keyword::FETCH],
+                )?
             } else {
                 vec![]
             };
+            self.check_order_by_positions(&body, &order_by);
 
             let limit = if self.parse_keyword(Keyword::LIMIT) {
                 self.parse_limit()?
@@ -2256,6 +3645,17 @@ impl<'a> Parser<'a> {
                 None
             };
 
+            if matches!(
+                fetch,
+                Some(Fetch {
+                    with_ties: true,
+                    ..
+                })
+            ) && order_by.is_empty()
+            {
+                return parser_err!("FETCH ... WITH TIES requires an ORDER BY clause");
+            }
+
             Ok(Query {
                 with,
                 body,
@@ -2264,48 +3664,67 @@ impl<'a> Parser<'a> {
                 offset,
                 fetch,
             })
-        } else {
-            let insert = self.parse_insert()?;
-            Ok(Query {
-                with,
-                body: SetExpr::Insert(insert),
-                limit: None,
-                order_by: vec![],
-                offset: None,
-                fetch: None,
-            })
         }
     }
 
-    /// Parse a CTE (`alias [( col1, col2, ... )] AS (subquery)`)
+    /// Record a warning for each `ORDER BY <n>` that refers to a position
+    /// outside of the select list, when the number of columns is known
+    fn check_order_by_positions(&mut self, body: &SetExpr, order_by: &[OrderByExpr]) {
+        // the output columns of a set operation are those of its first operand
+        let mut body = body;
+        let projection = loop {
+            match body {
+                SetExpr::Select(select) => break &select.projection,
+                SetExpr::SetOperation { left, .. } => body = left,
+                SetExpr::Query(query) => body = &query.body,
+                _ => return,
+            }
+        };
+        if projection.iter().any(|item| {
+            !matches!(
+                item,
+                SelectItem::UnnamedExpr(_) | SelectItem::ExprWithAlias { .. }
+            )
+        }) {
+            // a wildcard expands to an unknown number of columns
+            return;
+        }
+        for expr in order_by {
+            if let Expr::Value(Value::Number(n, _)) = &expr.expr {
+                match expr.ordinal() {
+                    Some(position) if position >= 1 && position <= projection.len() as u64 => {}
+                    _ => self.warnings.push(format!(
+                        "ORDER BY position {} is not in select list of {} column(s)",
+                        n,
+                        projection.len()
+                    )),
+                }
+            }
+        }
+    }
+
+    /// Parse a CTE (`alias [( col1, col2, ... )] AS (subquery)`), where,
+    /// unlike in other aliases, the `AS` is required
     fn parse_cte(&mut self) -> Result<Cte, ParserError> {
         let name = self.parse_identifier()?;
 
-        let mut cte = if self.parse_keyword(Keyword::AS) {
-            self.expect_token(&Token::LParen)?;
-            let query = self.parse_query()?;
-            self.expect_token(&Token::RParen)?;
-            let alias = TableAlias {
-                name,
-                columns: vec![],
-            };
-            Cte {
-                alias,
-                query,
-                from: None,
-            }
+        let columns = self.parse_parenthesized_column_list(Optional)?;
+        self.expect_keyword(Keyword::AS)?;
+        let materialized = if self.parse_keyword(Keyword::MATERIALIZED) {
+            Some(true)
+        } else if self.parse_keywords(&[Keyword::NOT, Keyword::MATERIALIZED]) {
+            Some(false)
         } else {
-            let columns = self.parse_parenthesized_column_list(Optional)?;
-            self.expect_keyword(Keyword::AS)?;
-            self.expect_token(&Token::LParen)?;
-            let query = self.parse_query()?;
-            self.expect_token(&Token::RParen)?;
-            let alias = TableAlias { name, columns };
-            Cte {
-                alias,
-                query,
-                from: None,
-            }
+            None
+        };
+        self.expect_token(&Token::LParen)?;
+        let query = self.parse_query()?;
+        self.expect_token(&Token::RParen)?;
+        let mut cte = Cte {
+            alias: TableAlias { name, columns },
+            query,
+            from: None,
+            materialized,
         };
         if self.parse_keyword(Keyword::FROM) {
             cte.from = Some(self.parse_identifier()?);
@@ -2386,7 +3805,8 @@ impl<'a> Parser<'a> {
             None
         };
 
-        let projection = self.parse_comma_separated(Parser::parse_select_item)?;
+        let projection =
+            self.parse_comma_separated_until(Parser::parse_select_item, &[Keyword::FROM])?;
 
         // Note that for keywords to be properly handled here, they need to be
         // added to `RESERVED_FOR_COLUMN_ALIAS` / `RESERVED_FOR_TABLE_ALIAS`,
@@ -2436,7 +3856,10 @@ impl<'a> Parser<'a> {
         };
 
         let group_by = if self.parse_keywords(&[Keyword::GROUP, Keyword::BY]) {
-            self.parse_comma_separated(Parser::parse_expr)?
+            self.parse_comma_separated_until(
+                Parser::parse_expr,
+                &[Keyword::HAVING, Keyword::ORDER],
+            )?
         } else {
             vec![]
         };
@@ -2517,6 +3940,17 @@ impl<'a> Parser<'a> {
     }
 
     pub fn parse_show(&mut self) -> Result<Statement, ParserError> {
+        if dialect_of!(self is MySqlDialect | GenericDialect) {
+            if self.parse_keywords(&[Keyword::FULL, Keyword::TABLES]) {
+                return self.parse_show_tables(true);
+            } else if self.parse_keyword(Keyword::TABLES) {
+                return self.parse_show_tables(false);
+            } else if self.parse_keyword(Keyword::VARIABLES) {
+                return Ok(Statement::ShowVariables {
+                    filter: self.parse_show_statement_filter()?,
+                });
+            }
+        }
         if self
             .parse_one_of_keywords(&[
                 Keyword::EXTENDED,
@@ -2553,6 +3987,23 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn parse_show_tables(&mut self, full: bool) -> Result<Statement, ParserError> {
+        let db_name = if self
+            .parse_one_of_keywords(&[Keyword::FROM, Keyword::IN])
+            .is_some()
+        {
+            Some(self.parse_object_name()?)
+        } else {
+            None
+        };
+        let filter = self.parse_show_statement_filter()?;
+        Ok(Statement::ShowTables {
+            full,
+            db_name,
+            filter,
+        })
+    }
+
     fn parse_show_statement_filter(&mut self) -> Result<Option<ShowStatementFilter>, ParserError> {
         if self.parse_keyword(Keyword::LIKE) {
             Ok(Some(ShowStatementFilter::Like(
@@ -2631,7 +4082,7 @@ impl<'a> Parser<'a> {
                     _ => break,
                 };
                 let relation = self.parse_table_factor()?;
-                let join_constraint = self.parse_join_constraint(natural)?;
+                let join_constraint = self.parse_join_constraint(natural, &relation)?;
                 Join {
                     relation,
                     join_operator: join_operator_type(join_constraint),
@@ -2649,7 +4100,8 @@ impl<'a> Parser<'a> {
             if !self.consume_token(&Token::LParen) {
                 self.expected("subquery after LATERAL", self.peek_token())?;
             }
-            self.parse_derived_table_factor(Lateral)
+            let derived = self.parse_derived_table_factor(Lateral)?;
+            self.check_derived_table_alias(derived)
         } else if self.parse_keyword(Keyword::TABLE) {
             // parse table function (SELECT * FROM TABLE (<expr>) [ AS <alias> ])
             self.expect_token(&Token::LParen)?;
@@ -2679,9 +4131,11 @@ impl<'a> Parser<'a> {
             // `parse_derived_table_factor` below will return success after parsing the
             // subquery, followed by the closing ')', and the alias of the derived table.
             // In the example above this is case (3).
-            return_ok_if_some!(
+            if let Some(derived) =
                 self.maybe_parse(|parser| parser.parse_derived_table_factor(NotLateral))
-            );
+            {
+                return self.check_derived_table_alias(derived);
+            }
             // A parsing error from `parse_derived_table_factor` indicates that the '(' we've
             // recently consumed does not start a derived table (cases 1, 2, or 4).
             // `maybe_parse` will ignore such an error and rewind to be after the opening '('.
@@ -2767,6 +4221,18 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Reject a derived table without an alias in dialects requiring one
+    fn check_derived_table_alias(&self, factor: TableFactor) -> Result<TableFactor, ParserError> {
+        match factor {
+            TableFactor::Derived { alias: None, .. }
+                if self.dialect.requires_derived_table_alias() =>
+            {
+                self.expected("an alias for the subquery in FROM", self.peek_token())
+            }
+            factor => Ok(factor),
+        }
+    }
+
     pub fn parse_derived_table_factor(
         &mut self,
         lateral: IsLateral,
@@ -2784,7 +4250,11 @@ impl<'a> Parser<'a> {
         })
     }
 
-    fn parse_join_constraint(&mut self, natural: bool) -> Result<JoinConstraint, ParserError> {
+    fn parse_join_constraint(
+        &mut self,
+        natural: bool,
+        relation: &TableFactor,
+    ) -> Result<JoinConstraint, ParserError> {
         if natural {
             Ok(JoinConstraint::Natural)
         } else if self.parse_keyword(Keyword::ON) {
@@ -2793,17 +4263,32 @@ impl<'a> Parser<'a> {
         } else if self.parse_keyword(Keyword::USING) {
             let columns = self.parse_parenthesized_column_list(Mandatory)?;
             Ok(JoinConstraint::Using(columns))
-        } else {
+        } else if dialect_of!(self is HiveDialect | MySqlDialect) {
+            // these treat a join without a condition as a cross join
             Ok(JoinConstraint::None)
-            //self.expected("ON, or USING after JOIN", self.peek_token())
+        } else if dialect_of!(self is BigQueryDialect | GenericDialect)
+            && matches!(relation, TableFactor::Table { name, args, .. }
+                if !args.is_empty() && name.to_string().eq_ignore_ascii_case("UNNEST"))
+        {
+            // BigQuery joins each row with the elements of its own array,
+            // e.g. `FROM t JOIN UNNEST(t.arr) AS x`, so there is no condition
+            Ok(JoinConstraint::None)
+        } else {
+            self.expected("ON, or USING after JOIN", self.peek_token())
         }
     }
 
     /// Parse an INSERT statement
     pub fn parse_insert(&mut self) -> Result<Statement, ParserError> {
+        self.parse_insert_or_replace(false)
+    }
+
+    /// Parse the rest of an INSERT statement, or of a `REPLACE` statement
+    /// if `replace` is set, i.e. the leading `REPLACE` was already consumed
+    fn parse_insert_or_replace(&mut self, replace: bool) -> Result<Statement, ParserError> {
         let or = if !dialect_of!(self is SQLiteDialect) {
             None
-        } else if self.parse_keywords(&[Keyword::OR, Keyword::REPLACE]) {
+        } else if replace || self.parse_keywords(&[Keyword::OR, Keyword::REPLACE]) {
             Some(SqliteOnConflict::Replace)
         } else if self.parse_keywords(&[Keyword::OR, Keyword::ROLLBACK]) {
             Some(SqliteOnConflict::Rollback)
@@ -2813,12 +4298,17 @@ impl<'a> Parser<'a> {
             Some(SqliteOnConflict::Fail)
         } else if self.parse_keywords(&[Keyword::OR, Keyword::IGNORE]) {
             Some(SqliteOnConflict::Ignore)
-        } else if self.parse_keyword(Keyword::REPLACE) {
-            Some(SqliteOnConflict::Replace)
         } else {
             None
         };
-        let action = self.expect_one_of_keywords(&[Keyword::INTO, Keyword::OVERWRITE])?;
+        // MySQL `REPLACE INTO`, which deletes the conflicting rows before inserting
+        let replace_into = replace && dialect_of!(self is MySqlDialect | GenericDialect);
+        let action = if replace_into {
+            self.expect_keyword(Keyword::INTO)?;
+            Keyword::INTO
+        } else {
+            self.expect_one_of_keywords(&[Keyword::INTO, Keyword::OVERWRITE])?
+        };
         let overwrite = action == Keyword::OVERWRITE;
         let local = self.parse_keyword(Keyword::LOCAL);
 
@@ -2874,13 +4364,58 @@ impl<'a> Parser<'a> {
 
             // Hive allows you to specify columns after partitions as well if you want.
             let after_columns = self.parse_parenthesized_column_list(Optional)?;
-            let source = if format.is_some() {
+            let overriding = if self.parse_keyword(Keyword::OVERRIDING) {
+                let overriding =
+                    match self.expect_one_of_keywords(&[Keyword::SYSTEM, Keyword::USER])? {
+                        Keyword::SYSTEM => Overriding::SystemValue,
+                        _ => Overriding::UserValue,
+                    };
+                self.expect_keyword(Keyword::VALUE)?;
+                Some(overriding)
+            } else {
+                None
+            };
+            let assignments = if overriding.is_none()
+                && format.is_none()
+                && dialect_of!(self is MySqlDialect | GenericDialect)
+                && self.parse_keyword(Keyword::SET)
+            {
+                self.parse_comma_separated(Parser::parse_assignment)?
+            } else {
+                vec![]
+            };
+            let source = if format.is_some() || !assignments.is_empty() {
                 None
             } else {
                 Some(Box::new(self.parse_query()?))
             };
+            // MySQL 8 lets the VALUES rows be named for use in `ON DUPLICATE KEY UPDATE`
+            let values_alias = match &source {
+                Some(query)
+                    if matches!(query.body, SetExpr::Values(_))
+                        && dialect_of!(self is MySqlDialect | GenericDialect) =>
+                {
+                    match self.parse_alias(Mandatory, &[])? {
+                        Some(name) => {
+                            let columns = self.parse_parenthesized_column_list(Optional)?;
+                            Some(TableAlias { name, columns })
+                        }
+                        None => None,
+                    }
+                }
+                _ => None,
+            };
+            let on_conflict = if dialect_of!(self is PostgreSqlDialect | GenericDialect)
+                && self.parse_keywords(&[Keyword::ON, Keyword::CONFLICT])
+            {
+                Some(self.parse_on_conflict()?)
+            } else {
+                None
+            };
+            let returning = self.parse_returning()?;
             Ok(Statement::Insert {
                 or,
+                replace_into,
                 table_name,
                 overwrite,
                 partitioned,
@@ -2889,32 +4424,100 @@ impl<'a> Parser<'a> {
                 source,
                 table,
                 format,
+                values_alias,
+                on_conflict,
+                assignments,
+                overriding,
+                returning,
             })
         }
     }
 
+    /// Parse an optional `RETURNING <select item>, ...` clause of an `INSERT`,
+    /// `UPDATE` or `DELETE`
+    pub fn parse_returning(&mut self) -> Result<Option<Vec<SelectItem>>, ParserError> {
+        if self.parse_keyword(Keyword::RETURNING) {
+            Ok(Some(self.parse_comma_separated(Parser::parse_select_item)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Parse the rest of an `ON CONFLICT` clause, assuming `ON CONFLICT` was
+    /// already consumed
+    pub fn parse_on_conflict(&mut self) -> Result<OnConflict, ParserError> {
+        let conflict_target = if self.parse_keywords(&[Keyword::ON, Keyword::CONSTRAINT]) {
+            Some(ConflictTarget::OnConstraint(self.parse_object_name()?))
+        } else if self.peek_token() == Token::LParen {
+            let columns = self.parse_parenthesized_column_list(Mandatory)?;
+            let predicate = if self.parse_keyword(Keyword::WHERE) {
+                Some(self.parse_expr()?)
+            } else {
+                None
+            };
+            Some(ConflictTarget::Columns { columns, predicate })
+        } else {
+            None
+        };
+        self.expect_keyword(Keyword::DO)?;
+        let action = if self.parse_keyword(Keyword::NOTHING) {
+            OnConflictAction::DoNothing
+        } else {
+            self.expect_keywords(&[Keyword::UPDATE, Keyword::SET])?;
+            let assignments = self.parse_comma_separated(Parser::parse_assignment)?;
+            let selection = if self.parse_keyword(Keyword::WHERE) {
+                Some(Box::new(self.parse_expr()?))
+            } else {
+                None
+            };
+            OnConflictAction::DoUpdate {
+                assignments,
+                selection,
+            }
+        };
+        Ok(OnConflict {
+            conflict_target,
+            action,
+        })
+    }
+
     pub fn parse_update(&mut self) -> Result<Statement, ParserError> {
         let table_name = self.parse_object_name()?;
         self.expect_keyword(Keyword::SET)?;
         let assignments = self.parse_comma_separated(Parser::parse_assignment)?;
+        let from = if self.parse_keyword(Keyword::FROM) {
+            self.parse_comma_separated(Parser::parse_table_and_joins)?
+        } else {
+            vec![]
+        };
         let selection = if self.parse_keyword(Keyword::WHERE) {
             Some(self.parse_expr()?)
         } else {
             None
         };
+        let returning = self.parse_returning()?;
         Ok(Statement::Update {
             table_name,
             assignments,
+            from,
             selection,
+            returning,
         })
     }
 
-    /// Parse a `var = expr` assignment, used in an UPDATE statement
+    /// Parse a `column = value` or `(column, ...) = value` assignment, as
+    /// used by UPDATE, `ON CONFLICT DO UPDATE` and MySQL's `INSERT ... SET`
     pub fn parse_assignment(&mut self) -> Result<Assignment, ParserError> {
-        let id = self.parse_identifier()?;
+        let target = if self.consume_token(&Token::LParen) {
+            let columns = self.parse_comma_separated(Parser::parse_identifier)?;
+            self.expect_token(&Token::RParen)?;
+            AssignmentTarget::Tuple(columns)
+        } else {
+            AssignmentTarget::ColumnName(self.parse_identifier()?)
+        };
         self.expect_token(&Token::Eq)?;
         let value = self.parse_expr()?;
-        Ok(Assignment { id, value })
+        Ok(Assignment { target, value })
     }
 
     fn parse_function_args(&mut self) -> Result<FunctionArg, ParserError> {
@@ -2926,7 +4529,13 @@ impl<'a> Parser<'a> {
 
             Ok(FunctionArg::Named { name, arg })
         } else {
-            Ok(FunctionArg::Unnamed(self.parse_expr()?))
+            match self.parse_expr()? {
+                Expr::Wildcard => Ok(FunctionArg::Wildcard),
+                Expr::QualifiedWildcard(prefix) => {
+                    Ok(FunctionArg::QualifiedWildcard(ObjectName(prefix)))
+                }
+                expr => Ok(FunctionArg::Unnamed(expr)),
+            }
         }
     }
 
@@ -2958,9 +4567,14 @@ impl<'a> Parser<'a> {
     pub fn parse_select_item(&mut self) -> Result<SelectItem, ParserError> {
         let expr = self.parse_expr()?;
         if let Expr::Wildcard = expr {
-            Ok(SelectItem::Wildcard)
+            Ok(SelectItem::Wildcard(
+                self.parse_wildcard_additional_options()?,
+            ))
         } else if let Expr::QualifiedWildcard(prefix) = expr {
-            Ok(SelectItem::QualifiedWildcard(ObjectName(prefix)))
+            Ok(SelectItem::QualifiedWildcard(
+                ObjectName(prefix),
+                self.parse_wildcard_additional_options()?,
+            ))
         } else {
             // `expr` is a regular SQL expression and can be followed by an alias
             if let Some(alias) = self.parse_optional_alias(keywords::RESERVED_FOR_COLUMN_ALIAS)? {
@@ -2971,16 +4585,59 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse the `EXCLUDE` and `REPLACE` modifiers that may follow a wildcard
+    /// in the projection of BigQuery and Snowflake
+    pub fn parse_wildcard_additional_options(
+        &mut self,
+    ) -> Result<WildcardAdditionalOptions, ParserError> {
+        if !dialect_of!(self is BigQueryDialect | SnowflakeDialect | GenericDialect) {
+            return Ok(WildcardAdditionalOptions::default());
+        }
+        let exclude = if self.parse_keyword(Keyword::EXCLUDE) {
+            // A single column may be given without parentheses
+            if self.consume_token(&Token::LParen) {
+                let columns = self.parse_comma_separated(Parser::parse_identifier)?;
+                self.expect_token(&Token::RParen)?;
+                Some(columns)
+            } else {
+                Some(vec![self.parse_identifier()?])
+            }
+        } else {
+            None
+        };
+        let replace = if self.parse_keyword(Keyword::REPLACE) {
+            self.expect_token(&Token::LParen)?;
+            let elements = self.parse_comma_separated(|parser| {
+                let expr = parser.parse_expr()?;
+                match parser.parse_alias(Mandatory, &[])? {
+                    Some(column_name) => Ok(ReplaceSelectElement { expr, column_name }),
+                    None => parser.expected("AS", parser.peek_token()),
+                }
+            })?;
+            self.expect_token(&Token::RParen)?;
+            Some(elements)
+        } else {
+            None
+        };
+        Ok(WildcardAdditionalOptions { exclude, replace })
+    }
+
     /// Parse an expression, optionally followed by ASC or DESC (used in ORDER BY)
     pub fn parse_order_by_expr(&mut self) -> Result<OrderByExpr, ParserError> {
         let expr = self.parse_expr()?;
 
-        let asc = if self.parse_keyword(Keyword::ASC) {
-            Some(true)
-        } else if self.parse_keyword(Keyword::DESC) {
-            Some(false)
-        } else {
-            None
+        let mut using = None;
+        let asc = match self.parse_one_of_keywords(&[Keyword::ASC, Keyword::DESC]) {
+            Some(Keyword::ASC) => Some(true),
+            Some(Keyword::DESC) => Some(false),
+            _ => {
+                if dialect_of!(self is PostgreSqlDialect | GenericDialect)
+                    && self.parse_keyword(Keyword::USING)
+                {
+                    using = Some(self.parse_order_by_using_operator()?);
+                }
+                None
+            }
         };
 
         let nulls_first = if self.parse_keywords(&[Keyword::NULLS, Keyword::FIRST]) {
@@ -2995,9 +4652,21 @@ impl<'a> Parser<'a> {
             expr,
             asc,
             nulls_first,
+            using,
         })
     }
 
+    /// Parse the ordering operator after `ORDER BY <expr> USING`
+    fn parse_order_by_using_operator(&mut self) -> Result<BinaryOperator, ParserError> {
+        match self.next_token() {
+            Token::Lt => Ok(BinaryOperator::Lt),
+            Token::LtEq => Ok(BinaryOperator::LtEq),
+            Token::Gt => Ok(BinaryOperator::Gt),
+            Token::GtEq => Ok(BinaryOperator::GtEq),
+            unexpected => self.expected("an ordering operator after USING", unexpected),
+        }
+    }
+
     /// Parse a TOP clause, MSSQL equivalent of LIMIT,
     /// that follows after SELECT [DISTINCT].
     pub fn parse_top(&mut self) -> Result<Top, ParserError> {
@@ -3025,19 +4694,36 @@ impl<'a> Parser<'a> {
         if self.parse_keyword(Keyword::ALL) {
             Ok(None)
         } else {
-            Ok(Some(Expr::Value(self.parse_number_value()?)))
+            Ok(Some(self.parse_row_count("LIMIT")?))
+        }
+    }
+
+    /// Parse the number of rows given to LIMIT or OFFSET. Any expression is
+    /// accepted, but a literal number must not be negative.
+    fn parse_row_count(&mut self, clause: &str) -> Result<Expr, ParserError> {
+        let expr = self.parse_expr()?;
+        match &expr {
+            // a negated literal is rejected as such, so `-0` is too
+            Expr::UnaryOp {
+                op: UnaryOperator::Minus,
+                expr: operand,
+            } if matches!(**operand, Expr::Value(Value::Number(..))) => {
+                parser_err!(format!(
+                    "{} must not be negative, found: -{}",
+                    clause, operand
+                ))
+            }
+            _ => Ok(expr),
         }
     }
 
     /// Parse an OFFSET clause
     pub fn parse_offset(&mut self) -> Result<Offset, ParserError> {
-        let value = Expr::Value(self.parse_number_value()?);
-        let rows = if self.parse_keyword(Keyword::ROW) {
-            OffsetRows::Row
-        } else if self.parse_keyword(Keyword::ROWS) {
-            OffsetRows::Rows
-        } else {
-            OffsetRows::None
+        let value = self.parse_row_count("OFFSET")?;
+        let rows = match self.parse_one_of_keywords(&[Keyword::ROW, Keyword::ROWS]) {
+            Some(Keyword::ROW) => OffsetRows::Row,
+            Some(Keyword::ROWS) => OffsetRows::Rows,
+            _ => OffsetRows::None,
         };
         Ok(Offset { value, rows })
     }
@@ -3077,6 +4763,18 @@ impl<'a> Parser<'a> {
             parser.expect_token(&Token::RParen)?;
             Ok(exprs)
         })?;
+        if let Some(first) = values.first() {
+            for (i, row) in values.iter().enumerate().skip(1) {
+                if row.len() != first.len() {
+                    self.warnings.push(format!(
+                        "VALUES row {} has {} value(s), expected {}",
+                        i + 1,
+                        row.len(),
+                        first.len()
+                    ));
+                }
+            }
+        }
         Ok(Values(values))
     }
 
@@ -3153,6 +4851,132 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse a GRANT statement (`GRANT` has already been consumed).
+    pub fn parse_grant(&mut self) -> Result<Statement, ParserError> {
+        let (privileges, objects) = self.parse_grant_revoke_privileges_objects()?;
+
+        self.expect_keyword(Keyword::TO)?;
+        let grantees = self.parse_comma_separated(Parser::parse_identifier)?;
+
+        let with_grant_option =
+            self.parse_keywords(&[Keyword::WITH, Keyword::GRANT, Keyword::OPTION]);
+
+        Ok(Statement::Grant {
+            privileges,
+            objects,
+            grantees,
+            with_grant_option,
+        })
+    }
+
+    /// Parse a REVOKE statement (`REVOKE` has already been consumed).
+    pub fn parse_revoke(&mut self) -> Result<Statement, ParserError> {
+        let (privileges, objects) = self.parse_grant_revoke_privileges_objects()?;
+
+        self.expect_keyword(Keyword::FROM)?;
+        let grantees = self.parse_comma_separated(Parser::parse_identifier)?;
+
+        let behavior = self.parse_optional_drop_behavior("REVOKE")?;
+
+        Ok(Statement::Revoke {
+            privileges,
+            objects,
+            grantees,
+            behavior,
+        })
+    }
+
+    fn parse_grant_revoke_privileges_objects(
+        &mut self,
+    ) -> Result<(Privileges, GrantObjects), ParserError> {
+        let privileges = if self.parse_keyword(Keyword::ALL) {
+            Privileges::All {
+                with_privileges_keyword: self.parse_keyword(Keyword::PRIVILEGES),
+            }
+        } else {
+            Privileges::Actions(self.parse_comma_separated(Parser::parse_grant_permission)?)
+        };
+
+        self.expect_keyword(Keyword::ON)?;
+
+        let objects = if self.parse_keywords(&[
+            Keyword::ALL,
+            Keyword::TABLES,
+            Keyword::IN,
+            Keyword::SCHEMA,
+        ]) {
+            GrantObjects::AllTablesInSchema {
+                schemas: self.parse_comma_separated(Parser::parse_object_name)?,
+            }
+        } else if self.parse_keywords(&[
+            Keyword::ALL,
+            Keyword::SEQUENCES,
+            Keyword::IN,
+            Keyword::SCHEMA,
+        ]) {
+            GrantObjects::AllSequencesInSchema {
+                schemas: self.parse_comma_separated(Parser::parse_object_name)?,
+            }
+        } else {
+            let object_type =
+                self.parse_one_of_keywords(&[Keyword::SEQUENCE, Keyword::SCHEMA, Keyword::TABLE]);
+            let objects = self.parse_comma_separated(Parser::parse_object_name)?;
+            match object_type {
+                Some(Keyword::SCHEMA) => GrantObjects::Schemas(objects),
+                Some(Keyword::SEQUENCE) => GrantObjects::Sequences(objects),
+                Some(Keyword::TABLE) | None => GrantObjects::Tables(objects),
+                _ => unreachable!(),
+            }
+        };
+
+        Ok((privileges, objects))
+    }
+
+    fn parse_grant_permission(&mut self) -> Result<Action, ParserError> {
+        let keyword = self.parse_one_of_keywords(&[
+            Keyword::CONNECT,
+            Keyword::CREATE,
+            Keyword::DELETE,
+            Keyword::EXECUTE,
+            Keyword::INSERT,
+            Keyword::REFERENCES,
+            Keyword::SELECT,
+            Keyword::TEMPORARY,
+            Keyword::TEMP,
+            Keyword::TRIGGER,
+            Keyword::TRUNCATE,
+            Keyword::UPDATE,
+            Keyword::USAGE,
+        ]);
+        let action = match keyword {
+            Some(Keyword::CONNECT) => Action::Connect,
+            Some(Keyword::CREATE) => Action::Create,
+            Some(Keyword::DELETE) => Action::Delete,
+            Some(Keyword::EXECUTE) => Action::Execute,
+            Some(Keyword::TEMPORARY) | Some(Keyword::TEMP) => Action::Temporary,
+            Some(Keyword::TRIGGER) => Action::Trigger,
+            Some(Keyword::TRUNCATE) => Action::Truncate,
+            Some(Keyword::USAGE) => Action::Usage,
+            Some(kw) => {
+                let columns = self.parse_parenthesized_column_list(Optional)?;
+                let columns = if columns.is_empty() {
+                    None
+                } else {
+                    Some(columns)
+                };
+                match kw {
+                    Keyword::INSERT => Action::Insert { columns },
+                    Keyword::REFERENCES => Action::References { columns },
+                    Keyword::SELECT => Action::Select { columns },
+                    Keyword::UPDATE => Action::Update { columns },
+                    _ => unreachable!(),
+                }
+            }
+            None => return self.expected("a privilege keyword", self.peek_token()),
+        };
+        Ok(action)
+    }
+
     fn parse_deallocate(&mut self) -> Result<Statement, ParserError> {
         let prepare = self.parse_keyword(Keyword::PREPARE);
         let name = self.parse_identifier()?;
@@ -3190,6 +5014,73 @@ impl<'a> Parser<'a> {
     }
 }
 
+/// An iterator over the statements of an input, see [Parser::parse_sql_lazily].
+/// It stops after the first error.
+pub struct StatementStream<'a> {
+    dialect: &'a dyn Dialect,
+    cursor: Cursor<'a>,
+    /// The warnings recorded for the statements parsed so far
+    warnings: Vec<String>,
+    max_tokens: Option<usize>,
+    done: bool,
+}
+
+impl<'a> StatementStream<'a> {
+    /// Fail with [ParserError::TooManyTokens] once a single statement has
+    /// more than `max_tokens` tokens, see [Tokenizer::with_max_tokens]
+    pub fn with_max_tokens(mut self, max_tokens: usize) -> Self {
+        self.max_tokens = Some(max_tokens);
+        self
+    }
+
+    /// The warnings recorded for the statements parsed so far
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    fn parse_next(&mut self) -> Option<Result<Statement, ParserError>> {
+        loop {
+            let tokens = match self.cursor.tokenize(self.max_tokens, true) {
+                Ok(tokens) if tokens.is_empty() => return None,
+                Ok(tokens) => tokens,
+                Err(_) if self.cursor.max_tokens_exceeded() => {
+                    return Some(Err(ParserError::TooManyTokens(
+                        self.max_tokens.unwrap_or_default(),
+                    )))
+                }
+                Err(e) => return Some(Err(e.into())),
+            };
+            let mut parser = Parser::new(tokens, self.dialect);
+            // skip empty statements, including whitespace at the end of input
+            if parser.consume_statement_terminator() || parser.peek_token() == Token::EOF {
+                continue;
+            }
+            let result = parser.parse_statement().and_then(|statement| {
+                if parser.consume_statement_terminator() || parser.peek_token() == Token::EOF {
+                    Ok(statement)
+                } else {
+                    parser.expected("end of statement", parser.peek_token())
+                }
+            });
+            self.warnings.append(&mut parser.warnings);
+            return Some(result);
+        }
+    }
+}
+
+impl<'a> Iterator for StatementStream<'a> {
+    type Item = Result<Statement, ParserError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let next = self.parse_next();
+        self.done = !matches!(next, Some(Ok(_)));
+        next
+    }
+}
+
 impl Word {
     pub fn to_ident(&self) -> Ident {
         Ident {
@@ -3202,7 +5093,74 @@ impl Word {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::all_dialects;
+    use crate::test_utils::{all_dialects, TestDialect, TestedDialects};
+
+    #[test]
+    fn test_next_precedence() {
+        let precedence = |dialects: TestedDialects, op: &str| {
+            dialects.run_parser_method(&format!("a {} b", op), |parser| {
+                parser.next_token();
+                parser.get_next_precedence().unwrap()
+            })
+        };
+        let cases = [
+            ("OR", 5),
+            ("AND", 10),
+            ("=", 20),
+            ("<>", 20),
+            ("<=", 20),
+            ("~", 20),
+            ("!~*", 20),
+            ("|", 21),
+            ("^", 22),
+            ("&", 23),
+            ("+", 30),
+            ("-", 30),
+            ("*", 40),
+            ("/", 40),
+            ("||", 40),
+            ("::", 50),
+        ];
+        for (op, expected) in cases.iter() {
+            assert_eq!(*expected, precedence(all_dialects(), op), "{}", op);
+        }
+
+        let pg_and_generic = || TestedDialects {
+            dialects: vec![Box::new(PostgreSqlDialect {}), Box::new(GenericDialect {})],
+        };
+        let pg_cases = [
+            ("->", 21),
+            ("->>", 21),
+            ("#>", 21),
+            ("@>", 21),
+            ("<@", 21),
+            ("&&", 21),
+            ("?", 21),
+            ("?|", 21),
+            ("?&", 21),
+            ("<<", 22),
+            (">>", 22),
+        ];
+        for (op, expected) in pg_cases.iter() {
+            assert_eq!(*expected, precedence(pg_and_generic(), op), "{}", op);
+        }
+
+        // `#` starts an identifier in the generic dialect
+        let pg = TestedDialects {
+            dialects: vec![Box::new(PostgreSqlDialect {})],
+        };
+        assert_eq!(22, precedence(pg, "#"));
+
+        // `?` is a placeholder, not an operator, in the other dialects
+        let others = TestedDialects {
+            dialects: vec![
+                Box::new(MySqlDialect {}),
+                Box::new(MsSqlDialect {}),
+                Box::new(SQLiteDialect {}),
+            ],
+        };
+        assert_eq!(0, precedence(others, "?"));
+    }
 
     #[test]
     fn test_prev_index() {
@@ -3224,4 +5182,587 @@ mod tests {
             parser.prev_token();
         });
     }
+
+    #[test]
+    fn test_parse_sql_with_context() {
+        let dialect = GenericDialect {};
+        let err =
+            Parser::parse_sql_with_context(&dialect, "SELECT a FROM t WHERE b = = 1 ORDER BY a")
+                .unwrap_err();
+        assert_eq!(
+            ParserError::ParserError("Expected an expression:, found: =".to_string()),
+            err.error
+        );
+        assert_eq!(
+            vec![
+                Token::make_word("b", None),
+                Token::Eq,
+                Token::Eq,
+                Token::Number("1".to_string(), false),
+                Token::make_keyword("ORDER"),
+                Token::make_keyword("BY"),
+            ],
+            err.context
+        );
+        assert_eq!(
+            "sql parser error: Expected an expression:, found: = near `b = = 1 ORDER BY`",
+            err.to_string()
+        );
+
+        // tokenizer errors have no token context
+        let err = Parser::parse_sql_with_context(&dialect, "SELECT 'foo").unwrap_err();
+        assert!(err.context.is_empty());
+    }
+
+    #[test]
+    fn test_parse_keywords_is_atomic() {
+        all_dialects().run_parser_method("GROUP id", |parser| {
+            // a partial match must not consume anything
+            assert!(!parser.parse_keywords(&[Keyword::GROUP, Keyword::BY]));
+            assert_eq!(parser.peek_token(), Token::make_keyword("GROUP"));
+            assert!(parser.parse_keyword(Keyword::GROUP));
+            assert_eq!(parser.next_token(), Token::make_word("id", None));
+        });
+
+        all_dialects().run_parser_method("IS NOT NULL", |parser| {
+            assert!(!parser.parse_keywords(&[Keyword::IS, Keyword::NOT, Keyword::TRUE]));
+            assert_eq!(parser.peek_token(), Token::make_keyword("IS"));
+            assert!(parser.parse_keywords(&[Keyword::IS, Keyword::NOT, Keyword::NULL]));
+            assert_eq!(parser.peek_token(), Token::EOF);
+        });
+
+        // a sequence running past the end of input doesn't match either
+        all_dialects().run_parser_method("ORDER", |parser| {
+            assert!(!parser.parse_keywords(&[Keyword::ORDER, Keyword::BY]));
+            assert_eq!(parser.next_token(), Token::make_keyword("ORDER"));
+        });
+    }
+
+    #[test]
+    fn test_parse_parenthesized_column_list() {
+        all_dialects().run_parser_method("(a, b, c) d", |parser| {
+            assert_eq!(
+                vec![Ident::new("a"), Ident::new("b"), Ident::new("c")],
+                parser.parse_parenthesized_column_list(Mandatory).unwrap()
+            );
+            assert_eq!(parser.next_token(), Token::make_word("d", None));
+        });
+
+        all_dialects().run_parser_method("d", |parser| {
+            assert_eq!(
+                Vec::<Ident>::new(),
+                parser.parse_parenthesized_column_list(Optional).unwrap()
+            );
+            assert_eq!(
+                ParserError::ParserError(
+                    "Expected a list of columns in parentheses, found: d".to_string()
+                ),
+                parser
+                    .parse_parenthesized_column_list(Mandatory)
+                    .unwrap_err()
+            );
+        });
+
+        all_dialects().run_parser_method("(a, b", |parser| {
+            assert_eq!(
+                ParserError::ParserError("Expected ), found: EOF".to_string()),
+                parser
+                    .parse_parenthesized_column_list(Optional)
+                    .unwrap_err()
+            );
+        });
+    }
+
+    #[test]
+    fn test_parse_infix_builtin_operators() {
+        // each input is a prefix expression followed by a single infix operation,
+        // paired with its canonical form
+        let cases = [
+            ("a + 1", "a + 1"),
+            ("a || 'x'", "a || 'x'"),
+            ("a = b", "a = b"),
+            ("a <> b", "a <> b"),
+            ("a IS NULL", "a IS NULL"),
+            ("a IS NOT NULL", "a IS NOT NULL"),
+            ("a NOT BETWEEN 1 AND 2", "a NOT BETWEEN 1 AND 2"),
+            ("a IN (1, 2)", "a IN (1, 2)"),
+            ("a NOT IN (SELECT b FROM t)", "a NOT IN (SELECT b FROM t)"),
+            ("a LIKE 'x%'", "a LIKE 'x%'"),
+            ("a NOT ILIKE 'x%'", "a NOT ILIKE 'x%'"),
+            ("a::INT", "CAST(a AS INT)"),
+        ];
+        for (sql, canonical) in cases.iter() {
+            all_dialects().run_parser_method(sql, |parser| {
+                let prefix = parser.parse_prefix().unwrap();
+                let precedence = parser.get_next_precedence().unwrap();
+                assert!(precedence > 0, "no infix operator found in {}", sql);
+                let expr = parser.parse_infix(prefix, precedence).unwrap();
+                assert_eq!(*canonical, expr.to_string());
+                assert_eq!(parser.peek_token(), Token::EOF);
+            });
+        }
+    }
+
+    #[test]
+    fn test_parse_one_of_keywords() {
+        all_dialects().run_parser_method("DESC NULLS", |parser| {
+            assert_eq!(
+                Some(Keyword::DESC),
+                parser.parse_one_of_keywords(&[Keyword::ASC, Keyword::DESC])
+            );
+            // no match leaves the stream untouched
+            assert_eq!(
+                None,
+                parser.parse_one_of_keywords(&[Keyword::ASC, Keyword::DESC])
+            );
+            assert_eq!(parser.peek_token(), Token::make_keyword("NULLS"));
+        });
+
+        // a quoted identifier is not a keyword
+        all_dialects().run_parser_method("\"ASC\"", |parser| {
+            assert_eq!(None, parser.parse_one_of_keywords(&[Keyword::ASC]));
+        });
+    }
+
+    #[test]
+    fn test_numeric_dot_shorthand() {
+        for sql in &[".5", "5."] {
+            all_dialects().run_parser_method(sql, |parser| {
+                assert!(parser.parse_expr().is_ok());
+                assert_eq!(
+                    vec![format!("Nonstandard numeric literal '{}'", sql)],
+                    parser.warnings()
+                );
+            });
+
+            let strict = TestedDialects {
+                dialects: vec![Box::new(TestDialect {
+                    supports_numeric_dot_shorthand: false,
+                    ..Default::default()
+                })],
+            };
+            strict.run_parser_method(sql, |parser| {
+                assert_eq!(
+                    ParserError::ParserError(format!(
+                        "Numeric literal '{}' needs digits on both sides of the decimal point",
+                        sql
+                    )),
+                    parser.parse_expr().unwrap_err()
+                );
+            });
+        }
+
+        all_dialects().run_parser_method("0.5", |parser| {
+            assert!(parser.parse_expr().is_ok());
+            assert!(parser.warnings().is_empty());
+        });
+    }
+
+    #[test]
+    fn test_identifier_longer_than_max_length() {
+        let name = "a".repeat(100);
+        let sql = format!("SELECT {} FROM t", name);
+        let warning = format!("Identifier '{}' is longer than 63 bytes", name);
+
+        let pg = TestedDialects {
+            dialects: vec![Box::new(PostgreSqlDialect {})],
+        };
+        pg.run_parser_method(&sql, |parser| {
+            assert!(parser.parse_statement().is_ok());
+            assert_eq!(vec![warning.clone()], parser.warnings());
+        });
+        pg.run_parser_method(&format!("SELECT \"{}\"", name), |parser| {
+            assert!(parser.parse_statement().is_ok());
+            assert_eq!(vec![warning.clone()], parser.warnings());
+        });
+
+        let generic = TestedDialects {
+            dialects: vec![Box::new(GenericDialect {})],
+        };
+        generic.run_parser_method(&sql, |parser| {
+            assert!(parser.parse_statement().is_ok());
+            assert!(parser.warnings().is_empty());
+        });
+    }
+
+    #[test]
+    fn test_values_row_arity_mismatch() {
+        let sql = "INSERT INTO t VALUES (1, 2), (3), (4, 5), (6, 7, 8)";
+        all_dialects().run_parser_method(sql, |parser| {
+            assert!(parser.parse_statement().is_ok());
+            assert_eq!(
+                vec![
+                    "VALUES row 2 has 1 value(s), expected 2".to_string(),
+                    "VALUES row 4 has 3 value(s), expected 2".to_string(),
+                ],
+                parser.warnings()
+            );
+        });
+
+        all_dialects().run_parser_method("INSERT INTO t VALUES (1, 2), (3, 4)", |parser| {
+            assert!(parser.parse_statement().is_ok());
+            assert!(parser.warnings().is_empty());
+        });
+    }
+
+    #[test]
+    fn test_order_by_position_out_of_range() {
+        all_dialects().run_parser_method("SELECT a, b FROM t ORDER BY 3", |parser| {
+            assert!(parser.parse_query().is_ok());
+            assert_eq!(
+                vec!["ORDER BY position 3 is not in select list of 2 column(s)".to_string()],
+                parser.warnings()
+            );
+        });
+
+        for sql in &[
+            "SELECT a, b FROM t ORDER BY 2, 1",
+            "SELECT * FROM t ORDER BY 3",
+            "SELECT a FROM t ORDER BY a",
+        ] {
+            all_dialects().run_parser_method(sql, |parser| {
+                assert!(parser.parse_query().is_ok());
+                assert!(parser.warnings().is_empty());
+            });
+        }
+    }
+
+    #[test]
+    fn test_dialect_is_fixed_per_parser() {
+        use crate::dialect::{MySqlDialect, PostgreSqlDialect};
+
+        // every statement is parsed with the dialect the parser was built with
+        let sql = "SHOW TABLES; SELECT 1; SHOW TABLES";
+        let statements = Parser::parse_sql(&MySqlDialect {}, sql).unwrap();
+        assert_eq!(3, statements.len());
+        assert!(matches!(statements[0], Statement::ShowTables { .. }));
+        assert!(matches!(statements[2], Statement::ShowTables { .. }));
+
+        let sql = "SELECT 1; SHOW TABLES";
+        let statements = Parser::parse_sql(&PostgreSqlDialect {}, sql).unwrap();
+        assert_eq!(2, statements.len());
+        assert!(matches!(statements[1], Statement::ShowVariable { .. }));
+    }
+
+    #[test]
+    fn test_parse_identifier_strict() {
+        all_dialects().run_parser_method("WHERE", |parser| {
+            assert_eq!(
+                ParserError::ParserError("Expected identifier, found: WHERE".to_string()),
+                parser.parse_identifier_strict().unwrap_err()
+            );
+            // nothing was consumed, and the lenient variant accepts the keyword
+            assert_eq!(Ident::new("WHERE"), parser.parse_identifier().unwrap());
+        });
+
+        // non-reserved keywords and quoted identifiers are fine
+        all_dialects().run_parser_method("key", |parser| {
+            assert_eq!(Ident::new("key"), parser.parse_identifier_strict().unwrap());
+        });
+        TestedDialects {
+            dialects: vec![Box::new(GenericDialect {})],
+        }
+        .run_parser_method("\"where\"", |parser| {
+            assert_eq!(
+                Ident::with_quote('"', "where"),
+                parser.parse_identifier_strict().unwrap()
+            );
+        });
+    }
+
+    #[test]
+    fn test_custom_statement_terminator() {
+        let sql = "SELECT a / b FROM t // SELECT 2, c //\nSELECT 3 ; 4";
+        let dialect = GenericDialect {};
+        let tokens = Tokenizer::new(&dialect, sql).tokenize().unwrap();
+        let mut parser =
+            Parser::new(tokens, &dialect).with_statement_terminator(vec![Token::Div, Token::Div]);
+        // `;` is no longer a separator
+        assert_eq!(
+            ParserError::ParserError("Expected end of statement, found: ;".to_string()),
+            parser.parse_statements().unwrap_err()
+        );
+
+        let sql = "SELECT a / b FROM t // SELECT 2, c //\n// SELECT 3";
+        let tokens = Tokenizer::new(&dialect, sql).tokenize().unwrap();
+        let mut parser =
+            Parser::new(tokens, &dialect).with_statement_terminator(vec![Token::Div, Token::Div]);
+        let stmts: Vec<String> = parser
+            .parse_statements()
+            .unwrap()
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(
+            vec!["SELECT a / b FROM t", "SELECT 2, c", "SELECT 3"],
+            stmts
+        );
+
+        // The terminator's tokens must be adjacent
+        let sql = "SELECT a FROM t / / SELECT 2";
+        let tokens = Tokenizer::new(&dialect, sql).tokenize().unwrap();
+        let mut parser =
+            Parser::new(tokens, &dialect).with_statement_terminator(vec![Token::Div, Token::Div]);
+        assert_eq!(
+            ParserError::ParserError("Expected end of statement, found: /".to_string()),
+            parser.parse_statements().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_hand_built_tokens() {
+        let tokens = vec![
+            Token::make_word("a", None),
+            Token::Whitespace(Whitespace::Space),
+            Token::Plus,
+            Token::Number("1".to_string(), false),
+            Token::Mult,
+            Token::make_word("b", Some('"')),
+        ];
+        let dialect = GenericDialect {};
+        let mut parser = Parser::from_tokens(tokens, &dialect);
+        assert_eq!(
+            Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                op: BinaryOperator::Plus,
+                right: Box::new(Expr::BinaryOp {
+                    left: Box::new(Expr::Value(Value::Number("1".parse().unwrap(), false))),
+                    op: BinaryOperator::Multiply,
+                    right: Box::new(Expr::Identifier(Ident::with_quote('"', "b"))),
+                }),
+            },
+            parser.parse_expr().unwrap()
+        );
+        assert_eq!(Token::EOF, parser.next_token());
+    }
+
+    #[test]
+    fn test_peek_token_ref() {
+        let sql = "SELECT  a";
+        let dialect = GenericDialect {};
+        let tokens = Tokenizer::new(&dialect, sql).tokenize().unwrap();
+        let mut parser = Parser::new(tokens, &dialect);
+        for _ in 0..2 {
+            let borrowed = parser.peek_token_ref().cloned();
+            assert_eq!(Some(parser.peek_token()), borrowed);
+            // the reference points into the buffered token stream
+            assert!(parser
+                .tokens
+                .iter()
+                .any(|t| core::ptr::eq(t, parser.peek_token_ref().unwrap())));
+            parser.next_token();
+        }
+        assert_eq!(None, parser.peek_token_ref());
+        assert_eq!(Token::EOF, parser.peek_token());
+        parser.next_token();
+        assert_eq!(None, parser.peek_token_ref());
+    }
+
+    #[test]
+    fn test_tokenizer_error_position() {
+        let sql = "SELECT a,\n  'foo FROM t";
+        let dialect = GenericDialect {};
+        let err = Tokenizer::new(&dialect, sql).tokenize().unwrap_err();
+        assert_eq!((2, 3), (err.line, err.col));
+        assert_eq!(
+            "Unterminated string literal at Line: 2, Column 3",
+            err.to_string()
+        );
+
+        let err = Parser::parse_sql(&dialect, sql).unwrap_err();
+        assert_eq!(
+            ParserError::TokenizerError(
+                "Unterminated string literal at Line: 2, Column 3".to_string()
+            ),
+            err
+        );
+        assert_eq!(
+            "sql parser error: Unterminated string literal at Line: 2, Column 3",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_sql_with_warnings() {
+        let dialect = GenericDialect {};
+        let (statements, warnings) = Parser::parse_sql_with_warnings(
+            &dialect,
+            "SELECT .5 FROM t ORDER BY 2; INSERT INTO t VALUES (1), (1, 2)",
+        )
+        .unwrap();
+        assert_eq!(2, statements.len());
+        assert_eq!(
+            vec![
+                "Nonstandard numeric literal '.5'".to_string(),
+                "ORDER BY position 2 is not in select list of 1 column(s)".to_string(),
+                "VALUES row 2 has 2 value(s), expected 1".to_string(),
+            ],
+            warnings
+        );
+
+        let dialect = PostgreSqlDialect {};
+        let name = "a".repeat(64);
+        let (_, warnings) =
+            Parser::parse_sql_with_warnings(&dialect, &format!("SELECT {}", name)).unwrap();
+        assert_eq!(
+            vec![format!("Identifier '{}' is longer than 63 bytes", name)],
+            warnings
+        );
+
+        // The derived table is parsed twice: first as the start of a query,
+        // and again as part of the nested join, but warned about once
+        let (_, warnings) = Parser::parse_sql_with_warnings(
+            &dialect,
+            "SELECT * FROM ((SELECT .5) AS x JOIN y ON true)",
+        )
+        .unwrap();
+        assert_eq!(vec!["Nonstandard numeric literal '.5'"], warnings);
+    }
+
+    #[test]
+    fn test_parse_sql_with_max_tokens() {
+        let dialect = GenericDialect {};
+        let sql = "SELECT 1, 2";
+        // exactly at the limit is fine
+        assert_eq!(
+            Parser::parse_sql(&dialect, sql).unwrap(),
+            Parser::parse_sql_with_max_tokens(&dialect, sql, 6).unwrap()
+        );
+        let err = Parser::parse_sql_with_max_tokens(&dialect, sql, 5).unwrap_err();
+        assert_eq!(ParserError::TooManyTokens(5), err);
+        assert_eq!(
+            "sql parser error: Input too large: more than 5 tokens",
+            err.to_string()
+        );
+
+        // Other errors are reported as usual
+        assert_eq!(
+            Parser::parse_sql(&dialect, "SELECT 'a").unwrap_err(),
+            Parser::parse_sql_with_max_tokens(&dialect, "SELECT 'a", 100).unwrap_err()
+        );
+
+        let mut stream =
+            Parser::parse_sql_lazily(&dialect, "SELECT 1; SELECT 1, 2").with_max_tokens(4);
+        assert!(stream.next().unwrap().is_ok());
+        assert_eq!(
+            ParserError::TooManyTokens(4),
+            stream.next().unwrap().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_sql_lazily() {
+        let dialect = GenericDialect {};
+        let sql: String = (0..10_000)
+            .map(|i| format!("SELECT a, b FROM t WHERE c = {};\n", i))
+            .collect();
+
+        // Only one statement's tokens are buffered at a time
+        let max_tokens = 32;
+        assert!(Tokenizer::new(&dialect, &sql)
+            .with_max_tokens(max_tokens)
+            .tokenize()
+            .is_err());
+        let statements = Parser::parse_sql_lazily(&dialect, &sql)
+            .with_max_tokens(max_tokens)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(10_000, statements.len());
+        assert_eq!(Parser::parse_sql(&dialect, &sql).unwrap(), statements);
+
+        // Empty statements are skipped, and the stream ends after an error
+        let mut stream = Parser::parse_sql_lazily(&dialect, ";SELECT 1;; SELEC 2; SELECT 3");
+        assert_eq!("SELECT 1", stream.next().unwrap().unwrap().to_string());
+        assert_eq!(
+            ParserError::ParserError("Expected an SQL statement, found: SELEC".to_string()),
+            stream.next().unwrap().unwrap_err()
+        );
+        assert!(stream.next().is_none());
+        assert_eq!(
+            ParserError::ParserError("Expected end of statement, found: 2".to_string()),
+            Parser::parse_sql_lazily(&dialect, "SELECT 1 2")
+                .next()
+                .unwrap()
+                .unwrap_err()
+        );
+
+        // Positions are reported relative to the whole input
+        let mut stream = Parser::parse_sql_lazily(&dialect, "SELECT 1;\nSELECT 'foo");
+        assert!(stream.next().unwrap().is_ok());
+        assert_eq!(
+            ParserError::TokenizerError(
+                "Unterminated string literal at Line: 2, Column 8".to_string()
+            ),
+            stream.next().unwrap().unwrap_err()
+        );
+
+        let dialect = PostgreSqlDialect {};
+        let long = "a".repeat(64);
+        let sql = format!("SELECT 1; SELECT {}", long);
+        let mut stream = Parser::parse_sql_lazily(&dialect, &sql);
+        assert_eq!(2, stream.by_ref().count());
+        assert_eq!(
+            vec![format!("Identifier '{}' is longer than 63 bytes", long)],
+            stream.warnings()
+        );
+    }
+
+    #[test]
+    fn test_parse_comment() {
+        let sql = "-- first\n/* second */ SELECT 1; SELECT 2";
+        let dialect = GenericDialect {};
+        let tokens = Tokenizer::new(&dialect, sql).tokenize().unwrap();
+        let mut parser = Parser::new(tokens, &dialect);
+        assert_eq!(vec!["first", "second"], parser.parse_comment());
+        assert_eq!("SELECT 1", parser.parse_statement().unwrap().to_string());
+        assert!(parser.consume_token(&Token::SemiColon));
+        assert!(parser.parse_comment().is_empty());
+        assert_eq!("SELECT 2", parser.parse_statement().unwrap().to_string());
+    }
+
+    #[test]
+    fn test_parse_grouped_expr() {
+        let parse = |sql: &str| {
+            all_dialects().run_parser_method(sql, |parser| {
+                parser.expect_token(&Token::LParen).unwrap();
+                parser.parse_grouped_expr().unwrap()
+            })
+        };
+        assert!(matches!(parse("(a + 1)"), Expr::Nested(_)));
+        assert!(matches!(parse("((a))"), Expr::Nested(_)));
+        match parse("(a, b + 1, (c))") {
+            Expr::Tuple(exprs) => assert_eq!(3, exprs.len()),
+            e => panic!("expected a row, got {:?}", e),
+        }
+        for sql in &[
+            "(SELECT 1)",
+            "(WITH t AS (SELECT 1) SELECT * FROM t)",
+            "(VALUES (1), (2))",
+        ] {
+            let expr = parse(sql);
+            assert!(matches!(expr, Expr::Subquery(_)), "{}", sql);
+            assert_eq!(*sql, expr.to_string());
+        }
+    }
+
+    #[test]
+    fn test_parse_alias() {
+        let reserved = keywords::RESERVED_FOR_COLUMN_ALIAS;
+        let alias = |sql: &str, as_required: bool| {
+            all_dialects().run_parser_method(sql, |parser| {
+                let as_keyword = if as_required { Mandatory } else { Optional };
+                let alias = parser.parse_alias(as_keyword, reserved).unwrap();
+                (alias.map(|a| a.value), parser.peek_token().to_string())
+            })
+        };
+        let some = |s: &str| Some(s.to_string());
+
+        assert_eq!((some("foo"), "EOF".to_string()), alias("AS foo", false));
+        assert_eq!((some("foo"), "EOF".to_string()), alias("foo", false));
+        assert_eq!((None, "FROM".to_string()), alias("FROM", false));
+        assert_eq!((some("from"), "EOF".to_string()), alias("AS from", false));
+
+        assert_eq!((some("foo"), "EOF".to_string()), alias("AS foo", true));
+        assert_eq!((None, "foo".to_string()), alias("foo", true));
+    }
 }
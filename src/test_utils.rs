@@ -29,7 +29,7 @@ use core::fmt::Debug;
 use crate::ast::*;
 use crate::dialect::*;
 use crate::parser::{Parser, ParserError};
-use crate::tokenizer::Tokenizer;
+use crate::tokenizer::{Token, Tokenizer};
 
 /// Tests use the methods on this struct to invoke the parser on one or
 /// multiple dialects.
@@ -145,6 +145,55 @@ pub fn all_dialects() -> TestedDialects {
     }
 }
 
+/// A dialect with plain ASCII identifiers whose `Dialect` hooks are set by
+/// the test using it, so a test of a single hook doesn't have to define and
+/// implement its own dialect. `TestDialect::default()` behaves like the
+/// trait defaults.
+#[derive(Debug)]
+pub struct TestDialect {
+    pub custom_operators: &'static [&'static str],
+    pub custom_operator_precedence: fn(&str) -> u8,
+    pub precedence_of: fn(&Token) -> Option<u8>,
+    pub supports_numeric_dot_shorthand: bool,
+}
+
+impl Default for TestDialect {
+    fn default() -> Self {
+        TestDialect {
+            custom_operators: &[],
+            custom_operator_precedence: |_| 20,
+            precedence_of: |_| None,
+            supports_numeric_dot_shorthand: true,
+        }
+    }
+}
+
+impl Dialect for TestDialect {
+    fn is_identifier_start(&self, ch: char) -> bool {
+        ch.is_ascii_alphabetic()
+    }
+
+    fn is_identifier_part(&self, ch: char) -> bool {
+        ch.is_ascii_alphanumeric()
+    }
+
+    fn custom_operators(&self) -> &[&str] {
+        self.custom_operators
+    }
+
+    fn custom_operator_precedence(&self, op: &str) -> u8 {
+        (self.custom_operator_precedence)(op)
+    }
+
+    fn precedence_of(&self, token: &Token) -> Option<u8> {
+        (self.precedence_of)(token)
+    }
+
+    fn supports_numeric_dot_shorthand(&self) -> bool {
+        self.supports_numeric_dot_shorthand
+    }
+}
+
 pub fn only<T>(v: impl IntoIterator<Item = T>) -> T {
     let mut iter = v.into_iter();
     if let (Some(item), None) = (iter.next(), iter.next()) {
@@ -154,6 +203,15 @@ pub fn only<T>(v: impl IntoIterator<Item = T>) -> T {
     }
 }
 
+/// Ensures that `stmt` is an `ALTER TABLE` with a single operation, and
+/// returns the table name along with that operation
+pub fn alter_table_op_with_name(stmt: Statement) -> (ObjectName, AlterTableOperation) {
+    match stmt {
+        Statement::AlterTable { name, operations } => (name, only(operations)),
+        _ => panic!("Expected ALTER TABLE statement"),
+    }
+}
+
 pub fn expr_from_projection(item: &SelectItem) -> &Expr {
     match item {
         SelectItem::UnnamedExpr(expr) => expr,
@@ -161,6 +219,11 @@ pub fn expr_from_projection(item: &SelectItem) -> &Expr {
     }
 }
 
+/// A boxed unquoted identifier, as used for the operands of `Expr`s
+pub fn ident(name: impl Into<String>) -> Box<Expr> {
+    Box::new(Expr::Identifier(Ident::new(name)))
+}
+
 pub fn number(n: &'static str) -> Value {
     Value::Number(n.parse().unwrap(), false)
 }
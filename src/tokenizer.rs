@@ -31,9 +31,10 @@ use core::str::Chars;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::ast::{escape_quoted_string, DollarQuotedString};
 use crate::dialect::keywords::{Keyword, ALL_KEYWORDS, ALL_KEYWORDS_INDEX};
 use crate::dialect::Dialect;
-use crate::dialect::SnowflakeDialect;
+use crate::dialect::{GenericDialect, PostgreSqlDialect, SnowflakeDialect};
 
 /// SQL Token enumeration
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -54,6 +55,10 @@ pub enum Token {
     NationalStringLiteral(String),
     /// Hexadecimal string literal: i.e.: X'deadbeef'
     HexStringLiteral(String),
+    /// Dollar-quoted string literal (PostgreSQL): i.e: $$string$$ or $tag$string$tag$
+    DollarQuotedString(DollarQuotedString),
+    /// A placeholder for a parameter of a prepared statement, i.e. `$1` or `?1`
+    Placeholder(String),
     /// Comma
     Comma,
     /// Whitespace (space, tab, etc)
@@ -140,6 +145,28 @@ pub enum Token {
     PGSquareRoot,
     /// `||/` , a cube root math operator in PostgreSQL
     PGCubeRoot,
+    /// `@>`, a containment operator in PostgreSQL
+    AtArrow,
+    /// `<@`, a "contained by" operator in PostgreSQL
+    ArrowAt,
+    /// `&&`, an overlap operator in PostgreSQL
+    Overlap,
+    /// `?`, a jsonb key existence operator in PostgreSQL
+    Question,
+    /// `?|`, a jsonb "any key exists" operator in PostgreSQL
+    QuestionPipe,
+    /// `?&`, a jsonb "all keys exist" operator in PostgreSQL
+    QuestionAnd,
+    /// `->`, a json field access operator in PostgreSQL
+    Arrow,
+    /// `->>`, a json field access operator returning text in PostgreSQL
+    LongArrow,
+    /// `#>`, a json path access operator in PostgreSQL
+    HashArrow,
+    /// `#>>`, a json path access operator returning text in PostgreSQL
+    HashLongArrow,
+    /// An operator declared by `Dialect::custom_operators`
+    CustomOperator(String),
 }
 
 impl fmt::Display for Token {
@@ -153,6 +180,8 @@ impl fmt::Display for Token {
             Token::BackQuotedString(ref s) => write!(f, "`{}`", s),
             Token::NationalStringLiteral(ref s) => write!(f, "N'{}'", s),
             Token::HexStringLiteral(ref s) => write!(f, "X'{}'", s),
+            Token::DollarQuotedString(ref s) => write!(f, "{}", s),
+            Token::Placeholder(ref s) => write!(f, "{}", s),
             Token::Comma => f.write_str(","),
             Token::Whitespace(ws) => write!(f, "{}", ws),
             Token::DoubleEq => f.write_str("=="),
@@ -196,6 +225,17 @@ impl fmt::Display for Token {
             Token::ShiftRight => f.write_str(">>"),
             Token::PGSquareRoot => f.write_str("|/"),
             Token::PGCubeRoot => f.write_str("||/"),
+            Token::AtArrow => f.write_str("@>"),
+            Token::ArrowAt => f.write_str("<@"),
+            Token::Overlap => f.write_str("&&"),
+            Token::Question => f.write_str("?"),
+            Token::QuestionPipe => f.write_str("?|"),
+            Token::QuestionAnd => f.write_str("?&"),
+            Token::Arrow => f.write_str("->"),
+            Token::LongArrow => f.write_str("->>"),
+            Token::HashArrow => f.write_str("#>"),
+            Token::HashLongArrow => f.write_str("#>>"),
+            Token::CustomOperator(ref op) => f.write_str(op),
         }
     }
 }
@@ -240,7 +280,8 @@ impl fmt::Display for Word {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.quote_style {
             Some(s) if s == '"' || s == '[' || s == '`' => {
-                write!(f, "{}{}{}", s, self.value, Word::matching_end_quote(s))
+                let end = Word::matching_end_quote(s);
+                write!(f, "{}{}{}", s, escape_quoted_string(&self.value, end), end)
             }
             None => f.write_str(&self.value),
             _ => panic!("Unexpected quote_style!"),
@@ -289,12 +330,27 @@ pub struct TokenizerError {
     pub col: u64,
 }
 
+impl fmt::Display for TokenizerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} at Line: {}, Column {}",
+            self.message, self.line, self.col
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TokenizerError {}
+
 /// SQL Tokenizer
 pub struct Tokenizer<'a> {
     dialect: &'a dyn Dialect,
     pub query: String,
     pub line: u64,
     pub col: u64,
+    max_tokens: Option<usize>,
+    max_tokens_exceeded: bool,
 }
 
 impl<'a> Tokenizer<'a> {
@@ -305,62 +361,178 @@ impl<'a> Tokenizer<'a> {
             query: query.to_string(),
             line: 1,
             col: 1,
+            max_tokens: None,
+            max_tokens_exceeded: false,
         }
     }
 
+    /// Fail tokenization once the input produces more than `max_tokens`
+    /// tokens (whitespace and comments included), to bound the work done
+    /// on untrusted input. There is no limit by default.
+    pub fn with_max_tokens(mut self, max_tokens: usize) -> Self {
+        self.max_tokens = Some(max_tokens);
+        self
+    }
+
+    /// Whether tokenization failed because of the `max_tokens` limit, rather
+    /// than because of invalid input
+    pub fn max_tokens_exceeded(&self) -> bool {
+        self.max_tokens_exceeded
+    }
+
     /// Tokenize the statement and produce a vector of tokens
     pub fn tokenize(&mut self) -> Result<Vec<Token>, TokenizerError> {
-        let mut peekable = self.query.chars().peekable();
+        let mut cursor = Cursor {
+            line: self.line,
+            col: self.col,
+            ..Cursor::new(self.dialect, &self.query)
+        };
+        let tokens = cursor.tokenize(self.max_tokens, false);
+        self.line = cursor.line;
+        self.col = cursor.col;
+        self.max_tokens_exceeded = cursor.max_tokens_exceeded;
+        tokens
+    }
+}
 
+/// The input left to tokenize, and the position reached in it, shared by
+/// [Tokenizer::tokenize] and the statement-at-a-time [Parser::parse_sql_lazily]
+///
+/// [Parser::parse_sql_lazily]: crate::parser::Parser::parse_sql_lazily
+pub(crate) struct Cursor<'a> {
+    dialect: &'a dyn Dialect,
+    chars: Peekable<Chars<'a>>,
+    line: u64,
+    col: u64,
+    max_tokens_exceeded: bool,
+}
+
+impl<'a> Cursor<'a> {
+    pub(crate) fn new(dialect: &'a dyn Dialect, input: &'a str) -> Self {
+        Self {
+            dialect,
+            chars: input.chars().peekable(),
+            line: 1,
+            col: 1,
+            max_tokens_exceeded: false,
+        }
+    }
+
+    /// Whether tokenization failed because of the `max_tokens` limit
+    pub(crate) fn max_tokens_exceeded(&self) -> bool {
+        self.max_tokens_exceeded
+    }
+
+    /// Tokenize the rest of the input, or with `stop_after_semicolon` up to
+    /// and including the next `;`. Successive calls continue where the
+    /// previous one stopped, returning no tokens once the input is exhausted.
+    /// The `max_tokens` limit applies to each call separately.
+    pub(crate) fn tokenize(
+        &mut self,
+        max_tokens: Option<usize>,
+        stop_after_semicolon: bool,
+    ) -> Result<Vec<Token>, TokenizerError> {
         let mut tokens: Vec<Token> = vec![];
 
-        while let Some(token) = self.next_token(&mut peekable)? {
-            match &token {
-                Token::Whitespace(Whitespace::Newline) => {
-                    self.line += 1;
-                    self.col = 1;
+        while let Some(token) = self.next_token()? {
+            if let Some(max_tokens) = max_tokens {
+                if tokens.len() >= max_tokens {
+                    self.max_tokens_exceeded = true;
+                    return self.tokenizer_error(
+                        format!("Input too large: more than {} tokens", max_tokens).as_str(),
+                    );
                 }
-
-                Token::Whitespace(Whitespace::Tab) => self.col += 4,
-                Token::Word(w) if w.quote_style == None => self.col += w.value.len() as u64,
-                Token::Word(w) if w.quote_style != None => self.col += w.value.len() as u64 + 2,
-                Token::Number(s, _) => self.col += s.len() as u64,
-                Token::SingleQuotedString(s) => self.col += s.len() as u64,
-                _ => self.col += 1,
             }
+            self.advance(&token);
 
+            let at_semicolon = token == Token::SemiColon;
             tokens.push(token);
+            if stop_after_semicolon && at_semicolon {
+                break;
+            }
         }
         Ok(tokens)
     }
 
+    /// Move the position past `token`
+    fn advance(&mut self, token: &Token) {
+        match token {
+            Token::Whitespace(Whitespace::Newline) => {
+                self.line += 1;
+                self.col = 1;
+            }
+
+            Token::Whitespace(Whitespace::Tab) => self.col += 4,
+            // Comments may span several lines, which must be accounted
+            // for to report correct positions for the following tokens.
+            Token::Whitespace(Whitespace::SingleLineComment { comment, prefix }) => {
+                if comment.ends_with('\n') {
+                    self.line += 1;
+                    self.col = 1;
+                } else {
+                    self.col += (prefix.len() + comment.len()) as u64;
+                }
+            }
+            Token::Whitespace(Whitespace::MultiLineComment(s)) => match s.rfind('\n') {
+                Some(last_newline) => {
+                    self.line += s.matches('\n').count() as u64;
+                    // the text after the last newline, plus the closing `*/`
+                    self.col = 1 + (s.len() - last_newline - 1) as u64 + 2;
+                }
+                None => self.col += s.len() as u64 + 4,
+            },
+            Token::Word(w) if w.quote_style == None => self.col += w.value.len() as u64,
+            Token::Word(w) if w.quote_style != None => self.col += w.value.len() as u64 + 2,
+            Token::Number(s, _) => self.col += s.len() as u64,
+            Token::SingleQuotedString(s) => self.col += s.len() as u64,
+            Token::Placeholder(s) => self.col += s.len() as u64,
+            Token::CustomOperator(op) => self.col += op.len() as u64,
+            // The body may span several lines, like a multi-line comment
+            Token::DollarQuotedString(s) => {
+                let s = s.to_string();
+                match s.rfind('\n') {
+                    Some(last_newline) => {
+                        self.line += s.matches('\n').count() as u64;
+                        // the text after the last newline, including the closing tag
+                        self.col = 1 + (s.len() - last_newline - 1) as u64;
+                    }
+                    None => self.col += s.len() as u64,
+                }
+            }
+            _ => self.col += 1,
+        }
+    }
+
     /// Get the next token or return None
-    fn next_token(&self, chars: &mut Peekable<Chars<'_>>) -> Result<Option<Token>, TokenizerError> {
-        //println!("next_token: {:?}", chars.peek());
-        match chars.peek() {
+    fn next_token(&mut self) -> Result<Option<Token>, TokenizerError> {
+        //println!("next_token: {:?}", self.chars.peek());
+        if let Some(token) = self.tokenize_custom_operator() {
+            return Ok(Some(token));
+        }
+        match self.chars.peek() {
             Some(&ch) => match ch {
-                ' ' => self.consume_and_return(chars, Token::Whitespace(Whitespace::Space)),
-                '\t' => self.consume_and_return(chars, Token::Whitespace(Whitespace::Tab)),
-                '\n' => self.consume_and_return(chars, Token::Whitespace(Whitespace::Newline)),
+                ' ' => self.consume_and_return(Token::Whitespace(Whitespace::Space)),
+                '\t' => self.consume_and_return(Token::Whitespace(Whitespace::Tab)),
+                '\n' => self.consume_and_return(Token::Whitespace(Whitespace::Newline)),
                 '\r' => {
                     // Emit a single Whitespace::Newline token for \r and \r\n
-                    chars.next();
-                    if let Some('\n') = chars.peek() {
-                        chars.next();
+                    self.chars.next();
+                    if let Some('\n') = self.chars.peek() {
+                        self.chars.next();
                     }
                     Ok(Some(Token::Whitespace(Whitespace::Newline)))
                 }
                 'N' => {
-                    chars.next(); // consume, to check the next char
-                    match chars.peek() {
+                    self.chars.next(); // consume, to check the next char
+                    match self.chars.peek() {
                         Some('\'') => {
                             // N'...' - a <national character string literal>
-                            let s = self.tokenize_single_quoted_string(chars)?;
+                            let s = self.tokenize_single_quoted_string()?;
                             Ok(Some(Token::NationalStringLiteral(s)))
                         }
                         _ => {
                             // regular identifier starting with an "N"
-                            let s = self.tokenize_word('N', chars);
+                            let s = self.tokenize_word('N');
                             Ok(Some(Token::make_word(&s, None)))
                         }
                     }
@@ -368,30 +540,63 @@ impl<'a> Tokenizer<'a> {
                 // The spec only allows an uppercase 'X' to introduce a hex
                 // string, but PostgreSQL, at least, allows a lowercase 'x' too.
                 x @ 'x' | x @ 'X' => {
-                    chars.next(); // consume, to check the next char
-                    match chars.peek() {
+                    self.chars.next(); // consume, to check the next char
+                    match self.chars.peek() {
                         Some('\'') => {
                             // X'...' - a <binary string literal>
-                            let s = self.tokenize_single_quoted_string(chars)?;
+                            let s = self.tokenize_single_quoted_string()?;
                             Ok(Some(Token::HexStringLiteral(s)))
                         }
                         _ => {
                             // regular identifier starting with an "X"
-                            let s = self.tokenize_word(x, chars);
+                            let s = self.tokenize_word(x);
                             Ok(Some(Token::make_word(&s, None)))
                         }
                     }
                 }
+                '$' if dialect_of!(self is PostgreSqlDialect | GenericDialect) => {
+                    self.chars.next(); // consume the '$'
+                    match self.chars.peek() {
+                        Some(&ch) if ch == '$' || ch.is_alphabetic() || ch == '_' => {
+                            let s = self.tokenize_dollar_quoted_string()?;
+                            Ok(Some(Token::DollarQuotedString(s)))
+                        }
+                        Some(ch) if ch.is_ascii_digit() => {
+                            let n = peeking_take_while(&mut self.chars, |ch| ch.is_ascii_digit());
+                            Ok(Some(Token::Placeholder(format!("${}", n))))
+                        }
+                        _ => Ok(Some(Token::Char('$'))),
+                    }
+                }
+                // `@>` and `#>` must be checked before identifiers, which may
+                // start with `@` or `#` in some dialects
+                '@' if dialect_of!(self is PostgreSqlDialect | GenericDialect)
+                    && self.chars.clone().nth(1) == Some('>') =>
+                {
+                    self.chars.next(); // consume the '@'
+                    self.consume_and_return(Token::AtArrow)
+                }
+                '#' if dialect_of!(self is PostgreSqlDialect | GenericDialect)
+                    && self.chars.clone().nth(1) == Some('>') =>
+                {
+                    self.chars.next(); // consume the '#'
+                    self.chars.next(); // consume the '>'
+                    match self.chars.peek() {
+                        Some('>') => self.consume_and_return(Token::HashLongArrow),
+                        _ => Ok(Some(Token::HashArrow)),
+                    }
+                }
                 // identifier or keyword
                 ch if self.dialect.is_identifier_start(ch) => {
-                    chars.next(); // consume the first char
-                    let s = self.tokenize_word(ch, chars);
+                    self.chars.next(); // consume the first char
+                    let s = self.tokenize_word(ch);
 
                     if s.chars().all(|x| ('0'..='9').contains(&x) || x == '.') {
                         let mut s = peeking_take_while(&mut s.chars().peekable(), |ch| {
                             matches!(ch, '0'..='9' | '.')
                         });
-                        let s2 = peeking_take_while(chars, |ch| matches!(ch, '0'..='9' | '.'));
+                        let s2 =
+                            peeking_take_while(&mut self.chars, |ch| matches!(ch, '0'..='9' | '.'));
                         s += s2.as_str();
                         return Ok(Some(Token::Number(s, false)));
                     }
@@ -399,45 +604,38 @@ impl<'a> Tokenizer<'a> {
                 }
                 // string
                 '\'' => {
-                    let s = self.tokenize_single_quoted_string(chars)?;
+                    let s = self.tokenize_single_quoted_string()?;
                     Ok(Some(Token::SingleQuotedString(s)))
                 }
                 // string
                 '`' => {
-                    let s = self.tokenize_back_quoted_string(chars)?;
+                    let s = self.tokenize_back_quoted_string()?;
                     Ok(Some(Token::BackQuotedString(s)))
                 }
                 // delimited (quoted) identifier
                 quote_start if self.dialect.is_delimited_identifier_start(quote_start) => {
-                    chars.next(); // consume the opening quote
+                    self.chars.next(); // consume the opening quote
                     let quote_end = Word::matching_end_quote(quote_start);
-                    let s = peeking_take_while(chars, |ch| ch != quote_end);
-                    if chars.next() == Some(quote_end) {
-                        Ok(Some(Token::make_word(&s, Some(quote_start))))
-                    } else {
-                        self.tokenizer_error(
-                            format!("Expected close delimiter '{}' before EOF.", quote_end)
-                                .as_str(),
-                        )
-                    }
+                    let s = self.tokenize_quoted_identifier(quote_end)?;
+                    Ok(Some(Token::make_word(&s, Some(quote_start))))
                 }
                 // numbers and period
                 '0'..='9' | '.' => {
-                    let mut s = peeking_take_while(chars, |ch| matches!(ch, '0'..='9'));
+                    let mut s = peeking_take_while(&mut self.chars, |ch| matches!(ch, '0'..='9'));
                     // match one period
-                    if let Some('.') = chars.peek() {
+                    if let Some('.') = self.chars.peek() {
                         s.push('.');
-                        chars.next();
+                        self.chars.next();
                     }
-                    s += &peeking_take_while(chars, |ch| matches!(ch, '0'..='9'));
+                    s += &peeking_take_while(&mut self.chars, |ch| matches!(ch, '0'..='9'));
 
                     // No number -> Token::Period
                     if s == "." {
                         return Ok(Some(Token::Period));
                     }
 
-                    let long = if chars.peek() == Some(&'L') {
-                        chars.next();
+                    let long = if self.chars.peek() == Some(&'L') {
+                        self.chars.next();
                         true
                     } else {
                         false
@@ -445,35 +643,42 @@ impl<'a> Tokenizer<'a> {
                     Ok(Some(Token::Number(s, long)))
                 }
                 // punctuation
-                '(' => self.consume_and_return(chars, Token::LParen),
-                ')' => self.consume_and_return(chars, Token::RParen),
-                ',' => self.consume_and_return(chars, Token::Comma),
+                '(' => self.consume_and_return(Token::LParen),
+                ')' => self.consume_and_return(Token::RParen),
+                ',' => self.consume_and_return(Token::Comma),
                 // operators
                 '-' => {
-                    chars.next(); // consume the '-'
-                    match chars.peek() {
+                    self.chars.next(); // consume the '-'
+                    match self.chars.peek() {
                         Some('-') => {
-                            chars.next(); // consume the second '-', starting a single-line comment
-                            let comment = self.tokenize_single_line_comment(chars);
+                            self.chars.next(); // consume the second '-', starting a single-line comment
+                            let comment = self.tokenize_single_line_comment();
                             Ok(Some(Token::Whitespace(Whitespace::SingleLineComment {
                                 prefix: "--".to_owned(),
                                 comment,
                             })))
                         }
+                        Some('>') if dialect_of!(self is PostgreSqlDialect | GenericDialect) => {
+                            self.chars.next(); // consume the '>'
+                            match self.chars.peek() {
+                                Some('>') => self.consume_and_return(Token::LongArrow),
+                                _ => Ok(Some(Token::Arrow)),
+                            }
+                        }
                         // a regular '-' operator
                         _ => Ok(Some(Token::Minus)),
                     }
                 }
                 '/' => {
-                    chars.next(); // consume the '/'
-                    match chars.peek() {
+                    self.chars.next(); // consume the '/'
+                    match self.chars.peek() {
                         Some('*') => {
-                            chars.next(); // consume the '*', starting a multi-line comment
-                            self.tokenize_multiline_comment(chars)
+                            self.chars.next(); // consume the '*', starting a multi-line comment
+                            self.tokenize_multiline_comment()
                         }
                         Some('/') if dialect_of!(self is SnowflakeDialect) => {
-                            chars.next(); // consume the second '/', starting a snowflake single-line comment
-                            let comment = self.tokenize_single_line_comment(chars);
+                            self.chars.next(); // consume the second '/', starting a snowflake single-line comment
+                            let comment = self.tokenize_single_line_comment();
                             Ok(Some(Token::Whitespace(Whitespace::SingleLineComment {
                                 prefix: "//".to_owned(),
                                 comment,
@@ -483,17 +688,17 @@ impl<'a> Tokenizer<'a> {
                         _ => Ok(Some(Token::Div)),
                     }
                 }
-                '+' => self.consume_and_return(chars, Token::Plus),
-                '*' => self.consume_and_return(chars, Token::Mult),
-                '%' => self.consume_and_return(chars, Token::Mod),
+                '+' => self.consume_and_return(Token::Plus),
+                '*' => self.consume_and_return(Token::Mult),
+                '%' => self.consume_and_return(Token::Mod),
                 '|' => {
-                    chars.next(); // consume the '|'
-                    match chars.peek() {
-                        Some('/') => self.consume_and_return(chars, Token::PGSquareRoot),
+                    self.chars.next(); // consume the '|'
+                    match self.chars.peek() {
+                        Some('/') => self.consume_and_return(Token::PGSquareRoot),
                         Some('|') => {
-                            chars.next(); // consume the second '|'
-                            match chars.peek() {
-                                Some('/') => self.consume_and_return(chars, Token::PGCubeRoot),
+                            self.chars.next(); // consume the second '|'
+                            match self.chars.peek() {
+                                Some('/') => self.consume_and_return(Token::PGCubeRoot),
                                 _ => Ok(Some(Token::StringConcat)),
                             }
                         }
@@ -502,22 +707,23 @@ impl<'a> Tokenizer<'a> {
                     }
                 }
                 '=' => {
-                    chars.next(); // consume
-                    match chars.peek() {
-                        Some('>') => self.consume_and_return(chars, Token::RArrow),
+                    self.chars.next(); // consume
+                    match self.chars.peek() {
+                        Some('>') => self.consume_and_return(Token::RArrow),
                         _ => Ok(Some(Token::Eq)),
                     }
                 }
                 '!' => {
-                    chars.next(); // consume
-                    match chars.peek() {
-                        Some('=') => self.consume_and_return(chars, Token::Neq),
-                        Some('!') => self.consume_and_return(chars, Token::DoubleExclamationMark),
+                    self.chars.next(); // consume
+                    match self.chars.peek() {
+                        Some('=') => self.consume_and_return(Token::Neq),
+                        Some('!') => self.consume_and_return(Token::DoubleExclamationMark),
                         Some('~') => {
-                            chars.next();
-                            match chars.peek() {
-                                Some('*') => self
-                                    .consume_and_return(chars, Token::ExclamationMarkTildeAsterisk),
+                            self.chars.next();
+                            match self.chars.peek() {
+                                Some('*') => {
+                                    self.consume_and_return(Token::ExclamationMarkTildeAsterisk)
+                                }
                                 _ => Ok(Some(Token::ExclamationMarkTilde)),
                             }
                         }
@@ -525,66 +731,108 @@ impl<'a> Tokenizer<'a> {
                     }
                 }
                 '<' => {
-                    chars.next(); // consume
-                    match chars.peek() {
+                    self.chars.next(); // consume
+                    match self.chars.peek() {
                         Some('=') => {
-                            chars.next();
-                            match chars.peek() {
-                                Some('>') => self.consume_and_return(chars, Token::Spaceship),
+                            self.chars.next();
+                            match self.chars.peek() {
+                                Some('>') => self.consume_and_return(Token::Spaceship),
                                 _ => Ok(Some(Token::LtEq)),
                             }
                         }
-                        Some('>') => self.consume_and_return(chars, Token::Neq),
-                        Some('<') => self.consume_and_return(chars, Token::ShiftLeft),
+                        Some('>') => self.consume_and_return(Token::Neq),
+                        Some('<') => self.consume_and_return(Token::ShiftLeft),
+                        Some('@') if dialect_of!(self is PostgreSqlDialect | GenericDialect) => {
+                            self.consume_and_return(Token::ArrowAt)
+                        }
                         _ => Ok(Some(Token::Lt)),
                     }
                 }
                 '>' => {
-                    chars.next(); // consume
-                    match chars.peek() {
-                        Some('=') => self.consume_and_return(chars, Token::GtEq),
-                        Some('>') => self.consume_and_return(chars, Token::ShiftRight),
+                    self.chars.next(); // consume
+                    match self.chars.peek() {
+                        Some('=') => self.consume_and_return(Token::GtEq),
+                        Some('>') => self.consume_and_return(Token::ShiftRight),
                         _ => Ok(Some(Token::Gt)),
                     }
                 }
                 ':' => {
-                    chars.next();
-                    match chars.peek() {
-                        Some(':') => self.consume_and_return(chars, Token::DoubleColon),
+                    self.chars.next();
+                    match self.chars.peek() {
+                        Some(':') => self.consume_and_return(Token::DoubleColon),
                         _ => Ok(Some(Token::Colon)),
                     }
                 }
-                ';' => self.consume_and_return(chars, Token::SemiColon),
-                '\\' => self.consume_and_return(chars, Token::Backslash),
-                '[' => self.consume_and_return(chars, Token::LBracket),
-                ']' => self.consume_and_return(chars, Token::RBracket),
-                '&' => self.consume_and_return(chars, Token::Ampersand),
-                '^' => self.consume_and_return(chars, Token::Caret),
-                '{' => self.consume_and_return(chars, Token::LBrace),
-                '}' => self.consume_and_return(chars, Token::RBrace),
+                ';' => self.consume_and_return(Token::SemiColon),
+                '\\' => self.consume_and_return(Token::Backslash),
+                '[' => self.consume_and_return(Token::LBracket),
+                ']' => self.consume_and_return(Token::RBracket),
+                '&' => {
+                    self.chars.next(); // consume the '&'
+                    match self.chars.peek() {
+                        Some('&') if dialect_of!(self is PostgreSqlDialect | GenericDialect) => {
+                            self.consume_and_return(Token::Overlap)
+                        }
+                        _ => Ok(Some(Token::Ampersand)),
+                    }
+                }
+                '^' => self.consume_and_return(Token::Caret),
+                '{' => self.consume_and_return(Token::LBrace),
+                '}' => self.consume_and_return(Token::RBrace),
                 '#' if dialect_of!(self is SnowflakeDialect) => {
-                    chars.next(); // consume the '#', starting a snowflake single-line comment
-                    let comment = self.tokenize_single_line_comment(chars);
+                    self.chars.next(); // consume the '#', starting a snowflake single-line comment
+                    let comment = self.tokenize_single_line_comment();
                     Ok(Some(Token::Whitespace(Whitespace::SingleLineComment {
                         prefix: "#".to_owned(),
                         comment,
                     })))
                 }
                 '~' => {
-                    chars.next(); // consume
-                    match chars.peek() {
-                        Some('*') => self.consume_and_return(chars, Token::TildeAsterisk),
+                    self.chars.next(); // consume
+                    match self.chars.peek() {
+                        Some('*') => self.consume_and_return(Token::TildeAsterisk),
                         _ => Ok(Some(Token::Tilde)),
                     }
                 }
-                '#' => self.consume_and_return(chars, Token::Sharp),
-                '@' => self.consume_and_return(chars, Token::AtSign),
-                other => self.consume_and_return(chars, Token::Char(other)),
+                '#' => self.consume_and_return(Token::Sharp),
+                '@' => self.consume_and_return(Token::AtSign),
+                '?' => {
+                    self.chars.next(); // consume the '?'
+                    let pg = dialect_of!(self is PostgreSqlDialect | GenericDialect);
+                    match self.chars.peek() {
+                        Some('|') if pg => self.consume_and_return(Token::QuestionPipe),
+                        Some('&') if pg => self.consume_and_return(Token::QuestionAnd),
+                        // a numbered `?NNN` prepared statement placeholder
+                        Some(ch) if ch.is_ascii_digit() => {
+                            let n = peeking_take_while(&mut self.chars, |ch| ch.is_ascii_digit());
+                            Ok(Some(Token::Placeholder(format!("?{}", n))))
+                        }
+                        // a `?` placeholder, or PostgreSQL's key exists operator
+                        _ => Ok(Some(Token::Question)),
+                    }
+                }
+                other => self.consume_and_return(Token::Char(other)),
             },
             None => Ok(None),
         }
     }
 
+    /// Consume the longest of the dialect's custom operators that starts the input
+    fn tokenize_custom_operator(&mut self) -> Option<Token> {
+        let op = self
+            .dialect
+            .custom_operators()
+            .iter()
+            .filter(|op| {
+                !op.is_empty() && self.chars.clone().take(op.chars().count()).eq(op.chars())
+            })
+            .max_by_key(|op| op.len())?;
+        for _ in op.chars() {
+            self.chars.next();
+        }
+        Some(Token::CustomOperator(op.to_string()))
+    }
+
     fn tokenizer_error<R>(&self, message: &str) -> Result<R, TokenizerError> {
         Err(TokenizerError {
             message: message.to_string(),
@@ -594,9 +842,9 @@ impl<'a> Tokenizer<'a> {
     }
 
     // Consume characters until newline
-    fn tokenize_single_line_comment(&self, chars: &mut Peekable<Chars<'_>>) -> String {
-        let mut comment = peeking_take_while(chars, |ch| ch != '\n');
-        if let Some(ch) = chars.next() {
+    fn tokenize_single_line_comment(&mut self) -> String {
+        let mut comment = peeking_take_while(&mut self.chars, |ch| ch != '\n');
+        if let Some(ch) = self.chars.next() {
             assert_eq!(ch, '\n');
             comment.push(ch);
         }
@@ -604,35 +852,33 @@ impl<'a> Tokenizer<'a> {
     }
 
     /// Tokenize an identifier or keyword, after the first char is already consumed.
-    fn tokenize_word(&self, first_char: char, chars: &mut Peekable<Chars<'_>>) -> String {
+    fn tokenize_word(&mut self, first_char: char) -> String {
+        let dialect = self.dialect;
         let mut s = first_char.to_string();
-        s.push_str(&peeking_take_while(chars, |ch| {
-            self.dialect.is_identifier_part(ch)
+        s.push_str(&peeking_take_while(&mut self.chars, |ch| {
+            dialect.is_identifier_part(ch)
         }));
         s
     }
 
     /// Read a single quoted string, starting with the opening quote.
-    fn tokenize_single_quoted_string(
-        &self,
-        chars: &mut Peekable<Chars<'_>>,
-    ) -> Result<String, TokenizerError> {
+    fn tokenize_single_quoted_string(&mut self) -> Result<String, TokenizerError> {
         let mut s = String::new();
-        chars.next(); // consume the opening quote
-        while let Some(&ch) = chars.peek() {
+        self.chars.next(); // consume the opening quote
+        while let Some(&ch) = self.chars.peek() {
             match ch {
                 '\'' => {
-                    chars.next(); // consume
-                    let escaped_quote = chars.peek().map(|c| *c == '\'').unwrap_or(false);
+                    self.chars.next(); // consume
+                    let escaped_quote = self.chars.peek().map(|c| *c == '\'').unwrap_or(false);
                     if escaped_quote {
                         s.push('\'');
-                        chars.next();
+                        self.chars.next();
                     } else {
                         return Ok(s);
                     }
                 }
                 _ => {
-                    chars.next(); // consume
+                    self.chars.next(); // consume
                     s.push(ch);
                 }
             }
@@ -640,20 +886,60 @@ impl<'a> Tokenizer<'a> {
         self.tokenizer_error("Unterminated string literal")
     }
 
-    fn tokenize_back_quoted_string(
-        &self,
-        chars: &mut Peekable<Chars<'_>>,
-    ) -> Result<String, TokenizerError> {
+    /// Read a delimited identifier up to and including `quote_end`, where a
+    /// doubled `quote_end` stands for a literal one
+    fn tokenize_quoted_identifier(&mut self, quote_end: char) -> Result<String, TokenizerError> {
+        let mut s = String::new();
+        while let Some(ch) = self.chars.next() {
+            if ch == quote_end {
+                if self.chars.peek() == Some(&quote_end) {
+                    self.chars.next();
+                    s.push(ch);
+                } else {
+                    return Ok(s);
+                }
+            } else {
+                s.push(ch);
+            }
+        }
+        self.tokenizer_error(
+            format!("Expected close delimiter '{}' before EOF.", quote_end).as_str(),
+        )
+    }
+
+    /// Read a dollar-quoted string `$tag$...$tag$` (where the tag may be
+    /// empty), after the opening `$` is already consumed
+    fn tokenize_dollar_quoted_string(&mut self) -> Result<DollarQuotedString, TokenizerError> {
+        let tag = peeking_take_while(&mut self.chars, |ch| ch.is_alphanumeric() || ch == '_');
+        if self.chars.next() != Some('$') {
+            return self.tokenizer_error("Expected '$' after the tag of a dollar-quoted string");
+        }
+        let delimiter = format!("${}$", tag);
+        let mut value = String::new();
+        for ch in &mut self.chars {
+            value.push(ch);
+            if value.ends_with(&delimiter) {
+                value.truncate(value.len() - delimiter.len());
+                return Ok(DollarQuotedString {
+                    value,
+                    tag: if tag.is_empty() { None } else { Some(tag) },
+                });
+            }
+        }
+        self.tokenizer_error("Unterminated dollar-quoted string")
+    }
+
+    fn tokenize_back_quoted_string(&mut self) -> Result<String, TokenizerError> {
         let mut s = String::new();
-        chars.next(); // consume the opening quote
-        while let Some(&ch) = chars.peek() {
+        self.chars.next(); // consume the opening quote
+        while let Some(&ch) = self.chars.peek() {
             match ch {
                 '`' => {
-                    chars.next(); // consume
+                    self.chars.next(); // consume
                     return Ok(s);
                 }
                 _ => {
-                    chars.next(); // consume
+                    self.chars.next(); // consume
                     s.push(ch);
                 }
             }
@@ -661,40 +947,35 @@ impl<'a> Tokenizer<'a> {
         self.tokenizer_error("Unterminated string literal")
     }
 
-    fn tokenize_multiline_comment(
-        &self,
-        chars: &mut Peekable<Chars<'_>>,
-    ) -> Result<Option<Token>, TokenizerError> {
+    fn tokenize_multiline_comment(&mut self) -> Result<Option<Token>, TokenizerError> {
         let mut s = String::new();
-        let mut maybe_closing_comment = false;
-        // TODO: deal with nested comments
+        let supports_nested_comments = self.dialect.supports_nested_comments();
+        // The comment ends once every `/*` (including the opening one) is closed
+        let mut depth = 1;
         loop {
-            match chars.next() {
-                Some(ch) => {
-                    if maybe_closing_comment {
-                        if ch == '/' {
-                            break Ok(Some(Token::Whitespace(Whitespace::MultiLineComment(s))));
-                        } else {
-                            s.push('*');
-                        }
-                    }
-                    maybe_closing_comment = ch == '*';
-                    if !maybe_closing_comment {
-                        s.push(ch);
+            match self.chars.next() {
+                Some('/') if supports_nested_comments && self.chars.peek() == Some(&'*') => {
+                    self.chars.next(); // consume the '*'
+                    s.push_str("/*");
+                    depth += 1;
+                }
+                Some('*') if self.chars.peek() == Some(&'/') => {
+                    self.chars.next(); // consume the '/'
+                    depth -= 1;
+                    if depth == 0 {
+                        break Ok(Some(Token::Whitespace(Whitespace::MultiLineComment(s))));
                     }
+                    s.push_str("*/");
                 }
+                Some(ch) => s.push(ch),
                 None => break self.tokenizer_error("Unexpected EOF while in a multi-line comment"),
             }
         }
     }
 
     #[allow(clippy::unnecessary_wraps)]
-    fn consume_and_return(
-        &self,
-        chars: &mut Peekable<Chars<'_>>,
-        t: Token,
-    ) -> Result<Option<Token>, TokenizerError> {
-        chars.next();
+    fn consume_and_return(&mut self, t: Token) -> Result<Option<Token>, TokenizerError> {
+        self.chars.next();
         Ok(Some(t))
     }
 }
@@ -722,7 +1003,9 @@ fn peeking_take_while(
 mod tests {
     use super::super::dialect::GenericDialect;
     use super::super::dialect::MsSqlDialect;
+    use super::super::dialect::MySqlDialect;
     use super::*;
+    use crate::test_utils::TestDialect;
 
     #[test]
     fn tokenize_select_1() {
@@ -944,7 +1227,7 @@ mod tests {
     fn tokenize_invalid_string() {
         let sql = String::from("\nمصطفىh");
 
-        let dialect = GenericDialect {};
+        let dialect = MsSqlDialect {};
         let mut tokenizer = Tokenizer::new(&dialect, &sql);
         let tokens = tokenizer.tokenize().unwrap();
         println!("tokens: {:#?}", tokens);
@@ -991,7 +1274,7 @@ mod tests {
     fn tokenize_invalid_string_cols() {
         let sql = String::from("\n\nSELECT * FROM table\tمصطفىh");
 
-        let dialect = GenericDialect {};
+        let dialect = MsSqlDialect {};
         let mut tokenizer = Tokenizer::new(&dialect, &sql);
         let tokens = tokenizer.tokenize().unwrap();
         println!("tokens: {:#?}", tokens);
@@ -1100,6 +1383,33 @@ mod tests {
         compare(expected, tokens);
     }
 
+    #[test]
+    fn tokenize_error_after_multiline_comment() {
+        let sql = String::from("SELECT /* a\ncomment\nhere */ 'foo");
+
+        let dialect = GenericDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        assert_eq!(
+            tokenizer.tokenize(),
+            Err(TokenizerError {
+                message: "Unterminated string literal".to_string(),
+                line: 3,
+                col: 9
+            })
+        );
+
+        let sql = String::from("SELECT -- comment\n/* one line */ 'foo");
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        assert_eq!(
+            tokenizer.tokenize(),
+            Err(TokenizerError {
+                message: "Unterminated string literal".to_string(),
+                line: 2,
+                col: 16
+            })
+        );
+    }
+
     #[test]
     fn tokenize_multiline_comment_with_even_asterisks() {
         let sql = String::from("\n/** Comment **/\n");
@@ -1131,6 +1441,112 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tokenize_quoted_identifier_with_escaped_quote() {
+        let sql = String::from("\"a \"\" b\" \"\"\"\"");
+        let dialect = GenericDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        let tokens = tokenizer.tokenize().unwrap();
+        let expected = vec![
+            Token::make_word(r#"a " b"#, Some('"')),
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word(r#"""#, Some('"')),
+        ];
+        compare(expected, tokens.clone());
+        assert_eq!(
+            sql,
+            tokens.iter().map(|t| t.to_string()).collect::<String>()
+        );
+    }
+
+    #[test]
+    fn tokenize_dollar_quoted_string() {
+        let sql = String::from("$$a 'b'$$ $x$ $$ $y$ $x$$1");
+        let dialect = PostgreSqlDialect {};
+        let tokens = Tokenizer::new(&dialect, &sql).tokenize().unwrap();
+        let expected = vec![
+            Token::DollarQuotedString(DollarQuotedString {
+                value: "a 'b'".to_string(),
+                tag: None,
+            }),
+            Token::Whitespace(Whitespace::Space),
+            Token::DollarQuotedString(DollarQuotedString {
+                value: " $$ $y$ ".to_string(),
+                tag: Some("x".to_string()),
+            }),
+            Token::Placeholder("$1".to_string()),
+        ];
+        compare(expected, tokens.clone());
+        assert_eq!(
+            sql,
+            tokens.iter().map(|t| t.to_string()).collect::<String>()
+        );
+    }
+
+    #[test]
+    fn tokenize_error_after_dollar_quoted_string() {
+        let sql = String::from("CREATE FUNCTION f() AS $$\nSELECT\n1$$ LANGUAGE sql 'oops");
+        let dialect = PostgreSqlDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        assert_eq!(
+            tokenizer.tokenize(),
+            Err(TokenizerError {
+                message: "Unterminated string literal".to_string(),
+                line: 3,
+                col: 18
+            })
+        );
+
+        let sql = String::from("SELECT $tag$a$tag$ 'oops");
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        assert_eq!(
+            tokenizer.tokenize(),
+            Err(TokenizerError {
+                message: "Unterminated string literal".to_string(),
+                line: 1,
+                col: 20
+            })
+        );
+    }
+
+    #[test]
+    fn tokenize_error_after_placeholder() {
+        let sql = String::from("SELECT $12, ?123 'oops");
+        let dialect = GenericDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        assert_eq!(
+            tokenizer.tokenize(),
+            Err(TokenizerError {
+                message: "Unterminated string literal".to_string(),
+                line: 1,
+                col: 18
+            })
+        );
+    }
+
+    #[test]
+    fn tokenize_max_tokens() {
+        let sql = String::from("SELECT 1, 2");
+        let dialect = GenericDialect {};
+
+        // exactly at the limit is fine
+        let tokens = Tokenizer::new(&dialect, &sql)
+            .with_max_tokens(6)
+            .tokenize()
+            .unwrap();
+        assert_eq!(6, tokens.len());
+
+        let mut tokenizer = Tokenizer::new(&dialect, &sql).with_max_tokens(5);
+        assert_eq!(
+            tokenizer.tokenize(),
+            Err(TokenizerError {
+                message: "Input too large: more than 5 tokens".to_string(),
+                line: 1,
+                col: 11
+            })
+        );
+    }
+
     #[test]
     fn tokenize_newlines() {
         let sql = String::from("line1\nline2\rline3\r\nline4\r");
@@ -1212,6 +1628,64 @@ mod tests {
         compare(expected, tokens);
     }
 
+    #[test]
+    fn tokenize_unicode_identifiers() {
+        let sql = "SELECT café, 名前 FROM données";
+        let dialect = GenericDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, sql);
+        let tokens = tokenizer.tokenize().unwrap();
+        let expected = vec![
+            Token::make_keyword("SELECT"),
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("café", None),
+            Token::Comma,
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("名前", None),
+            Token::Whitespace(Whitespace::Space),
+            Token::make_keyword("FROM"),
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("données", None),
+        ];
+        compare(expected, tokens);
+    }
+
+    #[test]
+    fn tokenize_multi_char_operators_maximal_munch() {
+        let word = |w: &str| Token::make_word(w, None);
+        let string = |s: &str| Token::SingleQuotedString(s.to_string());
+        let number = |n: &str| Token::Number(n.to_string(), false);
+        // Each operator is directly adjacent to its operands, so any split
+        // of a multi-char operator would show up as an extra token
+        let cases = vec![
+            ("a->>'b'", vec![word("a"), Token::LongArrow, string("b")]),
+            ("a->'b'", vec![word("a"), Token::Arrow, string("b")]),
+            (
+                "a#>>'{b}'",
+                vec![word("a"), Token::HashLongArrow, string("{b}")],
+            ),
+            (
+                "a>=-1",
+                vec![word("a"), Token::GtEq, Token::Minus, number("1")],
+            ),
+            ("a<=>b", vec![word("a"), Token::Spaceship, word("b")]),
+            ("a<>b", vec![word("a"), Token::Neq, word("b")]),
+            ("a!=b", vec![word("a"), Token::Neq, word("b")]),
+            ("1<<2", vec![number("1"), Token::ShiftLeft, number("2")]),
+            ("1>>2", vec![number("1"), Token::ShiftRight, number("2")]),
+            (
+                "'x'||'y'",
+                vec![string("x"), Token::StringConcat, string("y")],
+            ),
+            ("a::int", vec![word("a"), Token::DoubleColon, word("int")]),
+            ("a<@b", vec![word("a"), Token::ArrowAt, word("b")]),
+        ];
+        let dialect = PostgreSqlDialect {};
+        for (sql, expected) in cases {
+            let mut tokenizer = Tokenizer::new(&dialect, sql);
+            compare(expected, tokenizer.tokenize().unwrap());
+        }
+    }
+
     fn compare(expected: Vec<Token>, actual: Vec<Token>) {
         //println!("------------------------------");
         //println!("tokens   = {:?}", actual);
@@ -1219,4 +1693,122 @@ mod tests {
         //println!("------------------------------");
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn tokenize_pg_containment_operators() {
+        let sql = String::from("a @> b <@ c && d ? e ?| f ?& g");
+        let dialect = PostgreSqlDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        let tokens: Vec<Token> = tokenizer
+            .tokenize()
+            .unwrap()
+            .into_iter()
+            .filter(|t| !matches!(t, Token::Whitespace(_)))
+            .collect();
+        let expected = vec![
+            Token::make_word("a", None),
+            Token::AtArrow,
+            Token::make_word("b", None),
+            Token::ArrowAt,
+            Token::make_word("c", None),
+            Token::Overlap,
+            Token::make_word("d", None),
+            Token::Question,
+            Token::make_word("e", None),
+            Token::QuestionPipe,
+            Token::make_word("f", None),
+            Token::QuestionAnd,
+            Token::make_word("g", None),
+        ];
+        compare(expected, tokens);
+
+        // Other dialects keep tokenizing the individual characters
+        let dialect = MySqlDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, "a && b");
+        let tokens = tokenizer.tokenize().unwrap();
+        let expected = vec![
+            Token::make_word("a", None),
+            Token::Whitespace(Whitespace::Space),
+            Token::Ampersand,
+            Token::Ampersand,
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("b", None),
+        ];
+        compare(expected, tokens);
+    }
+
+    #[test]
+    fn tokenize_custom_operator() {
+        let sql = String::from("a===b==c=d");
+        let dialect = TestDialect {
+            custom_operators: &["==", "==="],
+            ..Default::default()
+        };
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        let tokens = tokenizer.tokenize().unwrap();
+        let expected = vec![
+            Token::make_word("a", None),
+            Token::CustomOperator("===".to_string()),
+            Token::make_word("b", None),
+            Token::CustomOperator("==".to_string()),
+            Token::make_word("c", None),
+            Token::Eq,
+            Token::make_word("d", None),
+        ];
+        compare(expected, tokens);
+
+        let mut tokenizer = Tokenizer::new(&dialect, "a === 'b");
+        assert_eq!(
+            tokenizer.tokenize(),
+            Err(TokenizerError {
+                message: "Unterminated string literal".to_string(),
+                line: 1,
+                col: 7
+            })
+        );
+    }
+
+    #[test]
+    fn tokenize_nested_multiline_comment() {
+        let sql = String::from("0/* outer /* inner */ outer */1");
+
+        let dialect = PostgreSqlDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        let tokens = tokenizer.tokenize().unwrap();
+        let expected = vec![
+            Token::Number("0".to_string(), false),
+            Token::Whitespace(Whitespace::MultiLineComment(
+                " outer /* inner */ outer ".to_string(),
+            )),
+            Token::Number("1".to_string(), false),
+        ];
+        compare(expected, tokens);
+
+        // Every nested comment must be closed
+        let mut tokenizer = Tokenizer::new(&dialect, "0/* outer /* inner */1");
+        assert_eq!(
+            tokenizer.tokenize(),
+            Err(TokenizerError {
+                message: "Unexpected EOF while in a multi-line comment".to_string(),
+                line: 1,
+                col: 2
+            })
+        );
+
+        // Without nesting, the first `*/` ends the comment
+        let dialect = GenericDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, &sql);
+        let tokens = tokenizer.tokenize().unwrap();
+        let expected = vec![
+            Token::Number("0".to_string(), false),
+            Token::Whitespace(Whitespace::MultiLineComment(" outer /* inner ".to_string())),
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("outer", None),
+            Token::Whitespace(Whitespace::Space),
+            Token::Mult,
+            Token::Div,
+            Token::Number("1".to_string(), false),
+        ];
+        compare(expected, tokens);
+    }
 }
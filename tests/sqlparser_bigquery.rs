@@ -0,0 +1,185 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![warn(clippy::all)]
+//! Test SQL syntax specific to BigQuery. The parser based on the
+//! generic dialect is also tested (on the inputs it can handle).
+
+#[macro_use]
+mod test_utils;
+use test_utils::*;
+
+use sqlparser::ast::*;
+use sqlparser::dialect::{BigQueryDialect, GenericDialect, PostgreSqlDialect};
+use sqlparser::parser::ParserError;
+
+#[test]
+fn parse_safe_cast() {
+    let sql = "SELECT SAFE_CAST(x AS STRING) FROM t";
+    let select = bigquery_and_generic().verified_only_select(sql);
+    assert_eq!(
+        &Expr::Cast {
+            kind: CastKind::SafeCast,
+            expr: Box::new(Expr::Identifier(Ident::new("x"))),
+            data_type: DataType::String,
+            format: None,
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    bigquery_and_generic().one_statement_parses_to(
+        "SELECT SAFE_CAST(x, DECIMAL) FROM t",
+        "SELECT SAFE_CAST(x AS NUMERIC) FROM t",
+    );
+
+    // Other dialects don't recognize SAFE_CAST as a cast
+    let res = TestedDialects {
+        dialects: vec![Box::new(PostgreSqlDialect {})],
+    }
+    .parse_sql_statements(sql);
+    assert!(res.is_err());
+}
+
+#[test]
+fn parse_cast_with_format() {
+    let sql = "SELECT CAST(d AS STRING FORMAT 'YYYY-MM-DD') FROM t";
+    let select = bigquery_and_generic().verified_only_select(sql);
+    assert_eq!(
+        &Expr::Cast {
+            kind: CastKind::Cast,
+            expr: Box::new(Expr::Identifier(Ident::new("d"))),
+            data_type: DataType::String,
+            format: Some(Box::new(Expr::Value(Value::SingleQuotedString(
+                "YYYY-MM-DD".to_string()
+            )))),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    bigquery().verified_only_select("SELECT SAFE_CAST(s AS DATE FORMAT 'DD/MM/YYYY') FROM t");
+
+    // FORMAT is not recognized inside CAST by other dialects
+    let res = TestedDialects {
+        dialects: vec![Box::new(PostgreSqlDialect {})],
+    }
+    .parse_sql_statements(sql);
+    assert!(res.is_err());
+}
+
+#[test]
+fn parse_trailing_comma() {
+    bigquery().one_statement_parses_to("SELECT a, b, FROM t", "SELECT a, b FROM t");
+    bigquery().one_statement_parses_to(
+        "SELECT a, FROM t GROUP BY a, ORDER BY a,",
+        "SELECT a FROM t GROUP BY a ORDER BY a",
+    );
+    bigquery().one_statement_parses_to("SELECT f(a, b,)", "SELECT f(a, b)");
+
+    // Keywords that don't end the list are still list items
+    bigquery().verified_stmt("SELECT a, offset, returning FROM t");
+    bigquery().verified_stmt("SELECT a, view, cluster FROM t");
+    bigquery().verified_stmt("INSERT INTO t (a, sort) VALUES (1, 2)");
+
+    // a trailing comma is still rejected by default
+    let res = TestedDialects {
+        dialects: vec![Box::new(GenericDialect {})],
+    }
+    .parse_sql_statements("SELECT a, b, FROM t");
+    assert_eq!(
+        ParserError::ParserError("Expected a list item after ',', found: FROM".to_string()),
+        res.unwrap_err()
+    );
+}
+
+fn bigquery() -> TestedDialects {
+    TestedDialects {
+        dialects: vec![Box::new(BigQueryDialect {})],
+    }
+}
+
+fn bigquery_and_generic() -> TestedDialects {
+    TestedDialects {
+        dialects: vec![Box::new(BigQueryDialect {}), Box::new(GenericDialect {})],
+    }
+}
+
+#[test]
+fn parse_wildcard_exclude_and_replace() {
+    let select = bigquery_and_generic().verified_only_select("SELECT * EXCLUDE (a, b) FROM t");
+    assert_eq!(
+        &SelectItem::Wildcard(WildcardAdditionalOptions {
+            exclude: Some(vec![Ident::new("a"), Ident::new("b")]),
+            replace: None,
+        }),
+        only(&select.projection)
+    );
+
+    let select =
+        bigquery_and_generic().verified_only_select("SELECT * REPLACE (price * 2 AS price) FROM t");
+    assert_eq!(
+        &SelectItem::Wildcard(WildcardAdditionalOptions {
+            exclude: None,
+            replace: Some(vec![ReplaceSelectElement {
+                expr: Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier(Ident::new("price"))),
+                    op: BinaryOperator::Multiply,
+                    right: Box::new(Expr::Value(number("2"))),
+                },
+                column_name: Ident::new("price"),
+            }]),
+        }),
+        only(&select.projection)
+    );
+
+    bigquery_and_generic()
+        .verified_only_select("SELECT t.* EXCLUDE (a) REPLACE (x + 1 AS c, 'n' AS d) FROM t");
+    bigquery_and_generic()
+        .one_statement_parses_to("SELECT * EXCLUDE a FROM t", "SELECT * EXCLUDE (a) FROM t");
+
+    let res = bigquery_and_generic().parse_sql_statements("SELECT * REPLACE (x c) FROM t");
+    assert_eq!(
+        ParserError::ParserError("Expected AS, found: c".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_join_unnest() {
+    let select = bigquery().verified_only_select("SELECT * FROM t JOIN UNNEST(t.arr) AS x");
+    assert_eq!(
+        vec![Join {
+            relation: TableFactor::Table {
+                name: ObjectName(vec![Ident::new("UNNEST")]),
+                alias: Some(TableAlias {
+                    name: Ident::new("x"),
+                    columns: vec![],
+                }),
+                args: vec![FunctionArg::Unnamed(Expr::CompoundIdentifier(vec![
+                    Ident::new("t"),
+                    Ident::new("arr"),
+                ]))],
+                with_hints: vec![],
+            },
+            join_operator: JoinOperator::Inner(JoinConstraint::None),
+        }],
+        only(&select.from).joins
+    );
+    bigquery_and_generic().verified_stmt("SELECT x FROM t LEFT JOIN UNNEST(t.arr) AS x");
+    bigquery_and_generic().verified_stmt("SELECT x FROM t JOIN UNNEST(t.arr) AS x ON x > 1");
+
+    // other joins still need their condition
+    let res = bigquery().parse_sql_statements("SELECT * FROM t JOIN u");
+    assert_eq!(
+        ParserError::ParserError("Expected ON, or USING after JOIN, found: EOF".to_string()),
+        res.unwrap_err()
+    );
+}
@@ -20,12 +20,20 @@
 
 #[macro_use]
 mod test_utils;
-use test_utils::{all_dialects, expr_from_projection, join, number, only, table, table_alias};
+use test_utils::{
+    all_dialects, alter_table_op_with_name, expr_from_projection, ident, join, number, only, table,
+    table_alias, TestDialect, TestedDialects,
+};
 
 use matches::assert_matches;
 use sqlparser::ast::*;
-use sqlparser::dialect::{keywords::ALL_KEYWORDS, GenericDialect, SQLiteDialect};
+use sqlparser::dialect::{
+    keywords::{Keyword, ALL_KEYWORDS},
+    BigQueryDialect, Dialect, GenericDialect, HiveDialect, MySqlDialect, PostgreSqlDialect,
+    SQLiteDialect,
+};
 use sqlparser::parser::{Parser, ParserError};
+use sqlparser::tokenizer::Token;
 
 #[test]
 fn parse_insert_values() {
@@ -184,15 +192,15 @@ fn parse_update() {
                 assignments,
                 vec![
                     Assignment {
-                        id: "a".into(),
+                        target: AssignmentTarget::ColumnName("a".into()),
                         value: Expr::Value(number("1")),
                     },
                     Assignment {
-                        id: "b".into(),
+                        target: AssignmentTarget::ColumnName("b".into()),
                         value: Expr::Value(number("2")),
                     },
                     Assignment {
-                        id: "c".into(),
+                        target: AssignmentTarget::ColumnName("c".into()),
                         value: Expr::Value(number("3")),
                     },
                 ]
@@ -272,6 +280,93 @@ fn parse_where_delete_statement() {
     }
 }
 
+#[test]
+fn parse_delete_using() {
+    let sql = "DELETE FROM films USING producers AS p, studios WHERE films.producer_id = p.id";
+    match verified_stmt(sql) {
+        Statement::Delete {
+            table_name, using, ..
+        } => {
+            assert_eq!(ObjectName(vec![Ident::new("films")]), table_name);
+            assert_eq!(
+                vec![
+                    TableWithJoins {
+                        relation: TableFactor::Table {
+                            name: ObjectName(vec![Ident::new("producers")]),
+                            alias: Some(TableAlias {
+                                name: Ident::new("p"),
+                                columns: vec![],
+                            }),
+                            args: vec![],
+                            with_hints: vec![],
+                        },
+                        joins: vec![],
+                    },
+                    TableWithJoins {
+                        relation: table("studios"),
+                        joins: vec![],
+                    },
+                ],
+                using
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_update_with_row_assignment() {
+    let sql = "UPDATE t SET (a, b) = (1, 2), c = (SELECT 3) WHERE d";
+    match verified_stmt(sql) {
+        Statement::Update { assignments, .. } => {
+            assert_eq!(
+                Assignment {
+                    target: AssignmentTarget::Tuple(vec![Ident::new("a"), Ident::new("b")]),
+                    value: Expr::Tuple(vec![Expr::Value(number("1")), Expr::Value(number("2"))]),
+                },
+                assignments[0]
+            );
+            assert_eq!(
+                AssignmentTarget::ColumnName(Ident::new("c")),
+                assignments[1].target
+            );
+            assert!(matches!(assignments[1].value, Expr::Subquery(_)));
+        }
+        _ => unreachable!(),
+    }
+
+    let res = parse_sql_statements("UPDATE t SET (a, b = 1");
+    assert_eq!(
+        ParserError::ParserError("Expected ), found: =".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_update_from() {
+    let sql = "UPDATE accounts SET balance = balance - t.amount \
+               FROM transfers AS t JOIN users AS u ON t.user_id = u.id \
+               WHERE accounts.id = t.account_id";
+    match verified_stmt(sql) {
+        Statement::Update {
+            table_name,
+            from,
+            selection,
+            ..
+        } => {
+            assert_eq!(ObjectName(vec![Ident::new("accounts")]), table_name);
+            assert_eq!(1, from.len());
+            assert_eq!(1, from[0].joins.len());
+            assert_eq!(
+                "transfers AS t JOIN users AS u ON t.user_id = u.id",
+                from[0].to_string()
+            );
+            assert!(selection.is_some());
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_top_level() {
     verified_stmt("SELECT 1");
@@ -290,6 +385,85 @@ fn parse_simple_select() {
     assert_eq!(Some(Expr::Value(number("5"))), select.limit);
 }
 
+#[test]
+fn parse_row_comparison() {
+    let select = verified_only_select("SELECT * FROM t WHERE (a, b) = (1, 2)");
+    assert_eq!(
+        Some(Expr::BinaryOp {
+            left: Box::new(Expr::Tuple(vec![
+                Expr::Identifier(Ident::new("a")),
+                Expr::Identifier(Ident::new("b")),
+            ])),
+            op: BinaryOperator::Eq,
+            right: Box::new(Expr::Tuple(vec![
+                Expr::Value(number("1")),
+                Expr::Value(number("2")),
+            ])),
+        }),
+        select.selection
+    );
+
+    let select = verified_only_select("SELECT * FROM t WHERE (a, b) < (c, d) AND x = 1");
+    match select.selection {
+        Some(Expr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            ..
+        }) => assert_eq!(
+            Expr::BinaryOp {
+                left: Box::new(Expr::Tuple(vec![
+                    Expr::Identifier(Ident::new("a")),
+                    Expr::Identifier(Ident::new("b")),
+                ])),
+                op: BinaryOperator::Lt,
+                right: Box::new(Expr::Tuple(vec![
+                    Expr::Identifier(Ident::new("c")),
+                    Expr::Identifier(Ident::new("d")),
+                ])),
+            },
+            *left
+        ),
+        _ => unreachable!(),
+    }
+
+    // a single parenthesized expression is still a nested expression
+    assert_eq!(
+        Expr::Nested(Box::new(Expr::Identifier(Ident::new("a")))),
+        verified_expr("(a)")
+    );
+}
+
+#[test]
+fn parse_select_without_from() {
+    let select = verified_only_select("SELECT 1 + 1, now()");
+    assert!(select.from.is_empty());
+    assert_eq!(
+        vec![
+            SelectItem::UnnamedExpr(Expr::BinaryOp {
+                left: Box::new(Expr::Value(number("1"))),
+                op: BinaryOperator::Plus,
+                right: Box::new(Expr::Value(number("1"))),
+            }),
+            SelectItem::UnnamedExpr(Expr::Function(Function {
+                name: ObjectName(vec![Ident::new("now")]),
+                params: vec![],
+                args: vec![],
+                over: None,
+                distinct: false,
+                null_treatment: None,
+                order_by: vec![],
+                filter: None,
+            })),
+        ],
+        select.projection
+    );
+
+    let select = verified_only_select("SELECT 'a' AS x, 2 * 3 AS y WHERE 1 = 1");
+    assert!(select.from.is_empty());
+    assert_eq!(2, select.projection.len());
+    assert!(select.selection.is_some());
+}
+
 #[test]
 fn parse_limit_is_not_an_alias() {
     // In dialects supporting LIMIT it shouldn't be parsed as a table alias
@@ -300,6 +474,146 @@ fn parse_limit_is_not_an_alias() {
     assert_eq!(Some(Expr::Value(number("5"))), ast.limit);
 }
 
+#[test]
+fn parse_limit_offset_placeholders() {
+    let ast = verified_query("SELECT * FROM t LIMIT ? OFFSET ?");
+    assert_eq!(Some(Expr::Parameter("?".to_string())), ast.limit);
+    assert_eq!(
+        Some(Offset {
+            value: Expr::Parameter("?".to_string()),
+            rows: OffsetRows::None,
+        }),
+        ast.offset
+    );
+
+    let pg = TestedDialects {
+        dialects: vec![Box::new(PostgreSqlDialect {})],
+    };
+    let ast = pg.verified_query("SELECT * FROM t LIMIT $1 OFFSET $2 ROWS");
+    assert_eq!(Some(Expr::Parameter("$1".to_string())), ast.limit);
+    assert_eq!(
+        Some(Offset {
+            value: Expr::Parameter("$2".to_string()),
+            rows: OffsetRows::Rows,
+        }),
+        ast.offset
+    );
+}
+
+#[test]
+fn parse_placeholder_styles() {
+    let sql = "SELECT * FROM t WHERE a = ? AND b = :name AND c = :1";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier(Ident::new("a"))),
+                    op: BinaryOperator::Eq,
+                    right: Box::new(Expr::Parameter("?".to_string())),
+                }),
+                op: BinaryOperator::And,
+                right: Box::new(Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier(Ident::new("b"))),
+                    op: BinaryOperator::Eq,
+                    right: Box::new(Expr::Parameter(":name".to_string())),
+                }),
+            }),
+            op: BinaryOperator::And,
+            right: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("c"))),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::Parameter(":1".to_string())),
+            }),
+        },
+        select.selection.unwrap()
+    );
+
+    let pg = TestedDialects {
+        dialects: vec![Box::new(PostgreSqlDialect {})],
+    };
+    pg.verified_stmt("INSERT INTO t VALUES ($1, $2)");
+
+    // numbered `?NNN` placeholders are tokenized alike in every dialect,
+    // including those where `?` is also an operator
+    let select = verified_only_select("SELECT * FROM t WHERE a = ?1 OR b = ?");
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::Parameter("?1".to_string())),
+            }),
+            op: BinaryOperator::Or,
+            right: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("b"))),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::Parameter("?".to_string())),
+            }),
+        },
+        select.selection.unwrap()
+    );
+    let pg_and_generic = TestedDialects {
+        dialects: vec![Box::new(PostgreSqlDialect {}), Box::new(GenericDialect {})],
+    };
+    let ast = pg_and_generic.verified_query("SELECT * FROM t LIMIT ?1 OFFSET ?2");
+    assert_eq!(Some(Expr::Parameter("?1".to_string())), ast.limit);
+    assert_eq!(
+        Some(Offset {
+            value: Expr::Parameter("?2".to_string()),
+            rows: OffsetRows::None,
+        }),
+        ast.offset
+    );
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("a"))),
+            op: BinaryOperator::PGExists,
+            right: Box::new(Expr::Parameter("?1".to_string())),
+        },
+        pg_and_generic.verified_expr("a ? ?1")
+    );
+
+    // a colon placeholder doesn't interfere with array slices
+    pg.verified_expr("a[1:n]");
+
+    let res = parse_sql_statements("SELECT * FROM t WHERE a = : x");
+    assert_eq!(
+        ParserError::ParserError("Expected a parameter name after :, found:  ".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_negative_limit_offset() {
+    let res = parse_sql_statements("SELECT * FROM t LIMIT -1");
+    assert_eq!(
+        ParserError::ParserError("LIMIT must not be negative, found: -1".to_string()),
+        res.unwrap_err()
+    );
+    let res = parse_sql_statements("SELECT * FROM t LIMIT 1 OFFSET -5");
+    assert_eq!(
+        ParserError::ParserError("OFFSET must not be negative, found: -5".to_string()),
+        res.unwrap_err()
+    );
+
+    // non-literal expressions are not checked
+    let ast = verified_query("SELECT * FROM t LIMIT x - 1 OFFSET - y");
+    assert_eq!(
+        Some(Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("x"))),
+            op: BinaryOperator::Minus,
+            right: Box::new(Expr::Value(number("1"))),
+        }),
+        ast.limit
+    );
+    let res = parse_sql_statements("SELECT * FROM t LIMIT - 0");
+    assert_eq!(
+        ParserError::ParserError("LIMIT must not be negative, found: -0".to_string()),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_select_distinct() {
     let sql = "SELECT DISTINCT name FROM customer";
@@ -325,26 +639,95 @@ fn parse_select_all_distinct() {
     );
 }
 
+#[test]
+fn parse_wildcard_projection_order() {
+    let wildcard = || SelectItem::Wildcard(WildcardAdditionalOptions::default());
+    let column = |name: &str| SelectItem::UnnamedExpr(Expr::Identifier(Ident::new(name)));
+
+    let select = verified_only_select("SELECT *, extra_col FROM t");
+    assert_eq!(vec![wildcard(), column("extra_col")], select.projection);
+
+    let select = verified_only_select("SELECT a, * FROM t");
+    assert_eq!(vec![column("a"), wildcard()], select.projection);
+
+    let select = verified_only_select("SELECT a, t.*, b FROM t");
+    assert_eq!(
+        vec![
+            column("a"),
+            SelectItem::QualifiedWildcard(
+                ObjectName(vec![Ident::new("t")]),
+                WildcardAdditionalOptions::default()
+            ),
+            column("b"),
+        ],
+        select.projection
+    );
+}
+
+#[test]
+fn parse_operator_without_operand() {
+    for (sql, expected) in &[
+        ("SELECT +", "Expected an expression:, found: EOF"),
+        ("SELECT a *", "Expected an expression:, found: EOF"),
+        (
+            "SELECT * FROM t WHERE AND x",
+            "Expected an expression, found: AND",
+        ),
+        (
+            "SELECT * FROM t WHERE a = 1 OR",
+            "Expected an expression:, found: EOF",
+        ),
+        (
+            "SELECT * FROM t WHERE OR",
+            "Expected an expression, found: OR",
+        ),
+    ] {
+        assert_eq!(
+            ParserError::ParserError(expected.to_string()),
+            parse_sql_statements(sql).unwrap_err(),
+            "{}",
+            sql
+        );
+    }
+    // quoted, the words are ordinary identifiers
+    verified_expr("\"and\" OR \"or\"");
+}
+
+#[test]
+fn parse_keywords_as_list_items() {
+    // Non-reserved keywords after a comma are list items, not the end of the list
+    verified_stmt("SELECT a, offset FROM t");
+    verified_stmt("SELECT a, view, cluster FROM t");
+    verified_stmt("SELECT a, returning FROM t");
+    verified_stmt("INSERT INTO t (a, sort) VALUES (1, 2)");
+}
+
 #[test]
 fn parse_select_wildcard() {
     let sql = "SELECT * FROM foo";
     let select = verified_only_select(sql);
-    assert_eq!(&SelectItem::Wildcard, only(&select.projection));
+    assert_eq!(
+        &SelectItem::Wildcard(WildcardAdditionalOptions::default()),
+        only(&select.projection)
+    );
 
     let sql = "SELECT foo.* FROM foo";
     let select = verified_only_select(sql);
     assert_eq!(
-        &SelectItem::QualifiedWildcard(ObjectName(vec![Ident::new("foo")])),
+        &SelectItem::QualifiedWildcard(
+            ObjectName(vec![Ident::new("foo")]),
+            WildcardAdditionalOptions::default()
+        ),
         only(&select.projection)
     );
 
     let sql = "SELECT myschema.mytable.* FROM myschema.mytable";
     let select = verified_only_select(sql);
     assert_eq!(
-        &SelectItem::QualifiedWildcard(ObjectName(vec![
-            Ident::new("myschema"),
-            Ident::new("mytable"),
-        ])),
+        &SelectItem::QualifiedWildcard(
+            ObjectName(vec![Ident::new("myschema"), Ident::new("mytable"),]),
+            WildcardAdditionalOptions::default()
+        ),
         only(&select.projection)
     );
 }
@@ -402,6 +785,28 @@ fn test_no_infix_error() {
     );
 }
 
+#[test]
+fn parse_qualified_function_or_column() {
+    assert_eq!(
+        Expr::Function(Function {
+            name: ObjectName(vec![Ident::new("schema"), Ident::new("func")]),
+            params: vec![],
+            args: vec![FunctionArg::Unnamed(Expr::Identifier(Ident::new("x")))],
+            over: None,
+            distinct: false,
+            null_treatment: None,
+            order_by: vec![],
+            filter: None,
+        }),
+        verified_expr("schema.func(x)")
+    );
+    assert_eq!(
+        Expr::CompoundIdentifier(vec![Ident::new("schema"), Ident::new("col")]),
+        verified_expr("schema.col")
+    );
+    assert_eq!(Expr::Identifier(Ident::new("col")), verified_expr("col"));
+}
+
 #[test]
 fn parse_select_count_wildcard() {
     let sql = "SELECT COUNT(*) FROM customer";
@@ -410,14 +815,66 @@ fn parse_select_count_wildcard() {
         &Expr::Function(Function {
             name: ObjectName(vec![Ident::new("COUNT")]),
             params: vec![],
-            args: vec![FunctionArg::Unnamed(Expr::Wildcard)],
+            args: vec![FunctionArg::Wildcard],
             over: None,
             distinct: false,
+            null_treatment: None,
+            order_by: vec![],
+            filter: None,
         }),
         expr_from_projection(only(&select.projection))
     );
 }
 
+#[test]
+fn parse_function_qualified_wildcard_arg() {
+    let select = verified_only_select("SELECT count(t.*), f(s.t.*, 1) FROM t");
+    match expr_from_projection(&select.projection[0]) {
+        Expr::Function(Function { args, .. }) => assert_eq!(
+            &vec![FunctionArg::QualifiedWildcard(ObjectName(vec![
+                Ident::new("t")
+            ]))],
+            args
+        ),
+        _ => unreachable!(),
+    }
+    match expr_from_projection(&select.projection[1]) {
+        Expr::Function(Function { args, .. }) => assert_eq!(
+            FunctionArg::QualifiedWildcard(ObjectName(vec![Ident::new("s"), Ident::new("t")])),
+            args[0]
+        ),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_function_wildcard_arg_is_not_multiplication() {
+    let select = verified_only_select("SELECT count(a * b) FROM t");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function { args, .. }) => assert_eq!(
+            &vec![FunctionArg::Unnamed(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                op: BinaryOperator::Multiply,
+                right: Box::new(Expr::Identifier(Ident::new("b"))),
+            })],
+            args
+        ),
+        _ => unreachable!(),
+    }
+
+    let select = verified_only_select("SELECT f(*, 1) FROM t");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function { args, .. }) => assert_eq!(
+            &vec![
+                FunctionArg::Wildcard,
+                FunctionArg::Unnamed(Expr::Value(number("1")))
+            ],
+            args
+        ),
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_select_count_distinct() {
     let sql = "SELECT COUNT(DISTINCT + x) FROM customer";
@@ -432,6 +889,9 @@ fn parse_select_count_distinct() {
             })],
             over: None,
             distinct: true,
+            null_treatment: None,
+            order_by: vec![],
+            filter: None,
         }),
         expr_from_projection(only(&select.projection))
     );
@@ -463,6 +923,9 @@ fn parse_function_with_params() {
             ],
             over: None,
             distinct: false,
+            null_treatment: None,
+            order_by: vec![],
+            filter: None,
         }),
         expr_from_projection(only(&select.projection))
     );
@@ -684,10 +1147,11 @@ fn parse_not_precedence() {
         verified_expr(sql),
         Expr::UnaryOp {
             op: UnaryOperator::Not,
-            expr: Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::Value(Value::SingleQuotedString("a".into()))),
-                op: BinaryOperator::NotLike,
-                right: Box::new(Expr::Value(Value::SingleQuotedString("b".into()))),
+            expr: Box::new(Expr::Like {
+                negated: true,
+                expr: Box::new(Expr::Value(Value::SingleQuotedString("a".into()))),
+                pattern: Box::new(Expr::Value(Value::SingleQuotedString("b".into()))),
+                escape_char: None,
             }),
         },
     );
@@ -705,25 +1169,116 @@ fn parse_not_precedence() {
             }),
         },
     );
+
+    // NOT binds looser than `=`, so the following parses as NOT (a = b)
+    let sql = "NOT a = b";
+    assert_eq!(
+        verified_expr(sql),
+        Expr::UnaryOp {
+            op: UnaryOperator::Not,
+            expr: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier("a".into())),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::Identifier("b".into())),
+            }),
+        },
+    );
+
+    // ...but tighter than AND, so the following parses as (NOT a) AND b
+    let sql = "NOT a AND b";
+    assert_eq!(
+        verified_expr(sql),
+        Expr::BinaryOp {
+            left: Box::new(Expr::UnaryOp {
+                op: UnaryOperator::Not,
+                expr: Box::new(Expr::Identifier("a".into())),
+            }),
+            op: BinaryOperator::And,
+            right: Box::new(Expr::Identifier("b".into())),
+        },
+    );
 }
 
 #[test]
-fn parse_like() {
-    fn chk(negated: bool) {
-        let sql = &format!(
-            "SELECT * FROM customers WHERE name {}LIKE '%a'",
-            if negated { "NOT " } else { "" }
-        );
-        let select = verified_only_select(sql);
-        assert_eq!(
-            Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("name"))),
-                op: if negated {
-                    BinaryOperator::NotLike
-                } else {
-                    BinaryOperator::Like
-                },
-                right: Box::new(Expr::Value(Value::SingleQuotedString("%a".to_string()))),
+fn parse_unary_op_parenthesized_operand() {
+    verified_expr("NOT (a OR b)");
+    verified_expr("NOT (a = b)");
+    verified_expr("- (a + b)");
+    verified_expr("- (- x)");
+    verified_expr("- - x");
+    verified_expr("- a * b");
+
+    // A hand-built operand that binds more loosely than the operator must be
+    // parenthesized, or the output would parse back differently
+    let not_or = Expr::UnaryOp {
+        op: UnaryOperator::Not,
+        expr: Box::new(Expr::BinaryOp {
+            left: ident("a"),
+            op: BinaryOperator::Or,
+            right: ident("b"),
+        }),
+    };
+    assert_eq!("NOT (a OR b)", not_or.to_string());
+    let minus_plus = Expr::UnaryOp {
+        op: UnaryOperator::Minus,
+        expr: Box::new(Expr::BinaryOp {
+            left: ident("a"),
+            op: BinaryOperator::Plus,
+            right: ident("b"),
+        }),
+    };
+    assert_eq!("- (a + b)", minus_plus.to_string());
+    let minus_is_null = Expr::UnaryOp {
+        op: UnaryOperator::Minus,
+        expr: Box::new(Expr::IsNull(ident("a"))),
+    };
+    assert_eq!("- (a IS NULL)", minus_is_null.to_string());
+    // The precedence of a custom operator is up to the dialect, so the
+    // operand is parenthesized to be safe
+    let minus_custom = Expr::UnaryOp {
+        op: UnaryOperator::Minus,
+        expr: Box::new(Expr::BinaryOp {
+            left: ident("a"),
+            op: BinaryOperator::Custom("~~~".to_string()),
+            right: ident("b"),
+        }),
+    };
+    assert_eq!("- (a ~~~ b)", minus_custom.to_string());
+    let not_and_eq = Expr::UnaryOp {
+        op: UnaryOperator::Not,
+        expr: Box::new(Expr::BinaryOp {
+            left: ident("a"),
+            op: BinaryOperator::Eq,
+            right: ident("b"),
+        }),
+    };
+    assert_eq!("NOT a = b", not_and_eq.to_string());
+    assert_eq!(not_and_eq, verified_expr("NOT a = b"));
+    let minus_minus = Expr::UnaryOp {
+        op: UnaryOperator::Minus,
+        expr: Box::new(Expr::UnaryOp {
+            op: UnaryOperator::Minus,
+            expr: ident("x"),
+        }),
+    };
+    assert_eq!("- - x", minus_minus.to_string());
+    assert_eq!(minus_minus, verified_expr("- - x"));
+}
+
+#[test]
+fn parse_like() {
+    fn chk(negated: bool) {
+        let sql = &format!(
+            "SELECT * FROM customers WHERE name {}LIKE '%a'",
+            if negated { "NOT " } else { "" }
+        );
+        let select = verified_only_select(sql);
+        assert_eq!(
+            Expr::Like {
+                negated,
+                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                pattern: Box::new(Expr::Value(Value::SingleQuotedString("%a".to_string()))),
+                escape_char: None,
             },
             select.selection.unwrap()
         );
@@ -736,14 +1291,11 @@ fn parse_like() {
         );
         let select = verified_only_select(sql);
         assert_eq!(
-            Expr::IsNull(Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("name"))),
-                op: if negated {
-                    BinaryOperator::NotLike
-                } else {
-                    BinaryOperator::Like
-                },
-                right: Box::new(Expr::Value(Value::SingleQuotedString("%a".to_string()))),
+            Expr::IsNull(Box::new(Expr::Like {
+                negated,
+                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                pattern: Box::new(Expr::Value(Value::SingleQuotedString("%a".to_string()))),
+                escape_char: None,
             })),
             select.selection.unwrap()
         );
@@ -752,6 +1304,132 @@ fn parse_like() {
     chk(true);
 }
 
+#[test]
+fn parse_like_escape_char() {
+    let select = verified_only_select("SELECT * FROM customers WHERE name LIKE 'a%'");
+    assert_eq!(
+        Expr::Like {
+            negated: false,
+            expr: Box::new(Expr::Identifier(Ident::new("name"))),
+            pattern: Box::new(Expr::Value(Value::SingleQuotedString("a%".to_string()))),
+            escape_char: None,
+        },
+        select.selection.unwrap()
+    );
+
+    let select = verified_only_select(r"SELECT * FROM customers WHERE name LIKE 'a%' ESCAPE '\'");
+    assert_eq!(
+        Expr::Like {
+            negated: false,
+            expr: Box::new(Expr::Identifier(Ident::new("name"))),
+            pattern: Box::new(Expr::Value(Value::SingleQuotedString("a%".to_string()))),
+            escape_char: Some('\\'),
+        },
+        select.selection.unwrap()
+    );
+
+    verified_only_select("SELECT * FROM customers WHERE name NOT ILIKE 'a!%' ESCAPE '!'");
+
+    let res = parse_sql_statements("SELECT * FROM customers WHERE name LIKE 'a%' ESCAPE 'ab'");
+    assert_eq!(
+        ParserError::ParserError("ESCAPE must be a single character, found: 'ab'".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_like_pattern_expression() {
+    assert_eq!(
+        Expr::Like {
+            negated: false,
+            expr: Box::new(Expr::Identifier(Ident::new("name"))),
+            pattern: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Value(Value::SingleQuotedString("%".to_string()))),
+                op: BinaryOperator::StringConcat,
+                right: Box::new(Expr::Identifier(Ident::new("suffix"))),
+            }),
+            escape_char: None,
+        },
+        verified_expr("name LIKE '%' || suffix")
+    );
+
+    // the pattern stops at operators binding looser than LIKE
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(Expr::Like {
+                negated: true,
+                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                pattern: Box::new(Expr::Identifier(Ident::new("pat"))),
+                escape_char: None,
+            }),
+            op: BinaryOperator::And,
+            right: Box::new(Expr::Identifier(Ident::new("active"))),
+        },
+        verified_expr("name NOT LIKE pat AND active")
+    );
+}
+
+#[test]
+fn parse_similar_to() {
+    let select = verified_only_select("SELECT * FROM customers WHERE name SIMILAR TO 'x'");
+    assert_eq!(
+        Expr::SimilarTo {
+            negated: false,
+            expr: Box::new(Expr::Identifier(Ident::new("name"))),
+            pattern: Box::new(Expr::Value(Value::SingleQuotedString("x".to_string()))),
+            escape_char: None,
+        },
+        select.selection.unwrap()
+    );
+
+    let select =
+        verified_only_select("SELECT * FROM customers WHERE name NOT SIMILAR TO 'x/%' ESCAPE '/'");
+    assert_eq!(
+        Expr::SimilarTo {
+            negated: true,
+            expr: Box::new(Expr::Identifier(Ident::new("name"))),
+            pattern: Box::new(Expr::Value(Value::SingleQuotedString("x/%".to_string()))),
+            escape_char: Some('/'),
+        },
+        select.selection.unwrap()
+    );
+
+    // SIMILAR TO binds like LIKE, tighter than IS
+    let select = verified_only_select("SELECT * FROM customers WHERE name SIMILAR TO 'x' IS NULL");
+    assert!(matches!(select.selection, Some(Expr::IsNull(_))));
+
+    // Without TO, `similar` is just an alias
+    let select = verified_only_select("SELECT a AS similar FROM t");
+    assert_eq!(
+        &SelectItem::ExprWithAlias {
+            expr: Expr::Identifier(Ident::new("a")),
+            alias: Ident::new("similar"),
+        },
+        only(&select.projection)
+    );
+    one_statement_parses_to("SELECT a similar FROM t", "SELECT a AS similar FROM t");
+
+    assert_eq!(
+        ParserError::ParserError("Expected TO after SIMILAR, found: 'x'".to_string()),
+        parse_sql_statements("SELECT * FROM customers WHERE name NOT SIMILAR 'x'").unwrap_err()
+    );
+}
+
+#[test]
+fn parse_like_escape_round_trip() {
+    for sql in &[
+        "name LIKE 'a%'",
+        "name NOT LIKE 'a%' ESCAPE '/'",
+        "name ILIKE 'a%' ESCAPE '/'",
+        "name NOT ILIKE 'a%'",
+        "name SIMILAR TO 'x'",
+        "name NOT SIMILAR TO 'x' ESCAPE '/'",
+        "name LIKE 'a''%' ESCAPE ''''",
+    ] {
+        verified_expr(sql);
+    }
+}
+
 #[test]
 fn parse_ilike() {
     fn chk(negated: bool) {
@@ -761,14 +1439,11 @@ fn parse_ilike() {
         );
         let select = verified_only_select(sql);
         assert_eq!(
-            Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("name"))),
-                op: if negated {
-                    BinaryOperator::NotILike
-                } else {
-                    BinaryOperator::ILike
-                },
-                right: Box::new(Expr::Value(Value::SingleQuotedString("%a".to_string()))),
+            Expr::ILike {
+                negated,
+                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                pattern: Box::new(Expr::Value(Value::SingleQuotedString("%a".to_string()))),
+                escape_char: None,
             },
             select.selection.unwrap()
         );
@@ -781,14 +1456,11 @@ fn parse_ilike() {
         );
         let select = verified_only_select(sql);
         assert_eq!(
-            Expr::IsNull(Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("name"))),
-                op: if negated {
-                    BinaryOperator::NotILike
-                } else {
-                    BinaryOperator::ILike
-                },
-                right: Box::new(Expr::Value(Value::SingleQuotedString("%a".to_string()))),
+            Expr::IsNull(Box::new(Expr::ILike {
+                negated,
+                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                pattern: Box::new(Expr::Value(Value::SingleQuotedString("%a".to_string()))),
+                escape_char: None,
             })),
             select.selection.unwrap()
         );
@@ -833,6 +1505,43 @@ fn parse_in_subquery() {
         },
         select.selection.unwrap()
     );
+
+    let sql = "SELECT * FROM users WHERE id NOT IN (SELECT id FROM banned)";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        Expr::InSubquery {
+            expr: Box::new(Expr::Identifier(Ident::new("id"))),
+            subquery: Box::new(verified_query("SELECT id FROM banned")),
+            negated: true,
+        },
+        select.selection.unwrap()
+    );
+}
+
+#[test]
+fn parse_in_subquery_or_list() {
+    for sql in &[
+        "SELECT * FROM t WHERE a IN (VALUES (1), (2))",
+        "SELECT * FROM t WHERE a NOT IN (WITH x AS (SELECT 1) SELECT * FROM x)",
+    ] {
+        let select = verified_only_select(sql);
+        assert!(
+            matches!(select.selection, Some(Expr::InSubquery { .. })),
+            "{}",
+            sql
+        );
+    }
+
+    // A parenthesized query is just the first element of an expression list
+    let select = verified_only_select("SELECT * FROM t WHERE a IN ((SELECT 1), 2)");
+    match select.selection {
+        Some(Expr::InList { list, .. }) => {
+            assert_eq!(2, list.len());
+            assert!(matches!(list[0], Expr::Subquery(_)));
+            assert_eq!(Expr::Value(number("2")), list[1]);
+        }
+        _ => unreachable!(),
+    }
 }
 
 #[test]
@@ -893,6 +1602,58 @@ fn parse_between() {
     chk(true);
 }
 
+#[test]
+fn predicate_display_round_trip() {
+    for sql in &[
+        "a BETWEEN 1 AND 2",
+        "a NOT BETWEEN 1 AND 2",
+        "a IN (1)",
+        "a NOT IN (1)",
+        "a IN ('x', 'y', 'z')",
+        "a NOT IN ('x', 'y', 'z')",
+        "a IN (SELECT b FROM t)",
+        "a NOT IN (SELECT b FROM t)",
+        "a IS NULL",
+        "a IS NOT NULL",
+        "a + 1 NOT BETWEEN b AND c IS NOT NULL",
+    ] {
+        verified_expr(sql);
+    }
+    assert_eq!(
+        Expr::IsNotNull(Box::new(Expr::InList {
+            expr: Box::new(Expr::Identifier(Ident::new("a"))),
+            list: vec![Expr::Value(number("1")), Expr::Value(number("2"))],
+            negated: true,
+        })),
+        verified_expr("a NOT IN (1, 2) IS NOT NULL")
+    );
+}
+
+#[test]
+fn display_large_expression() {
+    // a balanced tree over 512 operands, so that its depth stays small
+    fn balanced(lo: usize, hi: usize) -> String {
+        if hi - lo == 1 {
+            lo.to_string()
+        } else {
+            let mid = (lo + hi) / 2;
+            format!("({}) + ({})", balanced(lo, mid), balanced(mid, hi))
+        }
+    }
+    fn count_nodes(expr: &Expr) -> usize {
+        match expr {
+            Expr::BinaryOp { left, right, .. } => 1 + count_nodes(left) + count_nodes(right),
+            Expr::Nested(inner) => 1 + count_nodes(inner),
+            _ => 1,
+        }
+    }
+
+    let sql = balanced(0, 512);
+    let expr = verified_expr(&sql);
+    assert!(count_nodes(&expr) > 1000);
+    assert_eq!(sql, expr.to_string());
+}
+
 #[test]
 fn parse_between_with_expr() {
     use self::BinaryOperator::*;
@@ -951,16 +1712,19 @@ fn parse_select_order_by() {
                     expr: Expr::Identifier(Ident::new("lname")),
                     asc: Some(true),
                     nulls_first: None,
+                    using: None,
                 },
                 OrderByExpr {
                     expr: Expr::Identifier(Ident::new("fname")),
                     asc: Some(false),
                     nulls_first: None,
+                    using: None,
                 },
                 OrderByExpr {
                     expr: Expr::Identifier(Ident::new("id")),
                     asc: None,
                     nulls_first: None,
+                    using: None,
                 },
             ],
             select.order_by
@@ -983,11 +1747,13 @@ fn parse_select_order_by_limit() {
                 expr: Expr::Identifier(Ident::new("lname")),
                 asc: Some(true),
                 nulls_first: None,
+                using: None,
             },
             OrderByExpr {
                 expr: Expr::Identifier(Ident::new("fname")),
                 asc: Some(false),
                 nulls_first: None,
+                using: None,
             },
         ],
         select.order_by
@@ -1006,11 +1772,13 @@ fn parse_select_order_by_nulls_order() {
                 expr: Expr::Identifier(Ident::new("lname")),
                 asc: Some(true),
                 nulls_first: Some(true),
+                using: None,
             },
             OrderByExpr {
                 expr: Expr::Identifier(Ident::new("fname")),
                 asc: Some(false),
                 nulls_first: Some(false),
+                using: None,
             },
         ],
         select.order_by
@@ -1018,6 +1786,29 @@ fn parse_select_order_by_nulls_order() {
     assert_eq!(Some(Expr::Value(number("2"))), select.limit);
 }
 
+#[test]
+fn order_by_direction_and_nulls_display() {
+    for (asc, direction) in &[(None, ""), (Some(true), " ASC"), (Some(false), " DESC")] {
+        for (nulls_first, nulls) in &[
+            (None, ""),
+            (Some(true), " NULLS FIRST"),
+            (Some(false), " NULLS LAST"),
+        ] {
+            let sql = format!("SELECT x FROM t ORDER BY x{}{}", direction, nulls);
+            let query = verified_query(&sql);
+            assert_eq!(
+                vec![OrderByExpr {
+                    expr: Expr::Identifier(Ident::new("x")),
+                    asc: *asc,
+                    nulls_first: *nulls_first,
+                    using: None,
+                }],
+                query.order_by
+            );
+        }
+    }
+}
+
 #[test]
 fn parse_select_group_by() {
     let sql = "SELECT id, fname, lname FROM customer GROUP BY lname, fname";
@@ -1040,9 +1831,12 @@ fn parse_select_having() {
             left: Box::new(Expr::Function(Function {
                 name: ObjectName(vec![Ident::new("COUNT")]),
                 params: vec![],
-                args: vec![FunctionArg::Unnamed(Expr::Wildcard)],
+                args: vec![FunctionArg::Wildcard],
                 over: None,
                 distinct: false,
+                null_treatment: None,
+                order_by: vec![],
+                filter: None,
             })),
             op: BinaryOperator::Gt,
             right: Box::new(Expr::Value(number("1")))
@@ -1061,6 +1855,32 @@ fn parse_limit_accepts_all() {
         "SELECT id, fname, lname FROM customer WHERE id = 1 LIMIT ALL",
         "SELECT id, fname, lname FROM customer WHERE id = 1",
     );
+
+    let query = match one_statement_parses_to(
+        "SELECT id FROM customer LIMIT ALL OFFSET 5",
+        "SELECT id FROM customer OFFSET 5",
+    ) {
+        Statement::Query(query) => query,
+        _ => unreachable!(),
+    };
+    assert_eq!(None, query.limit);
+    assert_eq!(
+        Some(Offset {
+            value: Expr::Value(number("5")),
+            rows: OffsetRows::None,
+        }),
+        query.offset
+    );
+}
+
+#[test]
+fn parse_typed_null() {
+    let typed_null = |sql: &str| verified_expr(sql).is_typed_null().cloned();
+    assert_eq!(Some(DataType::Int), typed_null("CAST(NULL AS INT)"));
+    assert_eq!(Some(DataType::Text), typed_null("CAST(NULL AS TEXT)"));
+    assert_eq!(None, typed_null("CAST(1 AS INT)"));
+    assert_eq!(None, typed_null("CAST('NULL' AS TEXT)"));
+    assert_eq!(None, typed_null("NULL"));
 }
 
 #[test]
@@ -1069,8 +1889,10 @@ fn parse_cast() {
     let select = verified_only_select(sql);
     assert_eq!(
         &Expr::Cast {
+            kind: CastKind::Cast,
             expr: Box::new(Expr::Identifier(Ident::new("id"))),
-            data_type: DataType::BigInt
+            data_type: DataType::BigInt,
+            format: None,
         },
         expr_from_projection(only(&select.projection))
     );
@@ -1079,8 +1901,10 @@ fn parse_cast() {
     let select = verified_only_select(sql);
     assert_eq!(
         &Expr::Cast {
+            kind: CastKind::Cast,
             expr: Box::new(Expr::Identifier(Ident::new("id"))),
-            data_type: DataType::TinyInt
+            data_type: DataType::TinyInt,
+            format: None,
         },
         expr_from_projection(only(&select.projection))
     );
@@ -1113,14 +1937,60 @@ fn parse_cast() {
     );
 }
 
+#[test]
+fn parse_cast_collection_types() {
+    // the ANSI `ARRAY` suffix is equivalent to PostgreSQL's `[]`, which MsSql
+    // would read as a delimited identifier
+    let dialects = TestedDialects {
+        dialects: vec![
+            Box::new(GenericDialect {}),
+            Box::new(PostgreSqlDialect {}),
+            Box::new(HiveDialect {}),
+            Box::new(MySqlDialect {}),
+        ],
+    };
+    let select = dialects.verified_only_select("SELECT CAST(x AS INT[]) FROM t");
+    assert_eq!(
+        &Expr::Cast {
+            kind: CastKind::Cast,
+            expr: Box::new(Expr::Identifier(Ident::new("x"))),
+            data_type: DataType::Array(Box::new(DataType::Int)),
+            format: None,
+        },
+        expr_from_projection(only(&select.projection))
+    );
+    dialects.one_statement_parses_to(
+        "SELECT CAST(x AS INT ARRAY) FROM t",
+        "SELECT CAST(x AS INT[]) FROM t",
+    );
+    dialects.one_statement_parses_to(
+        "SELECT CAST(x AS VARCHAR(10) ARRAY ARRAY) FROM t",
+        "SELECT CAST(x AS CHARACTER VARYING(10)[][]) FROM t",
+    );
+
+    let select = verified_only_select("SELECT CAST(x AS INT MULTISET) FROM t");
+    assert_eq!(
+        &Expr::Cast {
+            kind: CastKind::Cast,
+            expr: Box::new(Expr::Identifier(Ident::new("x"))),
+            data_type: DataType::Multiset(Box::new(DataType::Int)),
+            format: None,
+        },
+        expr_from_projection(only(&select.projection))
+    );
+    dialects.verified_stmt("SELECT CAST(x AS INT[] MULTISET) FROM t");
+}
+
 #[test]
 fn parse_try_cast() {
     let sql = "SELECT TRY_CAST(id AS BIGINT) FROM customer";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Expr::TryCast {
+        &Expr::Cast {
+            kind: CastKind::TryCast,
             expr: Box::new(Expr::Identifier(Ident::new("id"))),
-            data_type: DataType::BigInt
+            data_type: DataType::BigInt,
+            format: None,
         },
         expr_from_projection(only(&select.projection))
     );
@@ -1175,6 +2045,7 @@ fn parse_listagg() {
                WITHIN GROUP (ORDER BY id, username)";
     let select = verified_only_select(sql);
 
+    verified_stmt("SELECT LISTAGG(name, ', ')");
     verified_stmt("SELECT LISTAGG(sellerid) WITHIN GROUP (ORDER BY dateid)");
     verified_stmt("SELECT LISTAGG(dateid)");
     verified_stmt("SELECT LISTAGG(DISTINCT dateid)");
@@ -1197,6 +2068,7 @@ fn parse_listagg() {
             }),
             asc: None,
             nulls_first: None,
+            using: None,
         },
         OrderByExpr {
             expr: Expr::Identifier(Ident {
@@ -1205,6 +2077,7 @@ fn parse_listagg() {
             }),
             asc: None,
             nulls_first: None,
+            using: None,
         },
     ];
     assert_eq!(
@@ -1261,6 +2134,7 @@ fn parse_create_table() {
                         name: "name".into(),
                         data_type: DataType::Varchar(Some(100)),
                         collation: None,
+                        leading_comments: vec![],
                         options: vec![ColumnOptionDef {
                             name: None,
                             option: ColumnOption::NotNull
@@ -1270,6 +2144,7 @@ fn parse_create_table() {
                         name: "lat".into(),
                         data_type: DataType::Double,
                         collation: None,
+                        leading_comments: vec![],
                         options: vec![ColumnOptionDef {
                             name: None,
                             option: ColumnOption::Null
@@ -1279,12 +2154,14 @@ fn parse_create_table() {
                         name: "lng".into(),
                         data_type: DataType::Double,
                         collation: None,
+                        leading_comments: vec![],
                         options: vec![],
                     },
                     ColumnDef {
                         name: "constrained".into(),
                         data_type: DataType::Int,
                         collation: None,
+                        leading_comments: vec![],
                         options: vec![
                             ColumnOptionDef {
                                 name: None,
@@ -1312,6 +2189,7 @@ fn parse_create_table() {
                         name: "ref".into(),
                         data_type: DataType::Int,
                         collation: None,
+                        leading_comments: vec![],
                         options: vec![ColumnOptionDef {
                             name: None,
                             option: ColumnOption::ForeignKey {
@@ -1319,6 +2197,7 @@ fn parse_create_table() {
                                 referred_columns: vec!["a".into(), "b".into(),],
                                 on_delete: None,
                                 on_update: None,
+                                characteristics: None,
                             }
                         }]
                     },
@@ -1326,6 +2205,7 @@ fn parse_create_table() {
                         name: "ref2".into(),
                         data_type: DataType::Int,
                         collation: None,
+                        leading_comments: vec![],
                         options: vec![ColumnOptionDef {
                             name: None,
                             option: ColumnOption::ForeignKey {
@@ -1333,6 +2213,7 @@ fn parse_create_table() {
                                 referred_columns: vec![],
                                 on_delete: Some(ReferentialAction::Cascade),
                                 on_update: Some(ReferentialAction::NoAction),
+                                characteristics: None,
                             }
                         },]
                     }
@@ -1501,6 +2382,68 @@ fn parse_create_table_with_on_delete_on_update_2in_any_order() -> Result<(), Par
     Ok(())
 }
 
+#[test]
+fn parse_table_foreign_key_referential_actions() {
+    let actions = [
+        ("RESTRICT", ReferentialAction::Restrict),
+        ("CASCADE", ReferentialAction::Cascade),
+        ("SET NULL", ReferentialAction::SetNull),
+        ("SET DEFAULT", ReferentialAction::SetDefault),
+        ("NO ACTION", ReferentialAction::NoAction),
+    ];
+    for (text, action) in actions.iter() {
+        let sql = format!(
+            "CREATE TABLE t (a INT, FOREIGN KEY (a) REFERENCES u(id) ON DELETE {})",
+            text
+        );
+        match verified_stmt(&sql) {
+            Statement::CreateTable { constraints, .. } => match &constraints[..] {
+                [TableConstraint::ForeignKey {
+                    on_delete,
+                    on_update,
+                    ..
+                }] => {
+                    assert_eq!(Some(action), on_delete.as_ref());
+                    assert_eq!(None, *on_update);
+                }
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    let sql = "CREATE TABLE t (a INT, CONSTRAINT fk FOREIGN KEY (a) REFERENCES u(id) \
+               ON DELETE CASCADE ON UPDATE SET NULL)";
+    match verified_stmt(sql) {
+        Statement::CreateTable { constraints, .. } => {
+            assert_eq!(
+                vec![TableConstraint::ForeignKey {
+                    name: Some(Ident::new("fk")),
+                    columns: vec![Ident::new("a")],
+                    foreign_table: ObjectName(vec![Ident::new("u")]),
+                    referred_columns: vec![Ident::new("id")],
+                    on_delete: Some(ReferentialAction::Cascade),
+                    on_update: Some(ReferentialAction::SetNull),
+                    characteristics: None,
+                }],
+                constraints
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    // the triggers may be given in either order, but are displayed ON DELETE first
+    one_statement_parses_to(
+        "ALTER TABLE t ADD FOREIGN KEY (a) REFERENCES u(id) ON UPDATE RESTRICT ON DELETE NO ACTION",
+        "ALTER TABLE t ADD FOREIGN KEY (a) REFERENCES u(id) ON DELETE NO ACTION ON UPDATE RESTRICT",
+    );
+
+    let res = parse_sql_statements(
+        "CREATE TABLE t (a INT, FOREIGN KEY (a) REFERENCES u(id) ON DELETE CASCADE ON DELETE CASCADE)",
+    );
+    assert!(res.is_err());
+}
+
 #[test]
 fn parse_create_table_with_options() {
     let sql = "CREATE TABLE t (c INT) WITH (foo = 'bar', a = 123)";
@@ -1565,6 +2508,7 @@ fn parse_create_external_table() {
                         name: "name".into(),
                         data_type: DataType::Varchar(Some(100)),
                         collation: None,
+                        leading_comments: vec![],
                         options: vec![ColumnOptionDef {
                             name: None,
                             option: ColumnOption::NotNull
@@ -1574,6 +2518,7 @@ fn parse_create_external_table() {
                         name: "lat".into(),
                         data_type: DataType::Double,
                         collation: None,
+                        leading_comments: vec![],
                         options: vec![ColumnOptionDef {
                             name: None,
                             option: ColumnOption::Null
@@ -1583,6 +2528,7 @@ fn parse_create_external_table() {
                         name: "lng".into(),
                         data_type: DataType::Double,
                         collation: None,
+                        leading_comments: vec![],
                         options: vec![],
                     },
                 ]
@@ -1633,6 +2579,7 @@ fn parse_create_or_replace_external_table() {
                     name: "name".into(),
                     data_type: DataType::Varchar(Some(100)),
                     collation: None,
+                    leading_comments: vec![],
                     options: vec![ColumnOptionDef {
                         name: None,
                         option: ColumnOption::NotNull
@@ -1674,11 +2621,11 @@ fn parse_create_external_table_lowercase() {
 #[test]
 fn parse_alter_table() {
     let add_column = "ALTER TABLE tab ADD COLUMN foo TEXT;";
-    match one_statement_parses_to(add_column, "ALTER TABLE tab ADD COLUMN foo TEXT") {
-        Statement::AlterTable {
-            name,
-            operation: AlterTableOperation::AddColumn { column_def },
-        } => {
+    match alter_table_op_with_name(one_statement_parses_to(
+        add_column,
+        "ALTER TABLE tab ADD COLUMN foo TEXT",
+    )) {
+        (name, AlterTableOperation::AddColumn { column_def }) => {
             assert_eq!("tab", name.to_string());
             assert_eq!("foo", column_def.name.to_string());
             assert_eq!("TEXT", column_def.data_type.to_string());
@@ -1687,11 +2634,8 @@ fn parse_alter_table() {
     };
 
     let rename_table = "ALTER TABLE tab RENAME TO new_tab";
-    match verified_stmt(rename_table) {
-        Statement::AlterTable {
-            name,
-            operation: AlterTableOperation::RenameTable { table_name },
-        } => {
+    match alter_table_op_with_name(verified_stmt(rename_table)) {
+        (name, AlterTableOperation::RenameTable { table_name }) => {
             assert_eq!("tab", name.to_string());
             assert_eq!("new_tab", table_name.to_string())
         }
@@ -1699,89 +2643,376 @@ fn parse_alter_table() {
     };
 
     let rename_column = "ALTER TABLE tab RENAME COLUMN foo TO new_foo";
-    match verified_stmt(rename_column) {
-        Statement::AlterTable {
+    match alter_table_op_with_name(verified_stmt(rename_column)) {
+        (
             name,
-            operation:
-                AlterTableOperation::RenameColumn {
-                    old_column_name,
-                    new_column_name,
-                },
-        } => {
+            AlterTableOperation::RenameColumn {
+                old_column_name,
+                new_column_name,
+            },
+        ) => {
             assert_eq!("tab", name.to_string());
             assert_eq!(old_column_name.to_string(), "foo");
             assert_eq!(new_column_name.to_string(), "new_foo");
         }
         _ => unreachable!(),
     }
-}
 
-#[test]
-fn parse_alter_table_constraints() {
-    check_one("CONSTRAINT address_pkey PRIMARY KEY (address_id)");
-    check_one("CONSTRAINT uk_task UNIQUE (report_date, task_id)");
-    check_one(
-        "CONSTRAINT customer_address_id_fkey FOREIGN KEY (address_id) \
-         REFERENCES public.address(address_id)",
+    // COLUMN is optional after RENAME
+    one_statement_parses_to(
+        "ALTER TABLE tab RENAME foo TO new_foo",
+        "ALTER TABLE tab RENAME COLUMN foo TO new_foo",
     );
-    check_one("CONSTRAINT ck CHECK (rtrim(ltrim(REF_CODE)) <> '')");
-
-    check_one("PRIMARY KEY (foo, bar)");
-    check_one("UNIQUE (id)");
-    check_one("FOREIGN KEY (foo, bar) REFERENCES AnotherTable(foo, bar)");
-    check_one("CHECK (end_date > start_date OR end_date IS NULL)");
-
-    fn check_one(constraint_text: &str) {
-        match verified_stmt(&format!("ALTER TABLE tab ADD {}", constraint_text)) {
-            Statement::AlterTable {
-                name,
-                operation: AlterTableOperation::AddConstraint(constraint),
-            } => {
-                assert_eq!("tab", name.to_string());
-                assert_eq!(constraint_text, constraint.to_string());
-            }
-            _ => unreachable!(),
-        }
-        verified_stmt(&format!("CREATE TABLE foo (id INT, {})", constraint_text));
-    }
+    verified_stmt(r#"ALTER TABLE tab RENAME TO "schema"."new tab""#);
 }
 
 #[test]
-fn parse_alter_table_drop_column() {
-    check_one("DROP COLUMN IF EXISTS is_active CASCADE");
-    one_statement_parses_to(
-        "ALTER TABLE tab DROP IF EXISTS is_active CASCADE",
-        "ALTER TABLE tab DROP COLUMN IF EXISTS is_active CASCADE",
+fn parse_constraint_name_must_not_be_reserved() {
+    let res = parse_sql_statements("CREATE TABLE t (a INT, CONSTRAINT where CHECK (a > 0))");
+    assert_eq!(
+        ParserError::ParserError("Expected identifier, found: where".to_string()),
+        res.unwrap_err()
     );
-    one_statement_parses_to(
-        "ALTER TABLE tab DROP is_active CASCADE",
-        "ALTER TABLE tab DROP COLUMN is_active CASCADE",
+
+    let res = parse_sql_statements("CREATE TABLE t (a INT CONSTRAINT from NOT NULL)");
+    assert_eq!(
+        ParserError::ParserError("Expected identifier, found: from".to_string()),
+        res.unwrap_err()
     );
 
-    fn check_one(constraint_text: &str) {
-        match verified_stmt(&format!("ALTER TABLE tab {}", constraint_text)) {
-            Statement::AlterTable {
-                name,
-                operation:
-                    AlterTableOperation::DropColumn {
-                        column_name,
-                        if_exists,
-                        cascade,
-                    },
-            } => {
-                assert_eq!("tab", name.to_string());
-                assert_eq!("is_active", column_name.to_string());
-                assert!(if_exists);
-                assert!(cascade);
-            }
-            _ => unreachable!(),
-        }
-    }
+    verified_stmt("CREATE TABLE t (a INT, CONSTRAINT \"where\" CHECK (a > 0))");
 }
 
 #[test]
-fn parse_bad_constraint() {
-    let res = parse_sql_statements("ALTER TABLE tab ADD");
+fn parse_deferrable_constraints() {
+    let sql = "CREATE TABLE t (\
+               a INT REFERENCES u (id) ON DELETE CASCADE DEFERRABLE INITIALLY DEFERRED NOT NULL, \
+               CONSTRAINT fk FOREIGN KEY (a) REFERENCES u(id) DEFERRABLE INITIALLY DEFERRED, \
+               UNIQUE (a) NOT DEFERRABLE)";
+    match verified_stmt(sql) {
+        Statement::CreateTable {
+            columns,
+            constraints,
+            ..
+        } => {
+            let deferred = Some(ConstraintCharacteristics {
+                deferrable: Some(true),
+                initially: Some(DeferrableInitial::Deferred),
+            });
+            assert_eq!(
+                vec![
+                    ColumnOptionDef {
+                        name: None,
+                        option: ColumnOption::ForeignKey {
+                            foreign_table: ObjectName(vec![Ident::new("u")]),
+                            referred_columns: vec![Ident::new("id")],
+                            on_delete: Some(ReferentialAction::Cascade),
+                            on_update: None,
+                            characteristics: deferred.clone(),
+                        },
+                    },
+                    ColumnOptionDef {
+                        name: None,
+                        option: ColumnOption::NotNull,
+                    },
+                ],
+                columns[0].options
+            );
+            assert_eq!(
+                vec![
+                    TableConstraint::ForeignKey {
+                        name: Some(Ident::new("fk")),
+                        columns: vec![Ident::new("a")],
+                        foreign_table: ObjectName(vec![Ident::new("u")]),
+                        referred_columns: vec![Ident::new("id")],
+                        on_delete: None,
+                        on_update: None,
+                        characteristics: deferred,
+                    },
+                    TableConstraint::Unique {
+                        name: None,
+                        columns: vec![Ident::new("a")],
+                        is_primary: false,
+                        characteristics: Some(ConstraintCharacteristics {
+                            deferrable: Some(false),
+                            initially: None,
+                        }),
+                    },
+                ],
+                constraints
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    // the clauses may come in either order, but are displayed DEFERRABLE first
+    one_statement_parses_to(
+        "CREATE TABLE t (a INT, PRIMARY KEY (a) INITIALLY IMMEDIATE DEFERRABLE)",
+        "CREATE TABLE t (a INT, PRIMARY KEY (a) DEFERRABLE INITIALLY IMMEDIATE)",
+    );
+
+    let res = parse_sql_statements("CREATE TABLE t (a INT, UNIQUE (a) INITIALLY LATER)");
+    assert_eq!(
+        ParserError::ParserError("Expected DEFERRED or IMMEDIATE, found: LATER".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_named_constraints() {
+    let sql = "CREATE TABLE t (\
+               id INT CONSTRAINT id_positive CHECK (id > 0), \
+               ref INT, \
+               CONSTRAINT pk PRIMARY KEY (id), \
+               CONSTRAINT fk FOREIGN KEY (ref) REFERENCES u(id), \
+               CONSTRAINT ck CHECK (ref <> id))";
+    match verified_stmt(sql) {
+        Statement::CreateTable {
+            columns,
+            constraints,
+            ..
+        } => {
+            assert_eq!(Some(Ident::new("id_positive")), columns[0].options[0].name);
+            let names: Vec<_> = constraints
+                .iter()
+                .map(|c| match c {
+                    TableConstraint::Unique {
+                        name,
+                        is_primary: true,
+                        ..
+                    } => ("PRIMARY KEY", name.clone()),
+                    TableConstraint::ForeignKey { name, .. } => ("FOREIGN KEY", name.clone()),
+                    TableConstraint::Check { name, .. } => ("CHECK", name.clone()),
+                    _ => unreachable!(),
+                })
+                .collect();
+            assert_eq!(
+                vec![
+                    ("PRIMARY KEY", Some(Ident::new("pk"))),
+                    ("FOREIGN KEY", Some(Ident::new("fk"))),
+                    ("CHECK", Some(Ident::new("ck"))),
+                ],
+                names
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_alter_table_constraints() {
+    check_one("CONSTRAINT address_pkey PRIMARY KEY (address_id)");
+    check_one("CONSTRAINT uk_task UNIQUE (report_date, task_id)");
+    check_one(
+        "CONSTRAINT customer_address_id_fkey FOREIGN KEY (address_id) \
+         REFERENCES public.address(address_id)",
+    );
+    check_one("CONSTRAINT ck CHECK (rtrim(ltrim(REF_CODE)) <> '')");
+
+    check_one("PRIMARY KEY (foo, bar)");
+    check_one("UNIQUE (id)");
+    check_one("FOREIGN KEY (foo, bar) REFERENCES AnotherTable(foo, bar)");
+    check_one("CHECK (end_date > start_date OR end_date IS NULL)");
+
+    fn check_one(constraint_text: &str) {
+        match alter_table_op_with_name(verified_stmt(&format!(
+            "ALTER TABLE tab ADD {}",
+            constraint_text
+        ))) {
+            (name, AlterTableOperation::AddConstraint(constraint)) => {
+                assert_eq!("tab", name.to_string());
+                assert_eq!(constraint_text, constraint.to_string());
+            }
+            _ => unreachable!(),
+        }
+        verified_stmt(&format!("CREATE TABLE foo (id INT, {})", constraint_text));
+    }
+}
+
+#[test]
+fn parse_alter_table_add_constraint() {
+    match alter_table_op_with_name(verified_stmt(
+        "ALTER TABLE t ADD CONSTRAINT fk FOREIGN KEY (a) REFERENCES u(id)",
+    )) {
+        (name, AlterTableOperation::AddConstraint(constraint)) => {
+            assert_eq!("t", name.to_string());
+            assert_eq!(
+                TableConstraint::ForeignKey {
+                    name: Some(Ident::new("fk")),
+                    columns: vec![Ident::new("a")],
+                    foreign_table: ObjectName(vec![Ident::new("u")]),
+                    referred_columns: vec![Ident::new("id")],
+                    on_delete: None,
+                    on_update: None,
+                    characteristics: None,
+                },
+                constraint
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    match alter_table_op_with_name(verified_stmt(
+        "ALTER TABLE t ADD CONSTRAINT uq UNIQUE (a, b)",
+    )) {
+        (_, AlterTableOperation::AddConstraint(constraint)) => {
+            assert_eq!(
+                TableConstraint::Unique {
+                    name: Some(Ident::new("uq")),
+                    columns: vec![Ident::new("a"), Ident::new("b")],
+                    is_primary: false,
+                    characteristics: None,
+                },
+                constraint
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_alter_table_drop_column() {
+    check_one("DROP COLUMN IF EXISTS is_active CASCADE");
+    one_statement_parses_to(
+        "ALTER TABLE tab DROP IF EXISTS is_active CASCADE",
+        "ALTER TABLE tab DROP COLUMN IF EXISTS is_active CASCADE",
+    );
+    one_statement_parses_to(
+        "ALTER TABLE tab DROP is_active CASCADE",
+        "ALTER TABLE tab DROP COLUMN is_active CASCADE",
+    );
+
+    match alter_table_op_with_name(verified_stmt("ALTER TABLE tab DROP COLUMN is_active")) {
+        (
+            _,
+            AlterTableOperation::DropColumn {
+                column_name,
+                if_exists,
+                cascade,
+            },
+        ) => {
+            assert_eq!("is_active", column_name.to_string());
+            assert!(!if_exists);
+            assert!(!cascade);
+        }
+        _ => unreachable!(),
+    }
+
+    fn check_one(constraint_text: &str) {
+        match alter_table_op_with_name(verified_stmt(&format!(
+            "ALTER TABLE tab {}",
+            constraint_text
+        ))) {
+            (
+                name,
+                AlterTableOperation::DropColumn {
+                    column_name,
+                    if_exists,
+                    cascade,
+                },
+            ) => {
+                assert_eq!("tab", name.to_string());
+                assert_eq!("is_active", column_name.to_string());
+                assert!(if_exists);
+                assert!(cascade);
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[test]
+fn parse_alter_table_alter_column() {
+    let alter_stmt = "ALTER TABLE tab";
+    match alter_table_op_with_name(verified_stmt(&format!(
+        "{} ALTER COLUMN is_active SET NOT NULL",
+        alter_stmt
+    ))) {
+        (name, AlterTableOperation::AlterColumn { column_name, op }) => {
+            assert_eq!("tab", name.to_string());
+            assert_eq!("is_active", column_name.to_string());
+            assert_eq!(op, AlterColumnOperation::SetNotNull);
+        }
+        _ => unreachable!(),
+    }
+
+    one_statement_parses_to(
+        "ALTER TABLE tab ALTER is_active DROP NOT NULL",
+        "ALTER TABLE tab ALTER COLUMN is_active DROP NOT NULL",
+    );
+
+    match alter_table_op_with_name(verified_stmt(&format!(
+        "{} ALTER COLUMN is_active SET DEFAULT 0",
+        alter_stmt
+    ))) {
+        (_, AlterTableOperation::AlterColumn { column_name, op }) => {
+            assert_eq!("is_active", column_name.to_string());
+            assert_eq!(
+                op,
+                AlterColumnOperation::SetDefault {
+                    value: Expr::Value(number("0"))
+                }
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    match alter_table_op_with_name(verified_stmt(&format!(
+        "{} ALTER COLUMN is_active DROP DEFAULT",
+        alter_stmt
+    ))) {
+        (_, AlterTableOperation::AlterColumn { op, .. }) => {
+            assert_eq!(op, AlterColumnOperation::DropDefault)
+        }
+        _ => unreachable!(),
+    }
+
+    match alter_table_op_with_name(one_statement_parses_to(
+        "ALTER TABLE tab ALTER COLUMN is_active TYPE bigint",
+        "ALTER TABLE tab ALTER COLUMN is_active SET DATA TYPE BIGINT",
+    )) {
+        (_, AlterTableOperation::AlterColumn { op, .. }) => assert_eq!(
+            op,
+            AlterColumnOperation::SetDataType {
+                data_type: DataType::BigInt
+            }
+        ),
+        _ => unreachable!(),
+    }
+
+    let res = parse_sql_statements(&format!(
+        "{} ALTER COLUMN is_active DROP CONSTRAINT",
+        alter_stmt
+    ));
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected SET/DROP NOT NULL, SET DEFAULT, SET DATA TYPE after ALTER COLUMN, found: DROP"
+                .to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_truncated_statement_reports_eof() {
+    for (sql, expected) in &[
+        ("SELECT a FROM", "identifier"),
+        ("SELECT a FROM t WHERE", "an expression:"),
+        ("INSERT INTO", "identifier"),
+        ("SELECT a FROM t ORDER BY", "an expression:"),
+    ] {
+        assert_eq!(
+            ParserError::ParserError(format!("Expected {}, found: EOF", expected)),
+            parse_sql_statements(sql).unwrap_err(),
+            "{}",
+            sql
+        );
+    }
+}
+
+#[test]
+fn parse_bad_constraint() {
+    let res = parse_sql_statements("ALTER TABLE tab ADD");
     assert_eq!(
         ParserError::ParserError("Expected identifier, found: EOF".to_string()),
         res.unwrap_err()
@@ -1807,6 +3038,9 @@ fn parse_scalar_function_in_projection() {
             args: vec![FunctionArg::Unnamed(Expr::Identifier(Ident::new("id")))],
             over: None,
             distinct: false,
+            null_treatment: None,
+            order_by: vec![],
+            filter: None,
         }),
         expr_from_projection(only(&select.projection))
     );
@@ -1827,6 +3061,64 @@ fn run_explain_analyze(query: &str, expected_verbose: bool, expected_analyze: bo
     }
 }
 
+#[test]
+fn statement_kind_predicates() {
+    let kinds = |sql: &str| {
+        let stmt = verified_stmt(sql);
+        (stmt.is_query(), stmt.is_dml(), stmt.is_ddl())
+    };
+    assert_eq!((true, false, false), kinds("SELECT a FROM t"));
+    assert_eq!(
+        (true, false, false),
+        kinds("WITH c AS (SELECT 1) SELECT * FROM c")
+    );
+    assert_eq!((false, true, false), kinds("INSERT INTO t (a) VALUES (1)"));
+    assert_eq!((false, true, false), kinds("UPDATE t SET a = 1"));
+    assert_eq!((false, true, false), kinds("DELETE FROM t WHERE a = 1"));
+    assert_eq!(
+        (false, true, false),
+        kinds("WITH c AS (SELECT 1) INSERT INTO t SELECT * FROM c")
+    );
+    assert_eq!(
+        (false, true, false),
+        kinds("WITH c AS (SELECT 1) DELETE FROM t WHERE a IN (SELECT * FROM c)")
+    );
+    assert_eq!((false, false, true), kinds("CREATE TABLE t (a INT)"));
+    assert_eq!(
+        (false, false, true),
+        kinds("ALTER TABLE t ADD COLUMN b INT")
+    );
+    assert_eq!((false, false, true), kinds("DROP TABLE t"));
+    assert_eq!((false, false, false), kinds("COMMIT"));
+    assert_eq!((false, false, false), kinds("EXPLAIN SELECT a FROM t"));
+}
+
+#[test]
+fn statement_display_round_trip() {
+    for sql in &[
+        "SELECT DISTINCT a, b AS c FROM t AS x JOIN u ON x.id = u.id WHERE a > 1 GROUP BY a, b HAVING COUNT(*) > 1 ORDER BY a DESC LIMIT 10",
+        "INSERT INTO t (a, b) VALUES (1, 'x'), (2, NULL)",
+        "INSERT INTO t SELECT * FROM u",
+        "UPDATE t SET a = 1, b = b + 1 WHERE c IS NULL",
+        "DELETE FROM t WHERE a IN (1, 2)",
+        "CREATE TABLE IF NOT EXISTS t (a INT NOT NULL PRIMARY KEY, b CHARACTER VARYING(10) DEFAULT 'x', CONSTRAINT fk FOREIGN KEY (a) REFERENCES u(id))",
+        "CREATE TABLE t AS SELECT * FROM u",
+        "CREATE OR REPLACE VIEW v (a, b) AS SELECT a, b FROM t",
+        "CREATE UNIQUE INDEX IF NOT EXISTS idx ON t(a,b DESC)",
+        "DROP TABLE IF EXISTS t, u CASCADE",
+        "DROP VIEW v",
+        "DROP INDEX idx",
+        "ALTER TABLE t ADD COLUMN c INT",
+        "ALTER TABLE t DROP COLUMN IF EXISTS c CASCADE",
+        "ALTER TABLE t RENAME COLUMN a TO b",
+        "ALTER TABLE t RENAME TO u",
+        "ALTER TABLE t ADD CONSTRAINT uq UNIQUE (a)",
+    ] {
+        let stmt = verified_stmt(sql);
+        assert_eq!(vec![stmt.clone()], parse_sql_statements(&stmt.to_string()).unwrap());
+    }
+}
+
 #[test]
 fn parse_explain_analyze_with_simple_select() {
     run_explain_analyze("EXPLAIN SELECT sqrt(id) FROM foo", false, false);
@@ -1860,6 +3152,55 @@ fn parse_named_argument_function() {
             ],
             over: None,
             distinct: false,
+            null_treatment: None,
+            order_by: vec![],
+            filter: None,
+        }),
+        expr_from_projection(only(&select.projection))
+    );
+}
+
+#[test]
+fn parse_niladic_functions() {
+    for name in &[
+        "CURRENT_DATE",
+        "CURRENT_TIME",
+        "CURRENT_TIMESTAMP",
+        "CURRENT_USER",
+    ] {
+        let select = verified_only_select(&format!("SELECT {} FROM t", name));
+        assert_eq!(
+            &Expr::NiladicFunction {
+                name: Ident::new(*name)
+            },
+            expr_from_projection(only(&select.projection))
+        );
+    }
+
+    let select = verified_only_select("SELECT * FROM t WHERE d < current_date");
+    assert_eq!(
+        Some(Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("d"))),
+            op: BinaryOperator::Lt,
+            right: Box::new(Expr::NiladicFunction {
+                name: Ident::new("current_date")
+            }),
+        }),
+        select.selection
+    );
+
+    // with a precision, it's an ordinary function call
+    let select = verified_only_select("SELECT CURRENT_TIMESTAMP(6)");
+    assert_eq!(
+        &Expr::Function(Function {
+            name: ObjectName(vec![Ident::new("CURRENT_TIMESTAMP")]),
+            params: vec![],
+            args: vec![FunctionArg::Unnamed(Expr::Value(number("6")))],
+            over: None,
+            distinct: false,
+            null_treatment: None,
+            order_by: vec![],
+            filter: None,
         }),
         expr_from_projection(only(&select.projection))
     );
@@ -1890,15 +3231,257 @@ fn parse_window_functions() {
                     expr: Expr::Identifier(Ident::new("dt")),
                     asc: Some(false),
                     nulls_first: None,
+                    using: None,
                 }],
                 window_frame: None,
             }),
             distinct: false,
+            null_treatment: None,
+            order_by: vec![],
+            filter: None,
         }),
         expr_from_projection(&select.projection[0])
     );
 }
 
+#[test]
+fn parse_aggregate_with_order_by_and_filter() {
+    let sql = "SELECT string_agg(DISTINCT name, ', ' ORDER BY name DESC, id) \
+               FILTER (WHERE id > 0) OVER (PARTITION BY dept) FROM t";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &Expr::Function(Function {
+            name: ObjectName(vec![Ident::new("string_agg")]),
+            params: vec![],
+            args: vec![
+                FunctionArg::Unnamed(Expr::Identifier(Ident::new("name"))),
+                FunctionArg::Unnamed(Expr::Value(Value::SingleQuotedString(", ".to_string()))),
+            ],
+            over: Some(WindowSpec {
+                partition_by: vec![Expr::Identifier(Ident::new("dept"))],
+                order_by: vec![],
+                window_frame: None,
+            }),
+            distinct: true,
+            null_treatment: None,
+            order_by: vec![
+                OrderByExpr {
+                    expr: Expr::Identifier(Ident::new("name")),
+                    asc: Some(false),
+                    nulls_first: None,
+                    using: None,
+                },
+                OrderByExpr {
+                    expr: Expr::Identifier(Ident::new("id")),
+                    asc: None,
+                    nulls_first: None,
+                    using: None,
+                },
+            ],
+            filter: Some(Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("id"))),
+                op: BinaryOperator::Gt,
+                right: Box::new(Expr::Value(number("0"))),
+            })),
+        }),
+        expr_from_projection(only(&select.projection))
+    );
+
+    verified_stmt("SELECT count(*) FILTER (WHERE a IS NULL) FROM t");
+    // without a parenthesis, FILTER is still an alias
+    let select = verified_only_select("SELECT count(*) AS filter FROM t");
+    one_statement_parses_to("SELECT count(*) filter FROM t", &select.to_string());
+}
+
+#[test]
+fn parse_window_and_query_order_by() {
+    let query = verified_query("SELECT row_number() OVER (ORDER BY a) FROM t ORDER BY b");
+    let select = match &query.body {
+        SetExpr::Select(select) => select,
+        _ => unreachable!(),
+    };
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function {
+            over: Some(window), ..
+        }) => assert_eq!(
+            vec![Expr::Identifier(Ident::new("a"))],
+            window
+                .order_by
+                .iter()
+                .map(|o| o.expr.clone())
+                .collect::<Vec<_>>()
+        ),
+        _ => unreachable!(),
+    }
+    assert_eq!(
+        vec![Expr::Identifier(Ident::new("b"))],
+        query
+            .order_by
+            .iter()
+            .map(|o| o.expr.clone())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn parse_nested_function_calls() {
+    let call = |name: &str, args: Vec<Expr>| {
+        Expr::Function(Function {
+            name: ObjectName(vec![Ident::new(name)]),
+            params: vec![],
+            args: args.into_iter().map(FunctionArg::Unnamed).collect(),
+            over: None,
+            distinct: false,
+            null_treatment: None,
+            order_by: vec![],
+            filter: None,
+        })
+    };
+    assert_eq!(
+        call(
+            "round",
+            vec![
+                call(
+                    "avg",
+                    vec![call("abs", vec![Expr::Identifier(Ident::new("x"))])]
+                ),
+                Expr::Value(number("2")),
+            ]
+        ),
+        verified_expr("round(avg(abs(x)), 2)")
+    );
+}
+
+#[test]
+fn parse_window_function_null_treatment() {
+    let sql = "SELECT lag(x) IGNORE NULLS OVER (ORDER BY a), \
+               lead(x, 2) RESPECT NULLS OVER (ORDER BY a), \
+               lag(x) OVER (ORDER BY a) \
+               FROM foo";
+    let select = verified_only_select(sql);
+    let null_treatment = |i: usize| match expr_from_projection(&select.projection[i]) {
+        Expr::Function(f) => f.null_treatment.clone(),
+        _ => unreachable!(),
+    };
+    assert_eq!(Some(NullTreatment::IgnoreNulls), null_treatment(0));
+    assert_eq!(Some(NullTreatment::RespectNulls), null_treatment(1));
+    assert_eq!(None, null_treatment(2));
+
+    let res = parse_sql_statements("SELECT lag(x) IGNORE OVER (ORDER BY a) FROM foo");
+    assert!(res.is_err());
+}
+
+#[test]
+fn parse_window_frame() {
+    let sql = "SELECT sum(foo) OVER (ORDER BY a \
+               ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) \
+               FROM foo";
+    let select = verified_only_select(sql);
+    let frame = WindowFrame {
+        units: WindowFrameUnits::Rows,
+        start_bound: WindowFrameBound::Preceding(None),
+        end_bound: Some(WindowFrameBound::CurrentRow),
+        exclude: None,
+    };
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function {
+            over: Some(over), ..
+        }) => {
+            assert_eq!(Some(frame), over.window_frame)
+        }
+        _ => unreachable!(),
+    }
+
+    let sql = "SELECT sum(foo) OVER (ORDER BY a \
+               RANGE BETWEEN 1 PRECEDING AND 2 FOLLOWING EXCLUDE TIES) \
+               FROM foo";
+    let select = verified_only_select(sql);
+    let frame = WindowFrame {
+        units: WindowFrameUnits::Range,
+        start_bound: WindowFrameBound::Preceding(Some(1)),
+        end_bound: Some(WindowFrameBound::Following(Some(2))),
+        exclude: Some(WindowFrameExclude::Ties),
+    };
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function {
+            over: Some(over), ..
+        }) => {
+            assert_eq!(Some(frame), over.window_frame)
+        }
+        _ => unreachable!(),
+    }
+
+    verified_stmt(
+        "SELECT sum(foo) OVER (ORDER BY a GROUPS 1 PRECEDING EXCLUDE CURRENT ROW) FROM foo",
+    );
+    verified_stmt(
+        "SELECT sum(foo) OVER (ORDER BY a ROWS UNBOUNDED PRECEDING EXCLUDE GROUP) FROM foo",
+    );
+    verified_stmt(
+        "SELECT sum(foo) OVER (ORDER BY a ROWS UNBOUNDED PRECEDING EXCLUDE NO OTHERS) FROM foo",
+    );
+
+    let res =
+        parse_sql_statements("SELECT sum(foo) OVER (ROWS UNBOUNDED PRECEDING EXCLUDE) FROM foo");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected CURRENT ROW, GROUP, TIES or NO OTHERS, found: )".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_window_frame_bounds() {
+    let frame_of = |bounds: &str| {
+        let sql = format!("SELECT sum(foo) OVER (ORDER BY a ROWS {}) FROM foo", bounds);
+        match expr_from_projection(only(&verified_only_select(&sql).projection)) {
+            Expr::Function(Function {
+                over: Some(over), ..
+            }) => over.window_frame.clone().unwrap(),
+            _ => unreachable!(),
+        }
+    };
+
+    for (sql, bound) in &[
+        ("UNBOUNDED PRECEDING", WindowFrameBound::Preceding(None)),
+        ("2 PRECEDING", WindowFrameBound::Preceding(Some(2))),
+        ("CURRENT ROW", WindowFrameBound::CurrentRow),
+        ("2 FOLLOWING", WindowFrameBound::Following(Some(2))),
+    ] {
+        assert_eq!(bound, &frame_of(sql).start_bound);
+        let frame = frame_of(&format!("BETWEEN {} AND UNBOUNDED FOLLOWING", sql));
+        assert_eq!(bound, &frame.start_bound);
+        assert_eq!(Some(WindowFrameBound::Following(None)), frame.end_bound);
+    }
+    let frame = frame_of("BETWEEN 3 PRECEDING AND 1 PRECEDING");
+    assert_eq!(Some(WindowFrameBound::Preceding(Some(1))), frame.end_bound);
+
+    for bounds in &[
+        "CURRENT ROW AND UNBOUNDED PRECEDING",
+        "CURRENT ROW AND 1 PRECEDING",
+        "1 FOLLOWING AND CURRENT ROW",
+        "UNBOUNDED FOLLOWING AND UNBOUNDED FOLLOWING",
+        "UNBOUNDED PRECEDING AND UNBOUNDED PRECEDING",
+    ] {
+        let sql = format!(
+            "SELECT sum(foo) OVER (ORDER BY a ROWS BETWEEN {}) FROM foo",
+            bounds
+        );
+        assert_eq!(
+            ParserError::ParserError(format!("Invalid window frame: BETWEEN {}", bounds)),
+            parse_sql_statements(&sql).unwrap_err()
+        );
+    }
+
+    let res =
+        parse_sql_statements("SELECT sum(foo) OVER (ORDER BY a ROWS BETWEEN 1 PRECEDING) FROM foo");
+    assert_eq!(
+        ParserError::ParserError("Expected AND, found: )".to_string()),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_aggregate_with_group_by() {
     let sql = "SELECT a, COUNT(1), MIN(b), MAX(b) FROM foo GROUP BY a";
@@ -1944,6 +3527,28 @@ fn parse_literal_string() {
     one_statement_parses_to("SELECT x'deadBEEF'", "SELECT X'deadBEEF'");
 }
 
+#[test]
+fn parse_literal_string_with_escaped_quote() {
+    let sql = "SELECT 'it''s', N'it''s', ''''";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &Expr::Value(Value::SingleQuotedString("it's".to_string())),
+        expr_from_projection(&select.projection[0])
+    );
+    assert_eq!(
+        &Expr::Value(Value::NationalStringLiteral("it's".to_string())),
+        expr_from_projection(&select.projection[1])
+    );
+    assert_eq!(
+        &Expr::Value(Value::SingleQuotedString("'".to_string())),
+        expr_from_projection(&select.projection[2])
+    );
+    assert_eq!(
+        "'it''s'",
+        Value::SingleQuotedString("it's".to_string()).to_string()
+    );
+}
+
 #[test]
 fn parse_literal_date() {
     let sql = "SELECT DATE '1999-01-01'";
@@ -1963,7 +3568,7 @@ fn parse_literal_time() {
     let select = verified_only_select(sql);
     assert_eq!(
         &Expr::TypedString {
-            data_type: DataType::Time,
+            data_type: DataType::Time { tz: false },
             value: "01:23:34".into()
         },
         expr_from_projection(only(&select.projection)),
@@ -1976,7 +3581,7 @@ fn parse_literal_timestamp() {
     let select = verified_only_select(sql);
     assert_eq!(
         &Expr::TypedString {
-            data_type: DataType::Timestamp,
+            data_type: DataType::Timestamp { tz: false },
             value: "1999-01-01 01:23:34".into()
         },
         expr_from_projection(only(&select.projection)),
@@ -2011,6 +3616,34 @@ fn parse_literal_interval() {
         expr_from_projection(only(&select.projection)),
     );
 
+    let select = verified_only_select("SELECT INTERVAL '1 2:03:04' DAY TO SECOND (6)");
+    assert_eq!(
+        &Expr::Value(Value::Interval {
+            value: "1 2:03:04".into(),
+            leading_field: Some(DateTimeField::Day),
+            leading_precision: None,
+            last_field: Some(DateTimeField::Second),
+            fractional_seconds_precision: Some(6),
+        }),
+        expr_from_projection(only(&select.projection)),
+    );
+    one_statement_parses_to(
+        "SELECT INTERVAL '1 2:03:04' DAY(2) TO SECOND(6)",
+        "SELECT INTERVAL '1 2:03:04' DAY (2) TO SECOND (6)",
+    );
+
+    let select = verified_only_select("SELECT INTERVAL '1.5' SECOND (3)");
+    assert_eq!(
+        &Expr::Value(Value::Interval {
+            value: "1.5".into(),
+            leading_field: Some(DateTimeField::Second),
+            leading_precision: Some(3),
+            last_field: None,
+            fractional_seconds_precision: None,
+        }),
+        expr_from_projection(only(&select.projection)),
+    );
+
     let sql = "SELECT INTERVAL '1' SECOND (5, 4)";
     let select = verified_only_select(sql);
     assert_eq!(
@@ -2122,6 +3755,9 @@ fn parse_table_function() {
                 ))],
                 over: None,
                 distinct: false,
+                null_treatment: None,
+                order_by: vec![],
+                filter: None,
             });
             assert_eq!(expr, expected_expr);
             assert_eq!(alias, table_alias("a"))
@@ -2179,6 +3815,9 @@ fn parse_delimited_identifiers() {
             args: vec![],
             over: None,
             distinct: false,
+            null_treatment: None,
+            order_by: vec![],
+            filter: None,
         }),
         expr_from_projection(&select.projection[1]),
     );
@@ -2195,6 +3834,27 @@ fn parse_delimited_identifiers() {
     //TODO verified_stmt(r#"UPDATE foo SET "bar" = 5"#);
 }
 
+#[test]
+fn parse_delimited_identifiers_with_escaped_quotes() {
+    let select = verified_only_select(r#"SELECT "my ""quoted"" col" FROM "a ""b""""#);
+    assert_eq!(
+        &Expr::Identifier(Ident::with_quote('"', r#"my "quoted" col"#)),
+        expr_from_projection(&select.projection[0]),
+    );
+    match &select.from[0].relation {
+        TableFactor::Table { name, .. } => {
+            assert_eq!(&ObjectName(vec![Ident::with_quote('"', r#"a "b""#)]), name);
+        }
+        _ => unreachable!(),
+    }
+
+    // identifiers built by hand are escaped as well
+    assert_eq!(
+        r#""say ""hi"" there""#,
+        Ident::with_quote('"', r#"say "hi" there"#).to_string()
+    );
+}
+
 #[test]
 fn parse_parens() {
     use self::BinaryOperator::*;
@@ -2270,6 +3930,53 @@ fn parse_simple_case_expr() {
         },
         expr_from_projection(only(&select.projection)),
     );
+
+    // the operand may be any expression
+    assert_eq!(
+        Expr::Case {
+            operand: Some(Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                op: BinaryOperator::Plus,
+                right: Box::new(Expr::Identifier(Ident::new("b"))),
+            })),
+            conditions: vec![Expr::Value(number("1"))],
+            results: vec![Expr::Value(Value::SingleQuotedString("x".to_string()))],
+            else_result: None,
+        },
+        verified_expr("CASE a + b WHEN 1 THEN 'x' END")
+    );
+}
+
+#[test]
+fn parse_nested_case_expr_round_trip() {
+    let sql = "SELECT CASE WHEN a = 1 THEN CASE b WHEN 2 THEN 'x' ELSE 'y' END WHEN a = 3 THEN 'z' END FROM t";
+    let select = verified_only_select(sql);
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Case {
+            operand: None,
+            results,
+            else_result: None,
+            ..
+        } => match &results[0] {
+            Expr::Case {
+                operand: Some(operand),
+                else_result: Some(_),
+                ..
+            } => assert_eq!(Expr::Identifier(Ident::new("b")), **operand),
+            e => panic!("Expected a nested simple CASE, got {:?}", e),
+        },
+        e => panic!("Expected a searched CASE, got {:?}", e),
+    }
+
+    // The rendered form must parse back to the very same tree
+    for sql in &[
+        "CASE x WHEN 1 THEN CASE WHEN y THEN 1 END ELSE CASE z WHEN 0 THEN 2 END END",
+        "CASE WHEN a THEN CASE WHEN b THEN CASE WHEN c THEN 1 END END END",
+        "CASE (CASE WHEN a THEN 1 END) WHEN 1 THEN 'one' END",
+    ] {
+        let expr = verified_expr(sql);
+        assert_eq!(expr, verified_expr(&expr.to_string()));
+    }
 }
 
 #[test]
@@ -2349,6 +4056,47 @@ fn parse_implicit_join() {
     );
 }
 
+#[test]
+fn parse_implicit_join_with_explicit_joins() {
+    let sql = "SELECT * FROM a, b JOIN c ON b.id = c.id LEFT JOIN d USING(id), e";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        vec!["a", "b", "e"],
+        select
+            .from
+            .iter()
+            .map(|t| t.relation.to_string())
+            .collect::<Vec<_>>()
+    );
+    assert!(select.from[0].joins.is_empty());
+    assert_eq!(
+        vec![
+            Join {
+                relation: table("c"),
+                join_operator: JoinOperator::Inner(JoinConstraint::On(Expr::BinaryOp {
+                    left: Box::new(Expr::CompoundIdentifier(vec![
+                        Ident::new("b"),
+                        Ident::new("id")
+                    ])),
+                    op: BinaryOperator::Eq,
+                    right: Box::new(Expr::CompoundIdentifier(vec![
+                        Ident::new("c"),
+                        Ident::new("id")
+                    ])),
+                })),
+            },
+            Join {
+                relation: table("d"),
+                join_operator: JoinOperator::LeftOuter(JoinConstraint::Using(vec![Ident::new(
+                    "id"
+                )])),
+            },
+        ],
+        select.from[1].joins
+    );
+    assert!(select.from[2].joins.is_empty());
+}
+
 #[test]
 fn parse_cross_join() {
     let sql = "SELECT * FROM t1 CROSS JOIN t2";
@@ -2572,6 +4320,93 @@ fn parse_join_syntax_variants() {
     );
 }
 
+#[test]
+fn parse_join_using_multiple_columns() {
+    let sql = "SELECT * FROM a JOIN b USING(x, y, \"Z\")";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        JoinOperator::Inner(JoinConstraint::Using(vec![
+            Ident::new("x"),
+            Ident::new("y"),
+            Ident::with_quote('"', "Z"),
+        ])),
+        only(&select.from).joins[0].join_operator
+    );
+
+    one_statement_parses_to(
+        "SELECT * FROM a FULL JOIN b USING (x,y)",
+        "SELECT * FROM a FULL JOIN b USING(x, y)",
+    );
+
+    let res = parse_sql_statements("SELECT * FROM a JOIN b USING x");
+    assert_eq!(
+        ParserError::ParserError("Expected a list of columns in parentheses, found: x".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_join_without_constraint() {
+    let strict = TestedDialects {
+        dialects: vec![
+            Box::new(GenericDialect {}),
+            Box::new(PostgreSqlDialect {}),
+            Box::new(SQLiteDialect {}),
+            Box::new(BigQueryDialect {}),
+        ],
+    };
+    for sql in &[
+        "SELECT * FROM a JOIN b",
+        "SELECT * FROM a LEFT JOIN b WHERE 1 = 1",
+    ] {
+        assert!(strict
+            .parse_sql_statements(sql)
+            .unwrap_err()
+            .to_string()
+            .contains("Expected ON, or USING after JOIN"));
+    }
+
+    // NATURAL and CROSS joins take no constraint
+    strict.verified_stmt("SELECT * FROM a NATURAL JOIN b");
+    strict.verified_stmt("SELECT * FROM a CROSS JOIN b");
+
+    // while some dialects treat a plain join without one as a cross join
+    TestedDialects {
+        dialects: vec![Box::new(HiveDialect {}), Box::new(MySqlDialect {})],
+    }
+    .verified_stmt("SELECT * FROM a JOIN b");
+}
+
+#[test]
+fn parse_alias_as_optionality() {
+    // `AS` is optional before a column alias...
+    let select = one_statement_parses_to("SELECT a b FROM t", "SELECT a AS b FROM t");
+    assert_eq!(
+        &SelectItem::ExprWithAlias {
+            expr: Expr::Identifier(Ident::new("a")),
+            alias: Ident::new("b"),
+        },
+        match &select {
+            Statement::Query(query) => match &query.body {
+                SetExpr::Select(select) => only(&select.projection),
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    );
+    verified_stmt("SELECT a AS b FROM t");
+
+    // ...but required after a CTE name
+    verified_stmt("WITH cte AS (SELECT 1) SELECT * FROM cte");
+    let res = parse_sql_statements("WITH cte (SELECT 1) SELECT * FROM cte");
+    assert!(res.is_err());
+    let res = parse_sql_statements("WITH cte (a) (SELECT 1) SELECT * FROM cte");
+    assert_eq!(
+        ParserError::ParserError("Expected AS, found: (".to_string()),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_ctes() {
     let cte_sqls = vec!["SELECT 1 AS foo", "SELECT 2 AS bar"];
@@ -2672,10 +4507,55 @@ fn parse_recursive_cte() {
         },
         query: cte_query,
         from: None,
+        materialized: None,
     };
     assert_eq!(with.cte_tables.first().unwrap(), &expected);
 }
 
+#[test]
+fn parse_recursive_cte_with_order_by() {
+    let sql = "WITH RECURSIVE t(n) AS (SELECT 1 UNION ALL SELECT n+1 FROM t WHERE n < 10) \
+               SELECT * FROM t ORDER BY n";
+    let canonical =
+        "WITH RECURSIVE t (n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM t WHERE n < 10) \
+                     SELECT * FROM t ORDER BY n";
+    let query = match one_statement_parses_to(sql, canonical) {
+        Statement::Query(query) => query,
+        _ => unreachable!(),
+    };
+
+    let with = query.with.as_ref().unwrap();
+    assert!(with.recursive);
+    let cte = only(&with.cte_tables);
+    assert_eq!(Ident::new("t"), cte.alias.name);
+    assert_eq!(vec![Ident::new("n")], cte.alias.columns);
+    // ORDER BY belongs to the outer query, whereas the CTE body is a bare
+    // UNION ALL whose right operand keeps its WHERE
+    assert!(cte.query.order_by.is_empty());
+    match &cte.query.body {
+        SetExpr::SetOperation {
+            op: SetOperator::Union,
+            all: true,
+            right,
+            ..
+        } => match &**right {
+            SetExpr::Select(select) => assert!(select.selection.is_some()),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+    assert!(matches!(query.body, SetExpr::Select(_)));
+    assert_eq!(
+        vec![OrderByExpr {
+            expr: Expr::Identifier(Ident::new("n")),
+            asc: None,
+            nulls_first: None,
+            using: None,
+        }],
+        query.order_by
+    );
+}
+
 #[test]
 fn parse_derived_tables() {
     let sql = "SELECT a.x, b.y FROM (SELECT x FROM foo) AS a CROSS JOIN (SELECT y FROM bar) AS b";
@@ -2742,6 +4622,123 @@ fn parse_union() {
     verified_stmt("(SELECT * FROM new EXCEPT SELECT * FROM old) UNION ALL (SELECT * FROM old EXCEPT SELECT * FROM new) ORDER BY 1");
 }
 
+#[test]
+fn parse_parenthesized_set_operands() {
+    fn set_op(body: &SetExpr) -> (&SetOperator, &SetExpr, &SetExpr) {
+        match body {
+            SetExpr::SetOperation {
+                op, left, right, ..
+            } => (op, left, right),
+            _ => panic!("expected a set operation, got {:?}", body),
+        }
+    }
+    fn inner_body(body: &SetExpr) -> &SetExpr {
+        match body {
+            SetExpr::Query(query) => &query.body,
+            _ => panic!("expected a parenthesized query, got {:?}", body),
+        }
+    }
+
+    let query = verified_query("(SELECT 1) UNION (SELECT 2)");
+    let (op, left, right) = set_op(&query.body);
+    assert_eq!(&SetOperator::Union, op);
+    assert!(matches!(inner_body(left), SetExpr::Select(_)));
+    assert!(matches!(inner_body(right), SetExpr::Select(_)));
+
+    // INTERSECT binds tighter than UNION...
+    let query = verified_query("SELECT 1 UNION SELECT 2 INTERSECT SELECT 3");
+    let (op, _, right) = set_op(&query.body);
+    assert_eq!(&SetOperator::Union, op);
+    assert_eq!(&SetOperator::Intersect, set_op(right).0);
+
+    // ...unless parentheses say otherwise
+    let query = verified_query("(SELECT 1 UNION SELECT 2) INTERSECT SELECT 3");
+    let (op, left, _) = set_op(&query.body);
+    assert_eq!(&SetOperator::Intersect, op);
+    assert_eq!(&SetOperator::Union, set_op(inner_body(left)).0);
+
+    let query = verified_query("SELECT 1 UNION (SELECT 2 INTERSECT SELECT 3)");
+    let (op, _, right) = set_op(&query.body);
+    assert_eq!(&SetOperator::Union, op);
+    assert_eq!(&SetOperator::Intersect, set_op(inner_body(right)).0);
+}
+
+#[test]
+fn parse_union_order_by_ordinal() {
+    let query = verified_query("SELECT a FROM t UNION SELECT b FROM u ORDER BY 1");
+    assert!(matches!(query.body, SetExpr::SetOperation { .. }));
+    assert_eq!(
+        vec![Some(1)],
+        query
+            .order_by
+            .iter()
+            .map(OrderByExpr::ordinal)
+            .collect::<Vec<_>>()
+    );
+
+    let query = verified_query("SELECT a FROM t ORDER BY a, 2");
+    assert_eq!(
+        vec![None, Some(2)],
+        query
+            .order_by
+            .iter()
+            .map(OrderByExpr::ordinal)
+            .collect::<Vec<_>>()
+    );
+
+    // positions are checked against the columns of the first operand
+    let sql = "SELECT a FROM t UNION SELECT b FROM u ORDER BY 2";
+    TestedDialects {
+        dialects: vec![Box::new(GenericDialect {})],
+    }
+    .run_parser_method(sql, |parser| {
+        parser.parse_statement().unwrap();
+        assert_eq!(
+            vec!["ORDER BY position 2 is not in select list of 1 column(s)".to_string()],
+            parser.warnings()
+        );
+    });
+}
+
+#[test]
+fn parse_union_order_by_limit_scope() {
+    // a trailing ORDER BY / LIMIT applies to the whole set operation
+    let query = verified_query("SELECT a FROM t UNION SELECT a FROM u ORDER BY a LIMIT 10");
+    match &query.body {
+        SetExpr::SetOperation {
+            op: SetOperator::Union,
+            right,
+            ..
+        } => assert!(matches!(**right, SetExpr::Select(_))),
+        _ => unreachable!(),
+    }
+    assert_eq!(
+        vec![OrderByExpr {
+            expr: Expr::Identifier(Ident::new("a")),
+            asc: None,
+            nulls_first: None,
+            using: None,
+        }],
+        query.order_by
+    );
+    assert_eq!(Some(Expr::Value(number("10"))), query.limit);
+
+    // unless the last operand is parenthesized along with it
+    let query = verified_query("SELECT a FROM t UNION (SELECT a FROM u ORDER BY a LIMIT 10)");
+    assert!(query.order_by.is_empty());
+    assert_eq!(None, query.limit);
+    match query.body {
+        SetExpr::SetOperation { right, .. } => match *right {
+            SetExpr::Query(inner) => {
+                assert_eq!(1, inner.order_by.len());
+                assert_eq!(Some(Expr::Value(number("10"))), inner.limit);
+            }
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_values() {
     verified_stmt("SELECT * FROM (VALUES (1), (2), (3))");
@@ -2795,10 +4792,10 @@ fn parse_scalar_subqueries() {
     assert_matches!(
         verified_expr(sql),
         Expr::BinaryOp {
-        op: BinaryOperator::Plus, ..
-        //left: box Subquery { .. },
-        //right: box Subquery { .. },
-    }
+            op: BinaryOperator::Plus,
+            .. //left: box Subquery { .. },
+               //right: box Subquery { .. },
+        }
     );
 }
 
@@ -2839,6 +4836,44 @@ fn parse_trim() {
     one_statement_parses_to("SELECT TRIM('   foo   ')", "SELECT TRIM('   foo   ')");
 }
 
+#[test]
+fn parse_exists_precedence() {
+    let exists = Expr::Exists(Box::new(verified_query("SELECT 1")));
+    let a_eq_1 = Expr::BinaryOp {
+        left: Box::new(Expr::Identifier(Ident::new("a"))),
+        op: BinaryOperator::Eq,
+        right: Box::new(Expr::Value(number("1"))),
+    };
+
+    // NOT applies to the EXISTS predicate only, and AND binds looser
+    let select = verified_only_select("SELECT * FROM t WHERE NOT EXISTS (SELECT 1) AND a = 1");
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(Expr::UnaryOp {
+                op: UnaryOperator::Not,
+                expr: Box::new(exists.clone()),
+            }),
+            op: BinaryOperator::And,
+            right: Box::new(a_eq_1.clone()),
+        },
+        select.selection.unwrap(),
+    );
+
+    let select = verified_only_select("SELECT * FROM t WHERE a = 1 OR EXISTS (SELECT 1) AND a = 1");
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(a_eq_1.clone()),
+            op: BinaryOperator::Or,
+            right: Box::new(Expr::BinaryOp {
+                left: Box::new(exists),
+                op: BinaryOperator::And,
+                right: Box::new(a_eq_1),
+            }),
+        },
+        select.selection.unwrap(),
+    );
+}
+
 #[test]
 fn parse_exists_subquery() {
     let expected_inner = verified_query("SELECT 1");
@@ -2890,6 +4925,7 @@ fn parse_create_view() {
             or_replace,
             materialized,
             with_options,
+            with_check_option,
         } => {
             assert_eq!("myschema.myview", name.to_string());
             assert_eq!(Vec::<Ident>::new(), columns);
@@ -2897,9 +4933,46 @@ fn parse_create_view() {
             assert!(!materialized);
             assert!(!or_replace);
             assert_eq!(with_options, vec![]);
+            assert_eq!(None, with_check_option);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_create_view_with_check_option() {
+    let sql = "CREATE VIEW v AS SELECT a FROM t WHERE a > 0 WITH LOCAL CHECK OPTION";
+    match verified_stmt(sql) {
+        Statement::CreateView {
+            query,
+            with_check_option,
+            ..
+        } => {
+            assert_eq!("SELECT a FROM t WHERE a > 0", query.to_string());
+            assert_eq!(Some(CheckOption::Local), with_check_option);
         }
         _ => unreachable!(),
     }
+
+    let sql = "CREATE VIEW v AS SELECT a FROM t WITH CASCADED CHECK OPTION";
+    match verified_stmt(sql) {
+        Statement::CreateView {
+            with_check_option, ..
+        } => assert_eq!(Some(CheckOption::Cascaded), with_check_option),
+        _ => unreachable!(),
+    }
+
+    // CASCADED is the default
+    one_statement_parses_to(
+        "CREATE VIEW v AS SELECT a FROM t WITH CHECK OPTION",
+        "CREATE VIEW v AS SELECT a FROM t WITH CASCADED CHECK OPTION",
+    );
+
+    let res = parse_sql_statements("CREATE VIEW v AS SELECT a FROM t WITH LOCAL OPTION");
+    assert_eq!(
+        ParserError::ParserError("Expected CHECK, found: OPTION".to_string()),
+        res.unwrap_err()
+    );
 }
 
 #[test]
@@ -2936,13 +5009,15 @@ fn parse_create_view_with_columns() {
             with_options,
             query,
             materialized,
+            with_check_option,
         } => {
             assert_eq!("v", name.to_string());
             assert_eq!(columns, vec![Ident::new("has"), Ident::new("cols")]);
             assert_eq!(with_options, vec![]);
             assert_eq!("SELECT 1, 2", query.to_string());
             assert!(!materialized);
-            assert!(!or_replace)
+            assert!(!or_replace);
+            assert_eq!(None, with_check_option);
         }
         _ => unreachable!(),
     }
@@ -2958,13 +5033,15 @@ fn parse_create_or_replace_view() {
             with_options,
             query,
             materialized,
+            with_check_option,
         } => {
             assert_eq!("v", name.to_string());
             assert_eq!(columns, vec![]);
             assert_eq!(with_options, vec![]);
             assert_eq!("SELECT 1", query.to_string());
             assert!(!materialized);
-            assert!(or_replace)
+            assert!(or_replace);
+            assert_eq!(None, with_check_option);
         }
         _ => unreachable!(),
     }
@@ -2985,13 +5062,15 @@ fn parse_create_or_replace_materialized_view() {
             with_options,
             query,
             materialized,
+            with_check_option,
         } => {
             assert_eq!("v", name.to_string());
             assert_eq!(columns, vec![]);
             assert_eq!(with_options, vec![]);
             assert_eq!("SELECT 1", query.to_string());
             assert!(materialized);
-            assert!(or_replace)
+            assert!(or_replace);
+            assert_eq!(None, with_check_option);
         }
         _ => unreachable!(),
     }
@@ -3008,6 +5087,7 @@ fn parse_create_materialized_view() {
             query,
             materialized,
             with_options,
+            with_check_option,
         } => {
             assert_eq!("myschema.myview", name.to_string());
             assert_eq!(Vec::<Ident>::new(), columns);
@@ -3015,6 +5095,7 @@ fn parse_create_materialized_view() {
             assert!(materialized);
             assert_eq!(with_options, vec![]);
             assert!(!or_replace);
+            assert_eq!(None, with_check_option);
         }
         _ => unreachable!(),
     }
@@ -3028,7 +5109,7 @@ fn parse_drop_table() {
             object_type,
             if_exists,
             names,
-            cascade,
+            behavior,
             purge: _,
         } => {
             assert!(!if_exists);
@@ -3037,7 +5118,7 @@ fn parse_drop_table() {
                 vec!["foo"],
                 names.iter().map(ToString::to_string).collect::<Vec<_>>()
             );
-            assert!(!cascade);
+            assert_eq!(None, behavior);
         }
         _ => unreachable!(),
     }
@@ -3048,7 +5129,7 @@ fn parse_drop_table() {
             object_type,
             if_exists,
             names,
-            cascade,
+            behavior,
             purge: _,
         } => {
             assert!(if_exists);
@@ -3057,7 +5138,7 @@ fn parse_drop_table() {
                 vec!["foo", "bar"],
                 names.iter().map(ToString::to_string).collect::<Vec<_>>()
             );
-            assert!(cascade);
+            assert_eq!(Some(DropBehavior::Cascade), behavior);
         }
         _ => unreachable!(),
     }
@@ -3075,6 +5156,42 @@ fn parse_drop_table() {
     );
 }
 
+#[test]
+fn parse_drop_behavior() {
+    for object_type in &["TABLE", "VIEW", "SCHEMA", "INDEX"] {
+        for (clause, expected) in &[
+            ("", None),
+            (" CASCADE", Some(DropBehavior::Cascade)),
+            (" RESTRICT", Some(DropBehavior::Restrict)),
+        ] {
+            let sql = format!("DROP {} a.b{}", object_type, clause);
+            match verified_stmt(&sql) {
+                Statement::Drop { behavior, .. } => assert_eq!(*expected, behavior),
+                _ => unreachable!(),
+            }
+        }
+
+        let sql = format!("DROP {} a.b RESTRICT CASCADE", object_type);
+        assert_eq!(
+            ParserError::ParserError(
+                "Cannot specify both CASCADE and RESTRICT in DROP".to_string()
+            ),
+            parse_sql_statements(&sql).unwrap_err(),
+        );
+
+        for keyword in &["CASCADE", "RESTRICT"] {
+            let sql = format!("DROP {} a.b {} {}", object_type, keyword, keyword);
+            assert_eq!(
+                ParserError::ParserError(format!(
+                    "Cannot specify {} more than once in DROP",
+                    keyword
+                )),
+                parse_sql_statements(&sql).unwrap_err(),
+            );
+        }
+    }
+}
+
 #[test]
 fn parse_drop_view() {
     let sql = "DROP VIEW myschema.myview";
@@ -3258,12 +5375,12 @@ fn parse_fetch_variations() {
         "SELECT foo FROM bar FETCH FIRST 10 ROWS ONLY",
     );
     one_statement_parses_to(
-        "SELECT foo FROM bar FETCH NEXT 10 ROWS WITH TIES",
-        "SELECT foo FROM bar FETCH FIRST 10 ROWS WITH TIES",
+        "SELECT foo FROM bar ORDER BY foo FETCH NEXT 10 ROWS WITH TIES",
+        "SELECT foo FROM bar ORDER BY foo FETCH FIRST 10 ROWS WITH TIES",
     );
     one_statement_parses_to(
-        "SELECT foo FROM bar FETCH NEXT ROWS WITH TIES",
-        "SELECT foo FROM bar FETCH FIRST ROWS WITH TIES",
+        "SELECT foo FROM bar ORDER BY foo FETCH NEXT ROWS WITH TIES",
+        "SELECT foo FROM bar ORDER BY foo FETCH FIRST ROWS WITH TIES",
     );
     one_statement_parses_to(
         "SELECT foo FROM bar FETCH FIRST ROWS ONLY",
@@ -3271,6 +5388,24 @@ fn parse_fetch_variations() {
     );
 }
 
+#[test]
+fn parse_fetch_with_ties_requires_order_by() {
+    let res = parse_sql_statements("SELECT foo FROM bar FETCH FIRST 10 ROWS WITH TIES");
+    assert_eq!(
+        ParserError::ParserError("FETCH ... WITH TIES requires an ORDER BY clause".to_string()),
+        res.unwrap_err()
+    );
+
+    // The ORDER BY of a subquery doesn't count
+    let res = parse_sql_statements(
+        "SELECT foo FROM (SELECT foo FROM bar ORDER BY foo) AS t FETCH FIRST ROWS WITH TIES",
+    );
+    assert!(res.is_err());
+
+    verified_stmt("SELECT foo FROM bar ORDER BY foo FETCH FIRST 10 ROWS WITH TIES");
+    verified_stmt("SELECT foo FROM bar FETCH FIRST 10 ROWS ONLY");
+}
+
 #[test]
 fn lateral_derived() {
     fn chk(lateral_in: bool) {
@@ -3397,6 +5532,29 @@ fn parse_start_transaction() {
     );
 }
 
+#[test]
+fn parse_set_boolean_values() {
+    fn set_value(sql: &str) -> SetVariableValue {
+        match verified_stmt(sql) {
+            Statement::SetVariable { mut value, .. } => value.remove(0),
+            _ => unreachable!(),
+        }
+    }
+
+    assert_eq!(
+        SetVariableValue::Ident("ON".into()),
+        set_value("SET autocommit = ON")
+    );
+    assert_eq!(
+        SetVariableValue::Literal(number("1")),
+        set_value("SET x = 1")
+    );
+    assert_eq!(
+        SetVariableValue::Literal(Value::Boolean(false)),
+        set_value("SET x = false")
+    );
+}
+
 #[test]
 fn parse_set_transaction() {
     // SET TRANSACTION shares transaction mode parsing code with START
@@ -3474,29 +5632,138 @@ fn parse_create_index() {
             expr: Expr::Identifier(Ident::new("name")),
             asc: None,
             nulls_first: None,
+            using: None,
         },
         OrderByExpr {
             expr: Expr::Identifier(Ident::new("age")),
             asc: Some(false),
             nulls_first: None,
+            using: None,
         },
     ];
     match verified_stmt(sql) {
         Statement::CreateIndex {
             name,
             table_name,
+            using,
             columns,
             unique,
             if_not_exists,
+            include,
+            tablespace,
+            predicate,
         } => {
             assert_eq!("idx_name", name.to_string());
             assert_eq!("test", table_name.to_string());
+            assert_eq!(None, using);
             assert_eq!(indexed_columns, columns);
             assert!(unique);
-            assert!(if_not_exists)
+            assert!(if_not_exists);
+            assert!(include.is_empty());
+            assert_eq!(None, tablespace);
+            assert_eq!(None, predicate);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_create_partial_index() {
+    let sql = "CREATE INDEX idx ON t(a) WHERE active";
+    match verified_stmt(sql) {
+        Statement::CreateIndex { predicate, .. } => {
+            assert_eq!(Some(Expr::Identifier(Ident::new("active"))), predicate);
+        }
+        _ => unreachable!(),
+    }
+
+    verified_stmt("CREATE INDEX idx ON t(a) INCLUDE (b) WHERE a IS NOT NULL AND NOT deleted");
+}
+
+#[test]
+fn parse_create_covering_index() {
+    let sql = "CREATE UNIQUE INDEX idx ON t(a) INCLUDE (b, c) TABLESPACE ts";
+    match verified_stmt(sql) {
+        Statement::CreateIndex {
+            columns, include, ..
+        } => {
+            assert_eq!(1, columns.len());
+            assert_eq!(vec![Ident::new("b"), Ident::new("c")], include);
+        }
+        _ => unreachable!(),
+    }
+
+    let res = parse_sql_statements("CREATE INDEX idx ON t(a) INCLUDE b");
+    assert_eq!(
+        ParserError::ParserError("Expected a list of columns in parentheses, found: b".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_create_index_with_tablespace() {
+    let sql = "CREATE INDEX idx ON t(a) TABLESPACE fast_disk";
+    match verified_stmt(sql) {
+        Statement::CreateIndex { tablespace, .. } => {
+            assert_eq!(Some(Ident::new("fast_disk")), tablespace);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_create_temporary_table_on_commit() {
+    for (clause, expected) in &[
+        ("ON COMMIT PRESERVE ROWS", OnCommit::PreserveRows),
+        ("ON COMMIT DELETE ROWS", OnCommit::DeleteRows),
+        ("ON COMMIT DROP", OnCommit::Drop),
+    ] {
+        let sql = format!("CREATE TEMPORARY TABLE t (a INT) {}", clause);
+        match verified_stmt(&sql) {
+            Statement::CreateTable {
+                temporary,
+                on_commit,
+                ..
+            } => {
+                assert!(temporary);
+                assert_eq!(Some(*expected), on_commit);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    let res = parse_sql_statements("CREATE TABLE t (a INT) ON COMMIT DROP");
+    assert_eq!(
+        ParserError::ParserError("ON COMMIT can only be used on temporary tables".to_string()),
+        res.unwrap_err()
+    );
+
+    let res = parse_sql_statements("CREATE TEMPORARY TABLE t (a INT) ON COMMIT");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected PRESERVE ROWS, DELETE ROWS or DROP after ON COMMIT, found: EOF".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_create_table_with_tablespace() {
+    let sql = "CREATE TABLE t (a INT) WITH (fillfactor = 70) TABLESPACE \"Fast Disk\"";
+    match verified_stmt(sql) {
+        Statement::CreateTable { tablespace, .. } => {
+            assert_eq!(Some(Ident::with_quote('"', "Fast Disk")), tablespace);
         }
         _ => unreachable!(),
     }
+
+    verified_stmt("CREATE TABLE t TABLESPACE ts AS SELECT 1");
+
+    let res = parse_sql_statements("CREATE TABLE t (a INT) TABLESPACE");
+    assert_eq!(
+        ParserError::ParserError("Expected identifier, found: EOF".to_string()),
+        res.unwrap_err()
+    );
 }
 #[test]
 fn parse_drop_index() {
@@ -3546,3 +5813,449 @@ fn verified_only_select(query: &str) -> Select {
 fn verified_expr(query: &str) -> Expr {
     all_dialects().verified_expr(query)
 }
+
+#[test]
+fn parse_with_dml() {
+    let cte = "WITH x AS (SELECT id FROM u) ";
+
+    let query = verified_query(&format!("{}INSERT INTO t SELECT * FROM x", cte));
+    assert_eq!("x", query.with.unwrap().cte_tables[0].alias.to_string());
+    assert!(matches!(
+        query.body,
+        SetExpr::Insert(Statement::Insert { .. })
+    ));
+
+    let query = verified_query(&format!(
+        "{}UPDATE t SET a = 1 WHERE id IN (SELECT id FROM x)",
+        cte
+    ));
+    assert!(query.with.is_some());
+    match query.body {
+        SetExpr::Update(Statement::Update { table_name, .. }) => {
+            assert_eq!("t", table_name.to_string())
+        }
+        _ => unreachable!(),
+    }
+
+    let query = verified_query(&format!(
+        "{}DELETE FROM t WHERE id IN (SELECT id FROM x)",
+        cte
+    ));
+    assert!(query.with.is_some());
+    match query.body {
+        SetExpr::Delete(Statement::Delete {
+            table_name,
+            selection,
+            ..
+        }) => {
+            assert_eq!("t", table_name.to_string());
+            assert!(selection.is_some());
+        }
+        _ => unreachable!(),
+    }
+
+    // Without WITH, a data-modifying statement is not a query
+    let res = all_dialects().one_of_identical_results(|dialect| {
+        Parser::parse_sql(dialect, "SELECT * FROM (DELETE FROM t) AS x").is_err()
+    });
+    assert!(res);
+}
+
+#[test]
+fn parse_column_leading_comments() {
+    let sql = "CREATE TABLE t (\n  id INT,\n  -- note\n  /* more */ name TEXT\n)";
+    let statement = &parse_sql_statements(sql).unwrap()[0];
+    // comments are not part of the SQL output
+    assert_eq!("CREATE TABLE t (id INT, name TEXT)", statement.to_string());
+    match statement {
+        Statement::CreateTable { columns, .. } => {
+            assert!(columns[0].leading_comments.is_empty());
+            assert_eq!(vec!["note", "more"], columns[1].leading_comments);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_grant() {
+    let sql = "GRANT SELECT, INSERT (a, b), UPDATE ON t1, t2 TO u1, u2 WITH GRANT OPTION";
+    match verified_stmt(sql) {
+        Statement::Grant {
+            privileges,
+            objects,
+            grantees,
+            with_grant_option,
+        } => {
+            assert_eq!(
+                Privileges::Actions(vec![
+                    Action::Select { columns: None },
+                    Action::Insert {
+                        columns: Some(vec![Ident::new("a"), Ident::new("b")])
+                    },
+                    Action::Update { columns: None },
+                ]),
+                privileges
+            );
+            assert_eq!(
+                GrantObjects::Tables(vec![
+                    ObjectName(vec![Ident::new("t1")]),
+                    ObjectName(vec![Ident::new("t2")]),
+                ]),
+                objects
+            );
+            assert_eq!(vec![Ident::new("u1"), Ident::new("u2")], grantees);
+            assert!(with_grant_option);
+        }
+        _ => unreachable!(),
+    }
+
+    verified_stmt("GRANT ALL PRIVILEGES ON SCHEMA s TO u");
+    verified_stmt("GRANT USAGE, TRUNCATE ON ALL TABLES IN SCHEMA s1, s2 TO u");
+    one_statement_parses_to("GRANT SELECT ON TABLE t TO u", "GRANT SELECT ON t TO u");
+}
+
+#[test]
+fn parse_revoke() {
+    let sql = "REVOKE ALL ON SEQUENCE s FROM u1 CASCADE";
+    match verified_stmt(sql) {
+        Statement::Revoke {
+            privileges,
+            objects,
+            grantees,
+            behavior,
+        } => {
+            assert_eq!(
+                Privileges::All {
+                    with_privileges_keyword: false
+                },
+                privileges
+            );
+            assert_eq!(
+                GrantObjects::Sequences(vec![ObjectName(vec![Ident::new("s")])]),
+                objects
+            );
+            assert_eq!(vec![Ident::new("u1")], grantees);
+            assert_eq!(Some(DropBehavior::Cascade), behavior);
+        }
+        _ => unreachable!(),
+    }
+
+    verified_stmt("REVOKE SELECT, DELETE ON t FROM u");
+    match verified_stmt("REVOKE SELECT ON t FROM u RESTRICT") {
+        Statement::Revoke { behavior, .. } => {
+            assert_eq!(Some(DropBehavior::Restrict), behavior)
+        }
+        _ => unreachable!(),
+    }
+
+    let res = parse_sql_statements("REVOKE SELECT ON t FROM u CASCADE RESTRICT");
+    assert_eq!(
+        ParserError::ParserError("Cannot specify both CASCADE and RESTRICT in REVOKE".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_create_sequence() {
+    let sql =
+        "CREATE SEQUENCE s START WITH 1 INCREMENT BY - 2 MINVALUE - 100 NO MAXVALUE CACHE 20 CYCLE";
+    match verified_stmt(sql) {
+        Statement::CreateSequence {
+            temporary,
+            if_not_exists,
+            name,
+            options,
+        } => {
+            assert!(!temporary);
+            assert!(!if_not_exists);
+            assert_eq!("s", name.to_string());
+            assert_eq!(
+                vec![
+                    SequenceOptions::StartWith(Expr::Value(number("1")), true),
+                    SequenceOptions::IncrementBy(
+                        Expr::UnaryOp {
+                            op: UnaryOperator::Minus,
+                            expr: Box::new(Expr::Value(number("2"))),
+                        },
+                        true
+                    ),
+                    SequenceOptions::MinValue(Some(Expr::UnaryOp {
+                        op: UnaryOperator::Minus,
+                        expr: Box::new(Expr::Value(number("100"))),
+                    })),
+                    SequenceOptions::MaxValue(None),
+                    SequenceOptions::Cache(Expr::Value(number("20"))),
+                    SequenceOptions::Cycle(true),
+                ],
+                options
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    verified_stmt("CREATE TEMPORARY SEQUENCE IF NOT EXISTS db.s INCREMENT 5 START 10 NO CYCLE");
+    verified_stmt("CREATE SEQUENCE s");
+}
+
+#[test]
+fn parse_data_type_argument_validation() {
+    verified_stmt(
+        "CREATE TABLE t (a CHAR(1), b CHARACTER VARYING(10), c NUMERIC(10,2), d NUMERIC(5,5))",
+    );
+    verified_stmt("SELECT CAST(a AS FLOAT(8)), CAST(b AS CHARACTER VARYING(3))");
+
+    let cases = [
+        (
+            "SELECT CAST(a AS CHAR(0))",
+            "Length of type CHAR must be at least 1",
+        ),
+        (
+            "CREATE TABLE t (a VARCHAR(0))",
+            "Length of type VARCHAR must be at least 1",
+        ),
+        (
+            "CREATE TABLE t (a FLOAT(0))",
+            "Length of type FLOAT must be at least 1",
+        ),
+        (
+            "CREATE TABLE t (a CHAR(-1))",
+            "Type CHAR does not accept a negative length or precision",
+        ),
+        (
+            "CREATE TABLE t (a NUMERIC(-3))",
+            "Type NUMERIC does not accept a negative length or precision",
+        ),
+        (
+            "CREATE TABLE t (a NUMERIC(0))",
+            "Precision of type NUMERIC must be at least 1",
+        ),
+        (
+            "SELECT CAST(a AS NUMERIC(5, 10))",
+            "Scale of type NUMERIC must not exceed its precision, found NUMERIC(5, 10)",
+        ),
+        (
+            "SELECT CAST(a AS DECIMAL(5, 10))",
+            "Scale of type DECIMAL must not exceed its precision, found DECIMAL(5, 10)",
+        ),
+        (
+            "CREATE TABLE t (a DEC(0))",
+            "Precision of type DEC must be at least 1",
+        ),
+        (
+            "CREATE TABLE t (a decimal(-3))",
+            "Type DECIMAL does not accept a negative length or precision",
+        ),
+    ];
+    for (sql, err) in cases.iter() {
+        assert_eq!(
+            ParserError::ParserError(err.to_string()),
+            parse_sql_statements(sql).unwrap_err(),
+            "{}",
+            sql
+        );
+    }
+}
+
+#[test]
+fn parse_empty_input() {
+    for sql in &[
+        "",
+        "  ",
+        "\n\t",
+        "-- just a comment",
+        "/* a block comment */",
+        "-- a comment\n/* and another */ ;",
+    ] {
+        assert_eq!(Ok(vec![]), parse_sql_statements(sql), "{:?}", sql);
+    }
+}
+
+#[test]
+fn parse_insert_overriding() {
+    for (sql, expected) in &[
+        (
+            "INSERT INTO t (id, a) OVERRIDING SYSTEM VALUE VALUES (1, 2)",
+            Overriding::SystemValue,
+        ),
+        (
+            "INSERT INTO t OVERRIDING USER VALUE SELECT * FROM s",
+            Overriding::UserValue,
+        ),
+    ] {
+        match verified_stmt(sql) {
+            Statement::Insert {
+                overriding, source, ..
+            } => {
+                assert_eq!(Some(*expected), overriding);
+                assert!(source.is_some());
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    match verified_stmt("INSERT INTO t VALUES (1)") {
+        Statement::Insert { overriding, .. } => assert_eq!(None, overriding),
+        _ => unreachable!(),
+    }
+
+    let res = parse_sql_statements("INSERT INTO t OVERRIDING VALUE VALUES (1)");
+    assert_eq!(
+        ParserError::ParserError("Expected one of SYSTEM or USER, found: VALUE".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_decimal_type_synonyms() {
+    let data_type = |sql: &str| match verified_expr(&format!("CAST(a AS {})", sql)) {
+        Expr::Cast { data_type, .. } => data_type,
+        e => panic!("Expected a CAST, got {:?}", e),
+    };
+    assert_eq!(
+        DataType::Decimal(Some(10), Some(2)),
+        data_type("NUMERIC(10,2)")
+    );
+    assert_eq!(DataType::Decimal(Some(10), None), data_type("NUMERIC(10)"));
+    assert_eq!(DataType::Decimal(None, None), data_type("NUMERIC"));
+
+    for (synonym, canonical) in &[
+        ("DEC(10,2)", "NUMERIC(10,2)"),
+        ("DECIMAL(10)", "NUMERIC(10)"),
+        ("DECIMAL", "NUMERIC"),
+        ("DEC", "NUMERIC"),
+    ] {
+        one_statement_parses_to(
+            &format!("SELECT CAST(a AS {})", synonym),
+            &format!("SELECT CAST(a AS {})", canonical),
+        );
+    }
+}
+
+#[test]
+fn parse_bool_type_synonym() {
+    let bool_expr = all_dialects()
+        .run_parser_method("CAST(a AS BOOL)", |parser| parser.parse_expr())
+        .unwrap();
+    assert_eq!(verified_expr("CAST(a AS BOOLEAN)"), bool_expr);
+    one_statement_parses_to("CREATE TABLE t (a BOOL)", "CREATE TABLE t (a BOOLEAN)");
+}
+
+#[test]
+fn parse_text_and_string_types() {
+    match verified_stmt("CREATE TABLE t (a TEXT, b TEXT NOT NULL)") {
+        Statement::CreateTable { columns, .. } => {
+            assert_eq!(DataType::Text, columns[0].data_type);
+            assert_eq!(DataType::Text, columns[1].data_type);
+        }
+        _ => unreachable!(),
+    }
+
+    let string_type = |dialect: Box<dyn Dialect>| match (TestedDialects {
+        dialects: vec![dialect],
+    })
+    .verified_expr("CAST(a AS STRING)")
+    {
+        Expr::Cast { data_type, .. } => data_type,
+        e => panic!("Expected a CAST, got {:?}", e),
+    };
+    assert_eq!(DataType::String, string_type(Box::new(HiveDialect {})));
+    assert_eq!(DataType::String, string_type(Box::new(BigQueryDialect {})));
+    // STRING is not a built-in type in PostgreSQL
+    assert_eq!(
+        DataType::Custom(ObjectName(vec![Ident::new("STRING")])),
+        string_type(Box::new(PostgreSqlDialect {}))
+    );
+}
+
+#[test]
+fn parse_dialect_custom_operators() {
+    let dialect = TestedDialects {
+        dialects: vec![Box::new(TestDialect {
+            custom_operators: &["===", "=~"],
+            custom_operator_precedence: |op| match op {
+                // Binds tighter than `+`
+                "=~" => 35,
+                _ => 20,
+            },
+            ..Default::default()
+        })],
+    };
+    let select = dialect.verified_only_select("SELECT * FROM t WHERE a === b AND c = d");
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(Expr::BinaryOp {
+                left: ident("a"),
+                op: BinaryOperator::Custom("===".to_string()),
+                right: ident("b"),
+            }),
+            op: BinaryOperator::And,
+            right: Box::new(Expr::BinaryOp {
+                left: ident("c"),
+                op: BinaryOperator::Eq,
+                right: ident("d"),
+            }),
+        },
+        select.selection.unwrap()
+    );
+
+    // The dialect-provided precedence makes `=~` bind tighter than `+`
+    match dialect.verified_expr("a + b =~ c") {
+        Expr::BinaryOp {
+            op: BinaryOperator::Plus,
+            right,
+            ..
+        } => assert_eq!(
+            Expr::BinaryOp {
+                left: ident("b"),
+                op: BinaryOperator::Custom("=~".to_string()),
+                right: ident("c"),
+            },
+            *right
+        ),
+        e => panic!("Expected + at the top, got {:?}", e),
+    }
+
+    // Other dialects don't know the operator
+    assert!(parse_sql_statements("SELECT a === b").is_err());
+}
+
+#[test]
+fn parse_dialect_precedence_override() {
+    // By default `AND` binds tighter, i.e. `a OR (b AND c)`
+    assert_eq!(
+        Expr::BinaryOp {
+            left: ident("a"),
+            op: BinaryOperator::Or,
+            right: Box::new(Expr::BinaryOp {
+                left: ident("b"),
+                op: BinaryOperator::And,
+                right: ident("c"),
+            }),
+        },
+        verified_expr("a OR b AND c")
+    );
+
+    // With the override it is `(a OR b) AND c`
+    let dialect = TestedDialects {
+        dialects: vec![Box::new(TestDialect {
+            precedence_of: |token| match token {
+                // Tighter than `AND` (10), looser than `NOT` (15)
+                Token::Word(w) if w.keyword == Keyword::OR => Some(12),
+                _ => None,
+            },
+            ..Default::default()
+        })],
+    };
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(Expr::BinaryOp {
+                left: ident("a"),
+                op: BinaryOperator::Or,
+                right: ident("b"),
+            }),
+            op: BinaryOperator::And,
+            right: ident("c"),
+        },
+        dialect.verified_expr("a OR b AND c")
+    );
+}
@@ -15,6 +15,7 @@
 //! Test SQL syntax specific to Hive. The parser based on the generic dialect
 //! is also tested (on the inputs it can handle).
 
+use sqlparser::ast::Statement;
 use sqlparser::dialect::HiveDialect;
 use sqlparser::test_utils::*;
 
@@ -31,6 +32,22 @@ fn parse_table_create() {
 fn parse_insert_overwrite() {
     let insert_partitions = r#"INSERT OVERWRITE TABLE db.new_table PARTITION (a = '1', b) SELECT a, b, c FROM db.table"#;
     hive().verified_stmt(insert_partitions);
+
+    match hive().verified_stmt("INSERT OVERWRITE TABLE t SELECT * FROM s") {
+        Statement::Insert {
+            table_name,
+            overwrite,
+            table,
+            replace_into,
+            ..
+        } => {
+            assert_eq!("t", table_name.to_string());
+            assert!(overwrite);
+            assert!(table);
+            assert!(!replace_into);
+        }
+        _ => unreachable!(),
+    }
 }
 
 #[test]
@@ -196,6 +213,9 @@ fn rename_table() {
 fn map_access() {
     let rename = "SELECT a.b[\"asdf\"] FROM db.table WHERE a = 2";
     hive().verified_stmt(rename);
+
+    // Chained keys are all kept
+    hive().verified_stmt("SELECT a[\"x\"][\"y\"] FROM t");
 }
 
 #[test]
@@ -53,6 +53,7 @@ fn parse_mssql_delimited_identifiers() {
         "SELECT [a.b!] [FROM] FROM foo [WHERE]",
         "SELECT [a.b!] AS [FROM] FROM foo AS [WHERE]",
     );
+    ms().verified_stmt("SELECT [a]]b] FROM [t]]]");
 }
 
 #[test]
@@ -113,6 +114,50 @@ fn parse_mssql_top() {
     let _ = ms_and_generic().one_statement_parses_to(sql, "SELECT TOP (5) bar, baz FROM foo");
 }
 
+#[test]
+fn parse_mssql_top_without_parens() {
+    let top = |sql: &str, canonical: &str| match ms_and_generic()
+        .one_statement_parses_to(sql, canonical)
+    {
+        Statement::Query(query) => match query.body {
+            SetExpr::Select(select) => select.top.unwrap(),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+
+    let t = top(
+        "SELECT TOP 10 PERCENT * FROM foo",
+        "SELECT TOP (10) PERCENT * FROM foo",
+    );
+    assert_eq!(Some(Expr::Value(number("10"))), t.quantity);
+    assert!(t.percent);
+    assert!(!t.with_ties);
+
+    let t = top(
+        "SELECT TOP 5 WITH TIES * FROM foo",
+        "SELECT TOP (5) WITH TIES * FROM foo",
+    );
+    assert_eq!(Some(Expr::Value(number("5"))), t.quantity);
+    assert!(!t.percent);
+    assert!(t.with_ties);
+}
+
+#[test]
+fn parse_mssql_try_cast() {
+    let sql = "SELECT TRY_CAST('1' AS INT)";
+    let select = ms_and_generic().verified_only_select(sql);
+    assert_eq!(
+        &Expr::Cast {
+            kind: CastKind::TryCast,
+            expr: Box::new(Expr::Value(Value::SingleQuotedString("1".to_string()))),
+            data_type: DataType::Int,
+            format: None,
+        },
+        expr_from_projection(only(&select.projection))
+    );
+}
+
 fn ms() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(MsSqlDialect {})],
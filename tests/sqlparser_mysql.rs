@@ -19,7 +19,8 @@ mod test_utils;
 use test_utils::*;
 
 use sqlparser::ast::*;
-use sqlparser::dialect::{GenericDialect, MySqlDialect};
+use sqlparser::dialect::{GenericDialect, MySqlDialect, PostgreSqlDialect};
+use sqlparser::parser::ParserError;
 use sqlparser::tokenizer::Token;
 
 #[test]
@@ -100,6 +101,223 @@ fn parse_show_columns() {
     }
 }
 
+#[test]
+fn parse_show_tables() {
+    assert_eq!(
+        mysql_and_generic().verified_stmt("SHOW TABLES"),
+        Statement::ShowTables {
+            full: false,
+            db_name: None,
+            filter: None,
+        }
+    );
+    assert_eq!(
+        mysql_and_generic().verified_stmt("SHOW FULL TABLES FROM mydb LIKE 'pattern'"),
+        Statement::ShowTables {
+            full: true,
+            db_name: Some(ObjectName(vec![Ident::new("mydb")])),
+            filter: Some(ShowStatementFilter::Like("pattern".into())),
+        }
+    );
+    mysql_and_generic().one_statement_parses_to("SHOW TABLES IN mydb", "SHOW TABLES FROM mydb");
+}
+
+#[test]
+fn parse_show_variables() {
+    assert_eq!(
+        mysql_and_generic().verified_stmt("SHOW VARIABLES"),
+        Statement::ShowVariables { filter: None }
+    );
+    assert_eq!(
+        mysql_and_generic().verified_stmt("SHOW VARIABLES LIKE 'x'"),
+        Statement::ShowVariables {
+            filter: Some(ShowStatementFilter::Like("x".into())),
+        }
+    );
+}
+
+#[test]
+fn parse_replace_into() {
+    match mysql_and_generic().verified_stmt("REPLACE INTO t (a, b) VALUES (1, 2)") {
+        Statement::Insert {
+            table_name,
+            columns,
+            replace_into,
+            overwrite,
+            or,
+            ..
+        } => {
+            assert_eq!("t", table_name.to_string());
+            assert_eq!(vec![Ident::new("a"), Ident::new("b")], columns);
+            assert!(replace_into);
+            assert!(!overwrite);
+            assert_eq!(None, or);
+        }
+        _ => unreachable!(),
+    }
+
+    // REPLACE is not a statement in other dialects
+    let res = TestedDialects {
+        dialects: vec![Box::new(PostgreSqlDialect {})],
+    }
+    .parse_sql_statements("REPLACE INTO t VALUES (1)");
+    assert!(res.is_err());
+
+    // REPLACE only starts a statement, it doesn't follow INSERT
+    assert_eq!(
+        ParserError::ParserError("Expected one of INTO or OVERWRITE, found: REPLACE".to_string()),
+        mysql_and_generic()
+            .parse_sql_statements("INSERT REPLACE INTO t VALUES (1)")
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn parse_insert_set() {
+    let sql = "INSERT INTO t SET a = 1, b = 'x'";
+    match mysql_and_generic().verified_stmt(sql) {
+        Statement::Insert {
+            table_name,
+            columns,
+            source,
+            assignments,
+            ..
+        } => {
+            assert_eq!("t", table_name.to_string());
+            assert!(columns.is_empty());
+            assert_eq!(None, source);
+            assert_eq!(
+                vec![
+                    Assignment {
+                        target: AssignmentTarget::ColumnName(Ident::new("a")),
+                        value: Expr::Value(number("1")),
+                    },
+                    Assignment {
+                        target: AssignmentTarget::ColumnName(Ident::new("b")),
+                        value: Expr::Value(Value::SingleQuotedString("x".to_string())),
+                    },
+                ],
+                assignments
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    // `INSERT ... SET` is a MySQL extension
+    let res = TestedDialects {
+        dialects: vec![Box::new(PostgreSqlDialect {})],
+    }
+    .parse_sql_statements(sql);
+    assert!(res.is_err());
+}
+
+#[test]
+fn parse_insert_values_alias() {
+    let sql = "INSERT INTO t (a, b) VALUES (1, 2) AS new (a, b)";
+    match mysql_and_generic().verified_stmt(sql) {
+        Statement::Insert { values_alias, .. } => assert_eq!(
+            Some(TableAlias {
+                name: Ident::new("new"),
+                columns: vec![Ident::new("a"), Ident::new("b")],
+            }),
+            values_alias
+        ),
+        _ => unreachable!(),
+    }
+
+    match mysql().verified_stmt("INSERT INTO t VALUES (1, 2), (3, 4) AS r") {
+        Statement::Insert { values_alias, .. } => assert_eq!(
+            Some(TableAlias {
+                name: Ident::new("r"),
+                columns: vec![],
+            }),
+            values_alias
+        ),
+        _ => unreachable!(),
+    }
+
+    match mysql().verified_stmt("INSERT INTO t VALUES (1, 2)") {
+        Statement::Insert { values_alias, .. } => assert_eq!(None, values_alias),
+        _ => unreachable!(),
+    }
+
+    // Row aliases are a MySQL extension
+    let res = TestedDialects {
+        dialects: vec![Box::new(PostgreSqlDialect {})],
+    }
+    .parse_sql_statements(sql);
+    assert!(res.is_err());
+
+    // ...and need their `AS`
+    let res = mysql().parse_sql_statements("INSERT INTO t VALUES (1, 2) r");
+    assert_eq!(
+        ParserError::ParserError("Expected end of statement, found: r".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_create_table_enum_and_set() {
+    let sql = "CREATE TABLE foo (e ENUM('a', 'b', 'c'), s SET('x', 'y''z'))";
+    match mysql_and_generic().verified_stmt(sql) {
+        Statement::CreateTable { columns, .. } => {
+            assert_eq!(
+                DataType::Enum(vec!["a".to_string(), "b".to_string(), "c".to_string()]),
+                columns[0].data_type
+            );
+            assert_eq!(
+                DataType::Set(vec!["x".to_string(), "y'z".to_string()]),
+                columns[1].data_type
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    let res = mysql().parse_sql_statements("CREATE TABLE foo (e ENUM(a, b))");
+    assert_eq!(
+        ParserError::ParserError("Expected a string literal, found: a".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_match_against() {
+    let select = mysql_and_generic()
+        .verified_only_select("SELECT * FROM t WHERE MATCH (a, b) AGAINST ('x' IN BOOLEAN MODE)");
+    assert_eq!(
+        Some(Expr::MatchAgainst {
+            columns: vec![Ident::new("a"), Ident::new("b")],
+            match_value: Value::SingleQuotedString("x".to_string()),
+            modifier: Some(SearchModifier::InBooleanMode),
+        }),
+        select.selection
+    );
+
+    let select = mysql_and_generic().verified_only_select(
+        "SELECT MATCH (body) AGAINST ('database' IN NATURAL LANGUAGE MODE) AS score FROM t",
+    );
+    match &select.projection[0] {
+        SelectItem::ExprWithAlias {
+            expr: Expr::MatchAgainst { modifier, .. },
+            ..
+        } => assert_eq!(&Some(SearchModifier::InNaturalLanguageMode), modifier),
+        _ => unreachable!(),
+    }
+
+    let select =
+        mysql_and_generic().verified_only_select("SELECT * FROM t WHERE MATCH (a) AGAINST ('x')");
+    match select.selection {
+        Some(Expr::MatchAgainst { modifier, .. }) => assert_eq!(None, modifier),
+        _ => unreachable!(),
+    }
+
+    mysql_and_generic().verified_stmt(
+        "SELECT * FROM t WHERE MATCH (a) AGAINST ('x' IN NATURAL LANGUAGE MODE WITH QUERY EXPANSION)",
+    );
+    mysql_and_generic()
+        .verified_stmt("SELECT * FROM t WHERE MATCH (a) AGAINST ('x' WITH QUERY EXPANSION)");
+}
+
 #[test]
 fn parse_create_table_auto_increment() {
     let sql = "CREATE TABLE foo (bar INT PRIMARY KEY AUTO_INCREMENT)";
@@ -111,6 +329,7 @@ fn parse_create_table_auto_increment() {
                     name: Ident::new("bar"),
                     data_type: DataType::Int,
                     collation: None,
+                    leading_comments: vec![],
                     options: vec![
                         ColumnOptionDef {
                             name: None,
@@ -131,6 +350,201 @@ fn parse_create_table_auto_increment() {
     }
 }
 
+#[test]
+fn parse_create_table_column_comment() {
+    let sql = "CREATE TABLE foo (bar INT NOT NULL COMMENT 'the bar''s id', baz TEXT)";
+    match mysql().verified_stmt(sql) {
+        Statement::CreateTable { columns, .. } => {
+            assert_eq!(
+                vec![
+                    ColumnOptionDef {
+                        name: None,
+                        option: ColumnOption::NotNull,
+                    },
+                    ColumnOptionDef {
+                        name: None,
+                        option: ColumnOption::Comment("the bar's id".to_string()),
+                    },
+                ],
+                columns[0].options
+            );
+            assert!(columns[1].options.is_empty());
+        }
+        _ => unreachable!(),
+    }
+
+    assert_eq!(
+        ParserError::ParserError("Expected a string after COMMENT, found: 1".to_string()),
+        mysql()
+            .parse_sql_statements("CREATE TABLE foo (bar INT COMMENT 1)")
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn parse_create_table_end_to_end() {
+    let sql = concat!(
+        "CREATE TABLE IF NOT EXISTS orders (",
+        "id INT NOT NULL AUTO_INCREMENT, ",
+        "customer_id INT NOT NULL, ",
+        "region CHARACTER VARYING(8) NOT NULL DEFAULT 'eu' COMMENT 'sales region', ",
+        "total NUMERIC(10,2) CHECK (total >= 0), ",
+        "status ENUM('new', 'paid') NOT NULL, ",
+        "created_at TIMESTAMP NULL, ",
+        "CONSTRAINT pk_orders PRIMARY KEY (id, region), ",
+        "CONSTRAINT fk_customer FOREIGN KEY (customer_id) REFERENCES customers(id) ",
+        "ON DELETE CASCADE ON UPDATE SET NULL, ",
+        "CONSTRAINT positive_total CHECK (total > 0)",
+        ") ENGINE=InnoDB DEFAULT CHARSET=utf8mb4"
+    );
+    let column = |name: &str, data_type: DataType, options: Vec<ColumnOption>| ColumnDef {
+        name: Ident::new(name),
+        data_type,
+        collation: None,
+        leading_comments: vec![],
+        options: options
+            .into_iter()
+            .map(|option| ColumnOptionDef { name: None, option })
+            .collect(),
+    };
+    match mysql().verified_stmt(sql) {
+        Statement::CreateTable {
+            name,
+            if_not_exists,
+            temporary,
+            columns,
+            constraints,
+            with_options,
+            query,
+            engine,
+            default_charset,
+            ..
+        } => {
+            assert_eq!("orders", name.to_string());
+            assert!(if_not_exists);
+            assert!(!temporary);
+            assert_eq!(
+                vec![
+                    column(
+                        "id",
+                        DataType::Int,
+                        vec![
+                            ColumnOption::NotNull,
+                            ColumnOption::DialectSpecific(vec![Token::make_keyword(
+                                "AUTO_INCREMENT"
+                            )]),
+                        ]
+                    ),
+                    column("customer_id", DataType::Int, vec![ColumnOption::NotNull]),
+                    column(
+                        "region",
+                        DataType::Varchar(Some(8)),
+                        vec![
+                            ColumnOption::NotNull,
+                            ColumnOption::Default(Expr::Value(Value::SingleQuotedString(
+                                "eu".to_string()
+                            ))),
+                            ColumnOption::Comment("sales region".to_string()),
+                        ]
+                    ),
+                    column(
+                        "total",
+                        DataType::Decimal(Some(10), Some(2)),
+                        vec![ColumnOption::Check(Expr::BinaryOp {
+                            left: ident("total"),
+                            op: BinaryOperator::GtEq,
+                            right: Box::new(Expr::Value(number("0"))),
+                        })]
+                    ),
+                    column(
+                        "status",
+                        DataType::Enum(vec!["new".to_string(), "paid".to_string()]),
+                        vec![ColumnOption::NotNull]
+                    ),
+                    column(
+                        "created_at",
+                        DataType::Timestamp { tz: false },
+                        vec![ColumnOption::Null]
+                    ),
+                ],
+                columns
+            );
+            assert_eq!(
+                vec![
+                    TableConstraint::Unique {
+                        name: Some(Ident::new("pk_orders")),
+                        columns: vec![Ident::new("id"), Ident::new("region")],
+                        is_primary: true,
+                        characteristics: None,
+                    },
+                    TableConstraint::ForeignKey {
+                        name: Some(Ident::new("fk_customer")),
+                        columns: vec![Ident::new("customer_id")],
+                        foreign_table: ObjectName(vec![Ident::new("customers")]),
+                        referred_columns: vec![Ident::new("id")],
+                        on_delete: Some(ReferentialAction::Cascade),
+                        on_update: Some(ReferentialAction::SetNull),
+                        characteristics: None,
+                    },
+                    TableConstraint::Check {
+                        name: Some(Ident::new("positive_total")),
+                        expr: Box::new(Expr::BinaryOp {
+                            left: ident("total"),
+                            op: BinaryOperator::Gt,
+                            right: Box::new(Expr::Value(number("0"))),
+                        }),
+                    },
+                ],
+                constraints
+            );
+            assert!(with_options.is_empty());
+            assert_eq!(None, query);
+            assert_eq!(Some(Ident::new("InnoDB")), engine);
+            assert_eq!(Some(Ident::new("utf8mb4")), default_charset);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_create_table_engine_and_charset() {
+    mysql_and_generic().one_statement_parses_to(
+        "CREATE TABLE t (a INT) CHARACTER SET = utf8mb4 ENGINE InnoDB",
+        "CREATE TABLE t (a INT) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4",
+    );
+    mysql_and_generic().one_statement_parses_to(
+        "CREATE TABLE t (a INT) DEFAULT CHARACTER SET latin1",
+        "CREATE TABLE t (a INT) DEFAULT CHARSET=latin1",
+    );
+    mysql_and_generic()
+        .verified_stmt("CREATE TABLE t (a INT) ENGINE=MyISAM DEFAULT CHARSET=latin1 AS SELECT 1");
+
+    // Other dialects don't know the table options
+    let res = TestedDialects {
+        dialects: vec![Box::new(PostgreSqlDialect {})],
+    }
+    .parse_sql_statements("CREATE TABLE t (a INT) ENGINE=InnoDB");
+    assert!(res.is_err());
+
+    let res = mysql_and_generic()
+        .parse_sql_statements("CREATE TABLE t (a INT) ENGINE=InnoDB ENGINE=MyISAM");
+    assert_eq!(
+        ParserError::ParserError(
+            "Cannot specify ENGINE more than once in CREATE TABLE".to_string()
+        ),
+        res.unwrap_err()
+    );
+    let res = mysql_and_generic().parse_sql_statements(
+        "CREATE TABLE t (a INT) CHARSET=utf8mb4 DEFAULT CHARACTER SET latin1",
+    );
+    assert_eq!(
+        ParserError::ParserError(
+            "Cannot specify CHARSET more than once in CREATE TABLE".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_quote_identifiers() {
     let sql = "CREATE TABLE `PRIMARY` (`BEGIN` INT PRIMARY KEY)";
@@ -142,6 +556,7 @@ fn parse_quote_identifiers() {
                     name: Ident::with_quote('`', "BEGIN"),
                     data_type: DataType::Int,
                     collation: None,
+                    leading_comments: vec![],
                     options: vec![ColumnOptionDef {
                         name: None,
                         option: ColumnOption::Unique { is_primary: true }
@@ -154,6 +569,69 @@ fn parse_quote_identifiers() {
     }
 }
 
+#[test]
+fn parse_group_concat() {
+    let sql = "SELECT GROUP_CONCAT(name SEPARATOR ', ') FROM t";
+    let select = mysql_and_generic().verified_only_select(sql);
+    assert_eq!(
+        &Expr::GroupConcat(GroupConcat {
+            distinct: false,
+            exprs: vec![Expr::Identifier(Ident::new("name"))],
+            order_by: vec![],
+            separator: Some(", ".to_string()),
+        }),
+        expr_from_projection(only(&select.projection))
+    );
+
+    let sql = "SELECT GROUP_CONCAT(DISTINCT first, last ORDER BY last DESC SEPARATOR ';') FROM t";
+    let select = mysql_and_generic().verified_only_select(sql);
+    assert_eq!(
+        &Expr::GroupConcat(GroupConcat {
+            distinct: true,
+            exprs: vec![
+                Expr::Identifier(Ident::new("first")),
+                Expr::Identifier(Ident::new("last")),
+            ],
+            order_by: vec![OrderByExpr {
+                expr: Expr::Identifier(Ident::new("last")),
+                asc: Some(false),
+                nulls_first: None,
+                using: None,
+            }],
+            separator: Some(";".to_string()),
+        }),
+        expr_from_projection(only(&select.projection))
+    );
+
+    mysql_and_generic().verified_stmt("SELECT GROUP_CONCAT(name) FROM t");
+
+    let res = mysql().parse_sql_statements("SELECT GROUP_CONCAT(name SEPARATOR x) FROM t");
+    assert_eq!(
+        ParserError::ParserError("Expected a string literal after SEPARATOR, found: x".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_describe() {
+    assert_eq!(
+        Statement::Describe {
+            table_name: ObjectName(vec![Ident::new("mydb"), Ident::with_quote('`', "t")]),
+        },
+        mysql_and_generic().verified_stmt("DESCRIBE mydb.`t`")
+    );
+    mysql_and_generic().one_statement_parses_to("DESC t", "DESCRIBE t");
+
+    let res = TestedDialects {
+        dialects: vec![Box::new(PostgreSqlDialect {})],
+    }
+    .parse_sql_statements("DESCRIBE t");
+    assert_eq!(
+        ParserError::ParserError("Expected an SQL statement, found: DESCRIBE".to_string()),
+        res.unwrap_err()
+    );
+}
+
 fn mysql() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(MySqlDialect {})],
@@ -165,3 +643,46 @@ fn mysql_and_generic() -> TestedDialects {
         dialects: vec![Box::new(MySqlDialect {}), Box::new(GenericDialect {})],
     }
 }
+
+#[test]
+fn parse_varchar_requires_length() {
+    mysql().verified_stmt("CREATE TABLE t (a CHARACTER VARYING(255))");
+    assert_eq!(
+        ParserError::ParserError("Type VARCHAR requires a length".to_string()),
+        mysql()
+            .parse_sql_statements("CREATE TABLE t (a VARCHAR)")
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn parse_derived_table_requires_alias() {
+    let select = mysql().verified_only_select("SELECT * FROM (SELECT 1 AS a) AS t");
+    match &only(&select.from).relation {
+        TableFactor::Derived { alias, .. } => {
+            assert_eq!(
+                Some(Ident::new("t")),
+                alias.as_ref().map(|a| a.name.clone())
+            )
+        }
+        _ => unreachable!(),
+    }
+
+    let res = mysql().parse_sql_statements("SELECT * FROM (SELECT 1 AS a) WHERE a = 1");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected an alias for the subquery in FROM, found: WHERE".to_string()
+        ),
+        res.unwrap_err()
+    );
+
+    // other dialects accept a derived table without an alias
+    let generic = TestedDialects {
+        dialects: vec![Box::new(GenericDialect {})],
+    };
+    let select = generic.verified_only_select("SELECT * FROM (SELECT 1 AS a) WHERE a = 1");
+    assert!(matches!(
+        only(&select.from).relation,
+        TableFactor::Derived { alias: None, .. }
+    ));
+}
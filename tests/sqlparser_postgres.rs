@@ -19,7 +19,7 @@ mod test_utils;
 use test_utils::*;
 
 use sqlparser::ast::*;
-use sqlparser::dialect::{GenericDialect, PostgreSqlDialect};
+use sqlparser::dialect::{GenericDialect, MySqlDialect, PostgreSqlDialect};
 use sqlparser::parser::ParserError;
 
 #[test]
@@ -56,6 +56,7 @@ fn parse_create_table_with_defaults() {
                         name: "customer_id".into(),
                         data_type: DataType::Int,
                         collation: None,
+                        leading_comments: vec![],
                         options: vec![ColumnOptionDef {
                             name: None,
                             option: ColumnOption::Default(
@@ -67,6 +68,7 @@ fn parse_create_table_with_defaults() {
                         name: "store_id".into(),
                         data_type: DataType::SmallInt,
                         collation: None,
+                        leading_comments: vec![],
                         options: vec![ColumnOptionDef {
                             name: None,
                             option: ColumnOption::NotNull,
@@ -76,6 +78,7 @@ fn parse_create_table_with_defaults() {
                         name: "first_name".into(),
                         data_type: DataType::Varchar(Some(45)),
                         collation: None,
+                        leading_comments: vec![],
                         options: vec![ColumnOptionDef {
                             name: None,
                             option: ColumnOption::NotNull,
@@ -85,6 +88,7 @@ fn parse_create_table_with_defaults() {
                         name: "last_name".into(),
                         data_type: DataType::Varchar(Some(45)),
                         collation: Some(ObjectName(vec![Ident::with_quote('"', "es_ES")])),
+                        leading_comments: vec![],
                         options: vec![ColumnOptionDef {
                             name: None,
                             option: ColumnOption::NotNull,
@@ -94,12 +98,14 @@ fn parse_create_table_with_defaults() {
                         name: "email".into(),
                         data_type: DataType::Varchar(Some(50)),
                         collation: None,
+                        leading_comments: vec![],
                         options: vec![],
                     },
                     ColumnDef {
                         name: "address_id".into(),
                         data_type: DataType::SmallInt,
                         collation: None,
+                        leading_comments: vec![],
                         options: vec![ColumnOptionDef {
                             name: None,
                             option: ColumnOption::NotNull
@@ -109,6 +115,7 @@ fn parse_create_table_with_defaults() {
                         name: "activebool".into(),
                         data_type: DataType::Boolean,
                         collation: None,
+                        leading_comments: vec![],
                         options: vec![
                             ColumnOptionDef {
                                 name: None,
@@ -124,6 +131,7 @@ fn parse_create_table_with_defaults() {
                         name: "create_date".into(),
                         data_type: DataType::Date,
                         collation: None,
+                        leading_comments: vec![],
                         options: vec![
                             ColumnOptionDef {
                                 name: None,
@@ -139,8 +147,9 @@ fn parse_create_table_with_defaults() {
                     },
                     ColumnDef {
                         name: "last_update".into(),
-                        data_type: DataType::Timestamp,
+                        data_type: DataType::Timestamp { tz: false },
                         collation: None,
+                        leading_comments: vec![],
                         options: vec![
                             ColumnOptionDef {
                                 name: None,
@@ -156,6 +165,7 @@ fn parse_create_table_with_defaults() {
                         name: "active".into(),
                         data_type: DataType::Int,
                         collation: None,
+                        leading_comments: vec![],
                         options: vec![ColumnOptionDef {
                             name: None,
                             option: ColumnOption::NotNull
@@ -357,6 +367,99 @@ PHP	₱ USD $
     //assert_eq!(sql, ast.to_string());
 }
 
+#[test]
+fn parse_copy_from_stdin() {
+    let sql = "COPY t FROM STDIN";
+    assert_eq!(
+        pg_and_generic().verified_stmt(sql),
+        Statement::Copy {
+            table_name: ObjectName(vec!["t".into()]),
+            columns: vec![],
+            to: false,
+            target: CopyTarget::Stdin,
+            options: vec![],
+            values: vec![],
+        }
+    );
+
+    pg_and_generic()
+        .one_statement_parses_to("COPY t (a, b) FROM stdin", "COPY t (a, b) FROM STDIN");
+}
+
+#[test]
+fn parse_copy_to_file() {
+    let sql = "COPY t (a, b) TO '/tmp/t.csv' WITH (FORMAT csv, HEADER, DELIMITER ';')";
+    assert_eq!(
+        pg_and_generic().verified_stmt(sql),
+        Statement::Copy {
+            table_name: ObjectName(vec!["t".into()]),
+            columns: vec!["a".into(), "b".into()],
+            to: true,
+            target: CopyTarget::File {
+                filename: "/tmp/t.csv".to_string(),
+            },
+            options: vec![
+                CopyOption {
+                    name: "FORMAT".into(),
+                    value: Some(Expr::Identifier("csv".into())),
+                },
+                CopyOption {
+                    name: "HEADER".into(),
+                    value: None,
+                },
+                CopyOption {
+                    name: "DELIMITER".into(),
+                    value: Some(Expr::Value(Value::SingleQuotedString(";".to_string()))),
+                },
+            ],
+            values: vec![],
+        }
+    );
+
+    match pg_and_generic().verified_stmt("COPY t FROM STDIN WITH (DELIMITER ',', QUOTE '\"')") {
+        Statement::Copy { options, .. } => assert_eq!(
+            vec![
+                CopyOption {
+                    name: "DELIMITER".into(),
+                    value: Some(Expr::Value(Value::SingleQuotedString(",".to_string()))),
+                },
+                CopyOption {
+                    name: "QUOTE".into(),
+                    value: Some(Expr::Value(Value::SingleQuotedString("\"".to_string()))),
+                },
+            ],
+            options
+        ),
+        _ => unreachable!(),
+    }
+    assert_eq!(
+        ParserError::ParserError(
+            "COPY option DELIMITER must be a single character, found: ';;'".to_string()
+        ),
+        pg_and_generic()
+            .parse_sql_statements("COPY t TO STDOUT WITH (DELIMITER ';;')")
+            .unwrap_err()
+    );
+    assert_eq!(
+        ParserError::ParserError("Expected literal string, found: 1".to_string()),
+        pg_and_generic()
+            .parse_sql_statements("COPY t TO STDOUT WITH (quote 1)")
+            .unwrap_err()
+    );
+
+    pg_and_generic().one_statement_parses_to(
+        "COPY t FROM '/tmp/t.bin' (FORMAT binary)",
+        "COPY t FROM '/tmp/t.bin' WITH (FORMAT binary)",
+    );
+    pg_and_generic().verified_stmt("COPY t TO STDOUT WITH (FORMAT csv)");
+
+    let res = pg().parse_sql_statements("COPY t INTO STDOUT");
+    assert_eq!(
+        ParserError::ParserError("Expected FROM or TO, found: INTO".to_string()),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_set() {
     let stmt = pg_and_generic().verified_stmt("SET a = b");
@@ -523,6 +626,47 @@ fn parse_execute() {
     );
 }
 
+#[test]
+fn parse_prepare_with_parameters() {
+    match pg_and_generic().verified_stmt("PREPARE p (INT) AS SELECT $1") {
+        Statement::Prepare {
+            name,
+            data_types,
+            statement,
+        } => {
+            assert_eq!(Ident::new("p"), name);
+            assert_eq!(vec![DataType::Int], data_types);
+            match *statement {
+                Statement::Query(query) => match query.body {
+                    SetExpr::Select(select) => assert_eq!(
+                        &Expr::Parameter("$1".to_string()),
+                        expr_from_projection(only(&select.projection))
+                    ),
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            }
+        }
+        _ => unreachable!(),
+    }
+    pg().verified_stmt("PREPARE q (INT, TEXT) AS UPDATE t SET b = $2 WHERE a = $1");
+
+    assert_eq!(
+        Statement::Execute {
+            name: Ident::new("p"),
+            parameters: vec![Expr::Value(number("1"))],
+        },
+        pg_and_generic().verified_stmt("EXECUTE p(1)")
+    );
+    assert_eq!(
+        Statement::Deallocate {
+            name: Ident::new("p"),
+            prepare: false,
+        },
+        pg_and_generic().verified_stmt("DEALLOCATE p")
+    );
+}
+
 #[test]
 fn parse_prepare() {
     let stmt =
@@ -674,6 +818,191 @@ fn parse_pg_regex_match_ops() {
     }
 }
 
+#[test]
+fn parse_create_table_partition_by() {
+    let sql = "CREATE TABLE measurement (city_id INT, logdate DATE) PARTITION BY RANGE (logdate)";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateTable { partition_by, .. } => assert_eq!(
+            Some(PartitionSpec {
+                strategy: PartitionStrategy::Range,
+                columns: vec![Expr::Identifier("logdate".into())],
+            }),
+            partition_by
+        ),
+        _ => unreachable!(),
+    }
+
+    let sql = "CREATE TABLE orders (id INT, region TEXT) PARTITION BY HASH (id, (lower(region)))";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateTable { partition_by, .. } => {
+            let partition_by = partition_by.unwrap();
+            assert_eq!(PartitionStrategy::Hash, partition_by.strategy);
+            assert_eq!(2, partition_by.columns.len());
+            assert!(matches!(partition_by.columns[1], Expr::Nested(_)));
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt("CREATE TABLE cities (name TEXT) PARTITION BY LIST (name)");
+
+    let res = pg().parse_sql_statements("CREATE TABLE t (a INT) PARTITION BY a");
+    assert_eq!(
+        ParserError::ParserError("Expected one of RANGE or LIST or HASH, found: a".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_create_index_using_method() {
+    for method in &["btree", "gin"] {
+        let sql = format!("CREATE INDEX idx ON t USING {} (col)", method);
+        match pg_and_generic().verified_stmt(&sql) {
+            Statement::CreateIndex { using, columns, .. } => {
+                assert_eq!(Some(Ident::new(*method)), using);
+                assert_eq!(1, columns.len());
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    pg_and_generic().one_statement_parses_to(
+        "CREATE INDEX idx ON t USING gist(a, b) WHERE a > 0",
+        "CREATE INDEX idx ON t USING gist (a,b) WHERE a > 0",
+    );
+}
+
+#[test]
+fn parse_order_by_using() {
+    let sql = "SELECT a FROM t ORDER BY a USING > NULLS LAST, b";
+    let query = match pg_and_generic().verified_stmt(sql) {
+        Statement::Query(query) => query,
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        vec![
+            OrderByExpr {
+                expr: Expr::Identifier("a".into()),
+                asc: None,
+                nulls_first: Some(false),
+                using: Some(BinaryOperator::Gt),
+            },
+            OrderByExpr {
+                expr: Expr::Identifier("b".into()),
+                asc: None,
+                nulls_first: None,
+                using: None,
+            },
+        ],
+        query.order_by
+    );
+
+    pg().verified_stmt("SELECT a FROM t ORDER BY a USING <");
+
+    // USING takes the place of ASC or DESC
+    let res = pg().parse_sql_statements("SELECT a FROM t ORDER BY a DESC USING <");
+    assert_eq!(
+        ParserError::ParserError("Expected end of statement, found: USING".to_string()),
+        res.unwrap_err()
+    );
+    let res = pg().parse_sql_statements("SELECT a FROM t ORDER BY a USING +");
+    assert_eq!(
+        ParserError::ParserError("Expected an ordering operator after USING, found: +".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_create_table_with_pg_types() {
+    let sql = "CREATE TABLE t (\
+               id SERIAL, \
+               big BIGSERIAL, \
+               u UUID, \
+               j JSON, \
+               jb JSONB, \
+               ip INET, \
+               net CIDR, \
+               mac MACADDR, \
+               price MONEY, \
+               b BYTEA)";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateTable { columns, .. } => {
+            let types: Vec<DataType> = columns.into_iter().map(|c| c.data_type).collect();
+            assert_eq!(
+                vec![
+                    DataType::Serial,
+                    DataType::BigSerial,
+                    DataType::Uuid,
+                    DataType::Json,
+                    DataType::Jsonb,
+                    DataType::Inet,
+                    DataType::Cidr,
+                    DataType::Macaddr,
+                    DataType::Money,
+                    DataType::Bytea,
+                ],
+                types
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    // other dialects treat these as custom types
+    let mysql = TestedDialects {
+        dialects: vec![Box::new(MySqlDialect {})],
+    };
+    match mysql.verified_stmt("CREATE TABLE t (j JSONB)") {
+        Statement::CreateTable { columns, .. } => assert_eq!(
+            DataType::Custom(ObjectName(vec![Ident::new("JSONB")])),
+            columns[0].data_type
+        ),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_unicode_identifiers() {
+    let select = pg_and_generic().verified_only_select("SELECT café FROM 表");
+    assert_eq!(
+        &Expr::Identifier(Ident::new("café")),
+        expr_from_projection(only(&select.projection))
+    );
+    assert_eq!(
+        select.from[0].relation,
+        TableFactor::Table {
+            name: ObjectName(vec![Ident::new("表")]),
+            alias: None,
+            args: vec![],
+            with_hints: vec![],
+        }
+    );
+}
+
+#[test]
+fn parse_reserved_user_keyword() {
+    // USER is reserved in PostgreSQL, so it can't be a bare column or alias...
+    assert_eq!(
+        ParserError::ParserError("Expected an expression, found: user".to_string()),
+        pg().parse_sql_statements("SELECT user FROM t").unwrap_err()
+    );
+    assert_eq!(
+        ParserError::ParserError("Expected an identifier after AS, found: user".to_string()),
+        pg().parse_sql_statements("SELECT a AS user FROM t")
+            .unwrap_err()
+    );
+    // ...unless it's quoted
+    pg().verified_stmt(r#"SELECT "user" AS "user" FROM t"#);
+
+    // while the generic dialect accepts it as an ordinary identifier
+    let generic = TestedDialects {
+        dialects: vec![Box::new(GenericDialect {})],
+    };
+    let select = generic.verified_only_select("SELECT user FROM t");
+    assert_eq!(
+        &Expr::Identifier(Ident::new("user")),
+        expr_from_projection(only(&select.projection))
+    );
+}
+
 fn pg() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(PostgreSqlDialect {})],
@@ -685,3 +1014,874 @@ fn pg_and_generic() -> TestedDialects {
         dialects: vec![Box::new(PostgreSqlDialect {}), Box::new(GenericDialect {})],
     }
 }
+
+#[test]
+fn parse_array_subscripts() {
+    let num = |s: &'static str| Some(Box::new(Expr::Value(number(s))));
+
+    let select = pg_and_generic().verified_only_select("SELECT a[1:3], a[:2], a[2:] FROM t");
+    assert_eq!(
+        &Expr::ArraySlice {
+            obj: ident("a"),
+            lower: num("1"),
+            upper: num("3"),
+        },
+        expr_from_projection(&select.projection[0])
+    );
+    assert_eq!(
+        &Expr::ArraySlice {
+            obj: ident("a"),
+            lower: None,
+            upper: num("2"),
+        },
+        expr_from_projection(&select.projection[1])
+    );
+    assert_eq!(
+        &Expr::ArraySlice {
+            obj: ident("a"),
+            lower: num("2"),
+            upper: None,
+        },
+        expr_from_projection(&select.projection[2])
+    );
+
+    let select = pg_and_generic().verified_only_select("SELECT a[i + 1], a[1][2:3] FROM t");
+    assert_eq!(
+        &Expr::ArrayIndex {
+            obj: ident("a"),
+            index: Box::new(Expr::BinaryOp {
+                left: ident("i"),
+                op: BinaryOperator::Plus,
+                right: Box::new(Expr::Value(number("1"))),
+            }),
+        },
+        expr_from_projection(&select.projection[0])
+    );
+    assert_eq!(
+        &Expr::ArraySlice {
+            obj: Box::new(Expr::ArrayIndex {
+                obj: ident("a"),
+                index: Box::new(Expr::Value(number("1"))),
+            }),
+            lower: num("2"),
+            upper: num("3"),
+        },
+        expr_from_projection(&select.projection[1])
+    );
+
+    pg().verified_only_select("SELECT a[:] FROM t");
+
+    // A chained string key is kept as an index into the previous subscript
+    let select = pg_and_generic().verified_only_select("SELECT a[1]['x'] FROM t");
+    assert_eq!(
+        &Expr::ArrayIndex {
+            obj: Box::new(Expr::ArrayIndex {
+                obj: ident("a"),
+                index: Box::new(Expr::Value(number("1"))),
+            }),
+            index: Box::new(Expr::Value(Value::SingleQuotedString("x".to_string()))),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+}
+
+#[test]
+fn parse_insert_on_conflict_row_assignment() {
+    let sql = "INSERT INTO t VALUES (1, 2) ON CONFLICT (a) DO UPDATE SET (a, b) = (3, 4), c = 5";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::Insert {
+            on_conflict:
+                Some(OnConflict {
+                    action: OnConflictAction::DoUpdate { assignments, .. },
+                    ..
+                }),
+            ..
+        } => assert_eq!(
+            vec![
+                AssignmentTarget::Tuple(vec![Ident::new("a"), Ident::new("b")]),
+                AssignmentTarget::ColumnName(Ident::new("c")),
+            ],
+            assignments
+                .into_iter()
+                .map(|a| a.target)
+                .collect::<Vec<_>>()
+        ),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_insert_on_conflict() {
+    match pg_and_generic()
+        .verified_stmt("INSERT INTO t VALUES (1) ON CONFLICT ON CONSTRAINT uq DO NOTHING")
+    {
+        Statement::Insert { on_conflict, .. } => assert_eq!(
+            Some(OnConflict {
+                conflict_target: Some(ConflictTarget::OnConstraint(ObjectName(vec![Ident::new(
+                    "uq"
+                )]))),
+                action: OnConflictAction::DoNothing,
+            }),
+            on_conflict
+        ),
+        _ => unreachable!(),
+    }
+
+    let sql = "INSERT INTO t (a, x) VALUES (1, 2) ON CONFLICT (a) WHERE active DO UPDATE SET x = 1";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::Insert { on_conflict, .. } => assert_eq!(
+            Some(OnConflict {
+                conflict_target: Some(ConflictTarget::Columns {
+                    columns: vec![Ident::new("a")],
+                    predicate: Some(Expr::Identifier(Ident::new("active"))),
+                }),
+                action: OnConflictAction::DoUpdate {
+                    assignments: vec![Assignment {
+                        target: AssignmentTarget::ColumnName(Ident::new("x")),
+                        value: Expr::Value(number("1")),
+                    }],
+                    selection: None,
+                },
+            }),
+            on_conflict
+        ),
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt("INSERT INTO t SELECT * FROM u ON CONFLICT DO NOTHING");
+    pg().verified_stmt(
+        "INSERT INTO t VALUES (1) ON CONFLICT (a, b) DO UPDATE SET x = 1, y = 2 WHERE t.z > 0",
+    );
+
+    let res = pg().parse_sql_statements("INSERT INTO t VALUES (1) ON CONFLICT (a) DO");
+    assert_eq!(
+        ParserError::ParserError("Expected UPDATE, found: EOF".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_returning() {
+    let stmt =
+        pg_and_generic().verified_stmt("INSERT INTO t (a) VALUES (1) RETURNING id AS x, t.*");
+    match stmt {
+        Statement::Insert { returning, .. } => assert_eq!(
+            Some(vec![
+                SelectItem::ExprWithAlias {
+                    expr: Expr::Identifier(Ident::new("id")),
+                    alias: Ident::new("x"),
+                },
+                SelectItem::QualifiedWildcard(
+                    ObjectName(vec![Ident::new("t")]),
+                    WildcardAdditionalOptions::default()
+                ),
+            ]),
+            returning
+        ),
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt(
+        "INSERT INTO t (a) VALUES (1) ON CONFLICT DO NOTHING RETURNING id AS new_id, *",
+    );
+    pg_and_generic()
+        .verified_stmt("UPDATE t SET a = 1 FROM u WHERE t.id = u.id RETURNING t.a, u.*");
+    pg_and_generic().verified_stmt("DELETE FROM t USING u RETURNING *");
+
+    match pg_and_generic().verified_stmt("DELETE FROM t WHERE a = 1") {
+        Statement::Delete { returning, .. } => assert_eq!(None, returning),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_cast_of_array_and_struct_literals() {
+    let select =
+        pg_and_generic().verified_only_select("SELECT ARRAY[1, 2], ARRAY[], ARRAY[ARRAY[1]]");
+    assert_eq!(
+        &Expr::Array(vec![Expr::Value(number("1")), Expr::Value(number("2"))]),
+        expr_from_projection(&select.projection[0])
+    );
+    assert_eq!(
+        &Expr::Array(vec![]),
+        expr_from_projection(&select.projection[1])
+    );
+
+    let select = pg_and_generic().verified_only_select("SELECT CAST(ARRAY[1, 2] AS INT[])");
+    let expected = Expr::Cast {
+        kind: CastKind::Cast,
+        expr: Box::new(Expr::Array(vec![
+            Expr::Value(number("1")),
+            Expr::Value(number("2")),
+        ])),
+        data_type: DataType::Array(Box::new(DataType::Int)),
+        format: None,
+    };
+    assert_eq!(&expected, expr_from_projection(only(&select.projection)));
+    pg_and_generic().one_statement_parses_to(
+        "SELECT ARRAY[1,2]::int[]",
+        "SELECT CAST(ARRAY[1, 2] AS INT[])",
+    );
+
+    // The cast binds tighter than binary operators
+    pg_and_generic().one_statement_parses_to(
+        "SELECT a || ARRAY[1]::int[][]",
+        "SELECT a || CAST(ARRAY[1] AS INT[][])",
+    );
+
+    pg_and_generic().one_statement_parses_to(
+        "SELECT STRUCT(1)::mytype",
+        "SELECT CAST(STRUCT(1) AS mytype)",
+    );
+    match pg_and_generic()
+        .verified_only_select("SELECT CAST(STRUCT(1) AS mytype)")
+        .projection[0]
+    {
+        SelectItem::UnnamedExpr(Expr::Cast {
+            ref expr,
+            ref data_type,
+            ..
+        }) => {
+            assert!(matches!(**expr, Expr::Function(_)));
+            assert_eq!(
+                &DataType::Custom(ObjectName(vec![Ident::new("mytype")])),
+                data_type
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_create_function() {
+    let sql = "CREATE FUNCTION add_one(a INT) RETURNS INT AS $$ SELECT a + 1 $$ LANGUAGE sql";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateFunction {
+            or_replace,
+            name,
+            args,
+            return_type,
+            body,
+            language,
+        } => {
+            assert!(!or_replace);
+            assert_eq!("add_one", name.to_string());
+            assert_eq!(
+                vec![CreateFunctionArg {
+                    name: Some(Ident::new("a")),
+                    data_type: DataType::Int,
+                }],
+                args
+            );
+            assert_eq!(Some(DataType::Int), return_type);
+            assert_eq!(
+                FunctionDefinition::DollarQuoted(DollarQuotedString {
+                    value: " SELECT a + 1 ".to_string(),
+                    tag: None,
+                }),
+                body
+            );
+            assert_eq!(Some(Ident::new("sql")), language);
+        }
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt(
+        "CREATE OR REPLACE FUNCTION s.f(INT, b TEXT) RETURNS TEXT AS 'SELECT ''x''' LANGUAGE sql",
+    );
+    pg().verified_stmt("CREATE FUNCTION f() AS $body$ SELECT '$$' $body$");
+    pg().one_statement_parses_to(
+        "CREATE FUNCTION f() RETURNS INT LANGUAGE sql AS $$SELECT 1$$",
+        "CREATE FUNCTION f() RETURNS INT AS $$SELECT 1$$ LANGUAGE sql",
+    );
+
+    let res = pg().parse_sql_statements("CREATE FUNCTION f() RETURNS INT LANGUAGE sql");
+    assert_eq!(
+        ParserError::ParserError("Expected AS <function body>, found: EOF".to_string()),
+        res.unwrap_err()
+    );
+    let res = pg().parse_sql_statements("CREATE FUNCTION f() AS $$SELECT 1");
+    assert_eq!(
+        ParserError::TokenizerError(
+            "Unterminated dollar-quoted string at Line: 1, Column 24".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_analyze() {
+    match pg().verified_stmt("ANALYZE t (a, b)") {
+        Statement::Analyze {
+            has_table_keyword,
+            table_name,
+            for_columns,
+            columns,
+            ..
+        } => {
+            assert!(!has_table_keyword);
+            assert_eq!("t", table_name.to_string());
+            assert!(!for_columns);
+            assert_eq!(vec![Ident::new("a"), Ident::new("b")], columns);
+        }
+        _ => unreachable!(),
+    }
+    pg().verified_stmt("ANALYZE public.t");
+}
+
+#[test]
+fn parse_vacuum() {
+    match pg().verified_stmt("VACUUM FULL ANALYZE t") {
+        Statement::Vacuum {
+            options,
+            table_name,
+            columns,
+        } => {
+            assert_eq!(vec![VacuumOption::Full, VacuumOption::Analyze], options);
+            assert_eq!(Some(ObjectName(vec![Ident::new("t")])), table_name);
+            assert!(columns.is_empty());
+        }
+        _ => unreachable!(),
+    }
+    pg_and_generic().verified_stmt("VACUUM");
+    pg_and_generic().verified_stmt("VACUUM FREEZE VERBOSE ANALYZE s.t (a, b)");
+
+    // VACUUM is a PostgreSQL statement
+    let res = TestedDialects {
+        dialects: vec![Box::new(MySqlDialect {})],
+    }
+    .parse_sql_statements("VACUUM t");
+    assert_eq!(
+        ParserError::ParserError("Expected an SQL statement, found: VACUUM".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_containment_and_jsonb_operators() {
+    let select = pg_and_generic().verified_only_select("SELECT * FROM t WHERE tags @> ARRAY['a']");
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("tags"))),
+            op: BinaryOperator::PGContains,
+            right: Box::new(Expr::Array(vec![Expr::Value(Value::SingleQuotedString(
+                "a".to_string()
+            ))])),
+        },
+        select.selection.unwrap()
+    );
+
+    let select = pg_and_generic().verified_only_select("SELECT range && other FROM t");
+    assert_eq!(
+        &Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("range"))),
+            op: BinaryOperator::PGOverlap,
+            right: Box::new(Expr::Identifier(Ident::new("other"))),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    for (sql, op) in &[
+        ("a <@ b", BinaryOperator::PGContainedBy),
+        ("a ? 'k'", BinaryOperator::PGExists),
+        ("a ?| ARRAY['k', 'l']", BinaryOperator::PGExistsAny),
+        ("a ?& ARRAY['k', 'l']", BinaryOperator::PGExistsAll),
+    ] {
+        match pg_and_generic().verified_expr(sql) {
+            Expr::BinaryOp { op: actual, .. } => assert_eq!(op, &actual),
+            e => panic!("Expected a binary operator, got {:?}", e),
+        }
+    }
+
+    // These bind tighter than comparisons but looser than arithmetic
+    match pg().verified_expr("a @> b = true") {
+        Expr::BinaryOp {
+            op: BinaryOperator::Eq,
+            left,
+            ..
+        } => assert!(matches!(
+            *left,
+            Expr::BinaryOp {
+                op: BinaryOperator::PGContains,
+                ..
+            }
+        )),
+        e => panic!("Expected = at the top, got {:?}", e),
+    }
+    match pg().verified_expr("a && b + c") {
+        Expr::BinaryOp {
+            op: BinaryOperator::PGOverlap,
+            right,
+            ..
+        } => assert!(matches!(
+            *right,
+            Expr::BinaryOp {
+                op: BinaryOperator::Plus,
+                ..
+            }
+        )),
+        e => panic!("Expected && at the top, got {:?}", e),
+    }
+}
+
+#[test]
+fn parse_json_access_chain() {
+    let sql = "SELECT * FROM t WHERE data -> 'a' ->> 'b' = 'x'";
+    let select = pg_and_generic().verified_only_select(sql);
+    let string = |s: &str| Box::new(Expr::Value(Value::SingleQuotedString(s.to_string())));
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier(Ident::new("data"))),
+                    op: BinaryOperator::PGJsonGet,
+                    right: string("a"),
+                }),
+                op: BinaryOperator::PGJsonGetText,
+                right: string("b"),
+            }),
+            op: BinaryOperator::Eq,
+            right: string("x"),
+        },
+        select.selection.unwrap()
+    );
+
+    pg_and_generic().one_statement_parses_to(
+        "SELECT data->'a'->>'b' FROM t",
+        "SELECT data -> 'a' ->> 'b' FROM t",
+    );
+
+    let select = pg_and_generic().verified_only_select("SELECT data #> '{a,b}' #>> '{c}' FROM t");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::PGJsonGetPathText,
+            ..
+        } => assert!(matches!(
+            **left,
+            Expr::BinaryOp {
+                op: BinaryOperator::PGJsonGetPath,
+                ..
+            }
+        )),
+        e => panic!("Expected a chained #>>, got {:?}", e),
+    }
+
+    // Like the other operators without a dedicated precedence, JSON access
+    // binds more loosely than arithmetic
+    match pg_and_generic().verified_expr("a -> 1 + 1") {
+        Expr::BinaryOp {
+            op: BinaryOperator::PGJsonGet,
+            right,
+            ..
+        } => assert!(matches!(
+            *right,
+            Expr::BinaryOp {
+                op: BinaryOperator::Plus,
+                ..
+            }
+        )),
+        e => panic!("Expected -> at the top, got {:?}", e),
+    }
+}
+
+#[test]
+fn parse_time_zone_type_aliases() {
+    for (alias, long_form) in &[
+        ("TIMESTAMPTZ", "TIMESTAMP WITH TIME ZONE"),
+        ("TIMETZ", "TIME WITH TIME ZONE"),
+    ] {
+        let alias_expr = pg_and_generic()
+            .run_parser_method(&format!("CAST(a AS {})", alias), |parser| {
+                parser.parse_expr()
+            })
+            .unwrap();
+        assert_eq!(
+            pg_and_generic().verified_expr(&format!("CAST(a AS {})", long_form)),
+            alias_expr
+        );
+    }
+
+    match pg().verified_expr("CAST(a AS TIMESTAMP WITH TIME ZONE)") {
+        Expr::Cast { data_type, .. } => assert_eq!(DataType::Timestamp { tz: true }, data_type),
+        _ => unreachable!(),
+    }
+    pg().one_statement_parses_to(
+        "SELECT CAST(a AS TIME WITHOUT TIME ZONE)",
+        "SELECT CAST(a AS TIME)",
+    );
+}
+
+#[test]
+fn parse_numeric_type_aliases() {
+    for (alias, expected) in &[
+        ("INT2", DataType::SmallInt),
+        ("INT4", DataType::Int),
+        ("INT8", DataType::BigInt),
+        ("FLOAT4", DataType::Real),
+        ("FLOAT8", DataType::Double),
+    ] {
+        let sql = format!("SELECT CAST(a AS {})", alias);
+        let canonical = format!("SELECT CAST(a AS {})", expected);
+        match pg_and_generic().one_statement_parses_to(&sql, &canonical) {
+            Statement::Query(query) => match query.body {
+                SetExpr::Select(select) => match expr_from_projection(only(&select.projection)) {
+                    Expr::Cast { data_type, .. } => assert_eq!(expected, data_type),
+                    e => panic!("Expected a CAST, got {:?}", e),
+                },
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    // Elsewhere these are ordinary type names
+    let mysql = TestedDialects {
+        dialects: vec![Box::new(MySqlDialect {})],
+    };
+    match mysql.verified_expr("CAST(a AS INT8)") {
+        Expr::Cast { data_type, .. } => assert_eq!(
+            DataType::Custom(ObjectName(vec![Ident::new("INT8")])),
+            data_type
+        ),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_serial_columns() {
+    let sql = "CREATE TABLE s.t (id SERIAL PRIMARY KEY, small SMALLSERIAL, big BIGSERIAL NOT NULL)";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateTable { name, columns, .. } => {
+            assert_eq!(
+                vec![DataType::Serial, DataType::SmallSerial, DataType::BigSerial],
+                columns
+                    .iter()
+                    .map(|c| c.data_type.clone())
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(
+                vec![ColumnOptionDef {
+                    name: None,
+                    option: ColumnOption::Unique { is_primary: true },
+                }],
+                columns[0].options
+            );
+
+            let expanded = columns[0].expand_serial(&name).unwrap();
+            assert_eq!(
+                "id INT NOT NULL DEFAULT nextval('s.t_id_seq') PRIMARY KEY",
+                expanded.to_string()
+            );
+            // An explicit NOT NULL is not repeated
+            let expanded = columns[2].expand_serial(&name).unwrap();
+            assert_eq!(
+                "big BIGINT DEFAULT nextval('s.t_big_seq') NOT NULL",
+                expanded.to_string()
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    match pg().verified_stmt(r#"CREATE TABLE s."My Table" ("Id" SERIAL)"#) {
+        Statement::CreateTable { name, columns, .. } => {
+            let expanded = columns[0].expand_serial(&name).unwrap();
+            assert_eq!(
+                r#""Id" INT NOT NULL DEFAULT nextval('s."My Table_Id_seq"')"#,
+                expanded.to_string()
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    // The unquoted part is folded, as PostgreSQL would for the sequence name
+    match pg().verified_stmt(r#"CREATE TABLE Orders ("Id" SERIAL, Total SERIAL)"#) {
+        Statement::CreateTable { name, columns, .. } => {
+            let expanded = columns[0].expand_serial(&name).unwrap();
+            assert_eq!(
+                r#""Id" INT NOT NULL DEFAULT nextval('"orders_Id_seq"')"#,
+                expanded.to_string()
+            );
+            let expanded = columns[1].expand_serial(&name).unwrap();
+            assert_eq!(
+                "Total INT NOT NULL DEFAULT nextval('Orders_Total_seq')",
+                expanded.to_string()
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    assert_eq!(None, DataType::Int.serial_base_type());
+    assert_eq!(
+        Some(DataType::SmallInt),
+        DataType::SmallSerial.serial_base_type()
+    );
+}
+
+#[test]
+fn parse_nested_comments() {
+    pg().one_statement_parses_to("SELECT 1 /* a /* b */ c */ FROM t", "SELECT 1 FROM t");
+    // Without nesting support, `c */` is left over after the comment
+    assert!(TestedDialects {
+        dialects: vec![Box::new(MySqlDialect {})],
+    }
+    .parse_sql_statements("SELECT 1 /* a /* b */ c */ FROM t")
+    .is_err());
+}
+
+#[test]
+fn parse_alter_table_validate_constraint() {
+    let (name, operation) = alter_table_op_with_name(
+        pg_and_generic().verified_stmt("ALTER TABLE orders VALIDATE CONSTRAINT orders_fk"),
+    );
+    assert_eq!("orders", name.to_string());
+    assert_eq!(
+        AlterTableOperation::ValidateConstraint {
+            name: Ident::new("orders_fk")
+        },
+        operation
+    );
+
+    let res = pg().parse_sql_statements("ALTER TABLE orders VALIDATE CONSTRAINT");
+    assert_eq!(
+        ParserError::ParserError("Expected identifier, found: EOF".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_alter_table_alter_constraint() {
+    match alter_table_op_with_name(pg_and_generic().verified_stmt(
+        "ALTER TABLE orders ALTER CONSTRAINT orders_fk DEFERRABLE INITIALLY DEFERRED",
+    )) {
+        (
+            _,
+            AlterTableOperation::AlterConstraint {
+                name,
+                characteristics,
+            },
+        ) => {
+            assert_eq!(Ident::new("orders_fk"), name);
+            assert_eq!(
+                ConstraintCharacteristics {
+                    deferrable: Some(true),
+                    initially: Some(DeferrableInitial::Deferred),
+                },
+                characteristics
+            );
+        }
+        _ => unreachable!(),
+    }
+    pg_and_generic().verified_stmt("ALTER TABLE orders ALTER CONSTRAINT orders_fk NOT DEFERRABLE");
+
+    let res = pg().parse_sql_statements("ALTER TABLE orders ALTER CONSTRAINT orders_fk");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected DEFERRABLE, NOT DEFERRABLE or INITIALLY after ALTER CONSTRAINT <name>, found: EOF"
+                .to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_alter_table_multiple_operations() {
+    match pg_and_generic()
+        .verified_stmt("ALTER TABLE t ADD COLUMN a INT, DROP COLUMN b, ALTER COLUMN c SET NOT NULL")
+    {
+        Statement::AlterTable { name, operations } => {
+            assert_eq!("t", name.to_string());
+            assert_eq!(
+                vec![
+                    AlterTableOperation::AddColumn {
+                        column_def: ColumnDef {
+                            name: Ident::new("a"),
+                            data_type: DataType::Int,
+                            collation: None,
+                            leading_comments: vec![],
+                            options: vec![],
+                        },
+                    },
+                    AlterTableOperation::DropColumn {
+                        column_name: Ident::new("b"),
+                        if_exists: false,
+                        cascade: false,
+                    },
+                    AlterTableOperation::AlterColumn {
+                        column_name: Ident::new("c"),
+                        op: AlterColumnOperation::SetNotNull,
+                    },
+                ],
+                operations
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    let res = pg().parse_sql_statements("ALTER TABLE t ADD COLUMN a INT,");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected ADD, RENAME, PARTITION, ALTER, DROP, VALIDATE or SET SCHEMA after ALTER TABLE, found: EOF"
+                .to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_alter_set_schema() {
+    let (name, operation) = alter_table_op_with_name(
+        pg_and_generic().verified_stmt("ALTER TABLE public.orders SET SCHEMA archive"),
+    );
+    assert_eq!("public.orders", name.to_string());
+    assert_eq!(
+        AlterTableOperation::SetSchema {
+            new_schema: ObjectName(vec![Ident::new("archive")])
+        },
+        operation
+    );
+
+    match pg_and_generic().verified_stmt("ALTER SEQUENCE orders_id_seq SET SCHEMA archive") {
+        Statement::AlterObject {
+            object_type,
+            name,
+            operation,
+        } => {
+            assert_eq!(ObjectType::Sequence, object_type);
+            assert_eq!("orders_id_seq", name.to_string());
+            assert_eq!(
+                AlterTableOperation::SetSchema {
+                    new_schema: ObjectName(vec![Ident::new("archive")])
+                },
+                operation
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_alter_object_rename() {
+    for (object_type, keyword) in &[
+        (ObjectType::View, "VIEW"),
+        (ObjectType::Sequence, "SEQUENCE"),
+        (ObjectType::Index, "INDEX"),
+    ] {
+        let sql = format!("ALTER {} s.old_name RENAME TO new_name", keyword);
+        match pg_and_generic().verified_stmt(&sql) {
+            Statement::AlterObject {
+                object_type: parsed_type,
+                name,
+                operation,
+            } => {
+                assert_eq!(*object_type, parsed_type);
+                assert_eq!("s.old_name", name.to_string());
+                assert_eq!(
+                    AlterTableOperation::RenameTable {
+                        table_name: ObjectName(vec![Ident::new("new_name")])
+                    },
+                    operation
+                );
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    // only renaming and moving the object are supported outside ALTER TABLE
+    let res = pg().parse_sql_statements("ALTER VIEW v ADD COLUMN a INT");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected RENAME TO or SET SCHEMA after ALTER VIEW, found: ADD".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_cte_materialization_hints() {
+    let materialized = |sql: &str| {
+        let query = pg_and_generic().verified_query(sql);
+        query.with.unwrap().cte_tables[0].materialized
+    };
+    assert_eq!(
+        Some(true),
+        materialized("WITH x AS MATERIALIZED (SELECT 1) SELECT * FROM x")
+    );
+    assert_eq!(
+        Some(false),
+        materialized("WITH x (a) AS NOT MATERIALIZED (SELECT 1) SELECT * FROM x")
+    );
+    assert_eq!(None, materialized("WITH x AS (SELECT 1) SELECT * FROM x"));
+}
+
+#[test]
+fn parse_create_table_like() {
+    match pg_and_generic().verified_stmt("CREATE TABLE t (LIKE src)") {
+        Statement::CreateTable { like, columns, .. } => {
+            assert_eq!(
+                Some(TableLike {
+                    name: ObjectName(vec![Ident::new("src")]),
+                    parenthesized: true,
+                    options: vec![],
+                }),
+                like
+            );
+            assert!(columns.is_empty());
+        }
+        _ => unreachable!(),
+    }
+
+    let sql = "CREATE TABLE t (LIKE s.src INCLUDING DEFAULTS EXCLUDING ALL)";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateTable { like, .. } => assert_eq!(
+            Some(TableLike {
+                name: ObjectName(vec![Ident::new("s"), Ident::new("src")]),
+                parenthesized: true,
+                options: vec![
+                    TableLikeOption {
+                        including: true,
+                        property: Ident::new("DEFAULTS"),
+                    },
+                    TableLikeOption {
+                        including: false,
+                        property: Ident::new("ALL"),
+                    },
+                ],
+            }),
+            like
+        ),
+        _ => unreachable!(),
+    }
+
+    let res = pg().parse_sql_statements("CREATE TABLE t (LIKE src INCLUDING)");
+    assert_eq!(
+        ParserError::ParserError("Expected identifier, found: )".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_create_table_inherits() {
+    let inherits = |sql: &str| match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateTable { inherits, .. } => inherits,
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        vec![ObjectName(vec![Ident::new("parent")])],
+        inherits("CREATE TABLE child (a INT) INHERITS (parent)")
+    );
+    assert_eq!(
+        vec![
+            ObjectName(vec![Ident::new("s"), Ident::new("p1")]),
+            ObjectName(vec![Ident::new("p2")]),
+        ],
+        inherits("CREATE TABLE child () INHERITS (s.p1, p2) TABLESPACE fast")
+    );
+    assert!(inherits("CREATE TABLE t (a INT)").is_empty());
+
+    let res = pg().parse_sql_statements("CREATE TABLE child (a INT) INHERITS ()");
+    assert_eq!(
+        ParserError::ParserError("Expected identifier, found: )".to_string()),
+        res.unwrap_err()
+    );
+}
@@ -14,6 +14,7 @@
 
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::Parser;
+use sqlparser::tokenizer::Tokenizer;
 
 macro_rules! tpch_tests {
     ($($name:ident: $value:expr,)*) => {
@@ -56,3 +57,43 @@ tpch_tests! {
     tpch_21: 21,
     tpch_22: 22,
 }
+
+/// Builds a corpus of `n` statements resembling a migration file, mixing DDL,
+/// DML and queries with string literals that exercise the tokenizer's escape
+/// handling. A copy of this corpus drives the `throughput` benchmarks in
+/// `sqlparser_bench`, which measure tokens/sec and can be run with:
+///
+/// ```text
+/// cd sqlparser_bench && cargo bench -- throughput
+/// ```
+fn migration_corpus(n: usize) -> String {
+    let templates = [
+        "CREATE TABLE t{i} (id INT PRIMARY KEY, name VARCHAR(100) NOT NULL, note TEXT DEFAULT 'it''s {i}')",
+        "INSERT INTO t{i} (id, name) VALUES ({i}, 'a\\\\b'), ({i} + 1, 'x''y')",
+        "UPDATE t{i} SET name = 'n_{i}' WHERE id = {i} AND name LIKE 'a\\_%'",
+        "SELECT a.id, COUNT(*) FROM t{i} AS a JOIN u ON a.id = u.id WHERE a.name <> \"quoted \"\"{i}\"\"\" GROUP BY a.id ORDER BY 2 DESC LIMIT 10",
+        "DELETE FROM t{i} WHERE id IN (SELECT id FROM u WHERE x = X'0{i}')",
+    ];
+    let mut sql = String::new();
+    for i in 0..n {
+        sql.push_str(&templates[i % templates.len()].replace("{i}", &i.to_string()));
+        sql.push_str(";\n");
+    }
+    sql
+}
+
+#[test]
+fn migration_corpus_work() {
+    let dialect = GenericDialect {};
+    for &(n, expected_tokens) in &[(500, 20_000), (5000, 200_000)] {
+        let sql = migration_corpus(n);
+        let tokens = Tokenizer::new(&dialect, &sql).tokenize().unwrap();
+        let statements = Parser::parse_sql(&dialect, &sql).unwrap();
+
+        // The work grows linearly with the corpus: every statement is parsed,
+        // none merged or dropped, and the number of tokens the benchmark's
+        // tokens/sec is based on stays the same per statement
+        assert_eq!(n, statements.len());
+        assert_eq!(expected_tokens, tokens.len());
+    }
+}
@@ -70,6 +70,7 @@ fn parse_create_table_auto_increment() {
                     name: "bar".into(),
                     data_type: DataType::Int,
                     collation: None,
+                    leading_comments: vec![],
                     options: vec![
                         ColumnOptionDef {
                             name: None,
@@ -102,12 +103,14 @@ fn parse_create_sqlite_quote() {
                         name: Ident::with_quote('"', "KEY"),
                         data_type: DataType::Int,
                         collation: None,
+                        leading_comments: vec![],
                         options: vec![],
                     },
                     ColumnDef {
                         name: Ident::with_quote('[', "INDEX"),
                         data_type: DataType::Int,
                         collation: None,
+                        leading_comments: vec![],
                         options: vec![],
                     },
                 ],